@@ -0,0 +1,147 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! Short beeps, e.g., for boot diagnostics or a PC-speaker-style interface.
+//!
+//! A beep is played in pull mode: the device asks for each period of a square
+//! wave as it needs it, so starting a beep returns at once and nothing waits
+//! for it to end.
+
+use alloc::sync::Arc;
+use core::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use ostd::{
+    mm::{Infallible, VmReader, VmWriter},
+    sync::SpinLock,
+};
+
+use crate::{
+    convert::{can_convert, sample_bytes},
+    open_output,
+    tone::{ToneGenerator, Waveform},
+    AudioOutput, CallbackHandle, OutputStream, SampleFormat, SoundError, StreamParams,
+};
+
+/// The amplitude of a beep, a quarter of the full scale.
+pub const BEEP_AMPLITUDE: i16 = 8192;
+
+const BEEP_PARAMS: StreamParams = StreamParams {
+    format: SampleFormat::S16,
+    rate: 48000,
+    channels: 1,
+    // 40 ms, in periods of 10 ms.
+    buffer_bytes: 3840,
+    period_bytes: 960,
+};
+
+/// A beep being played.
+///
+/// The output stream is held until the handle is dropped, which cuts the beep
+/// short if it has not ended yet.
+#[derive(Debug)]
+pub struct Beep {
+    /// The frames of the tone left to be played.
+    remaining_frames: Arc<AtomicUsize>,
+    // Unregisters the callback before the stream is closed.
+    _callback: CallbackHandle,
+    _stream: OutputStream,
+}
+
+impl Beep {
+    /// Returns whether every frame of the beep has been handed to the device.
+    pub fn is_finished(&self) -> bool {
+        self.remaining_frames.load(Ordering::Relaxed) == 0
+    }
+}
+
+/// Starts a beep of `frequency` Hz lasting `duration` on the default output device.
+pub fn beep(frequency: u32, duration: Duration) -> Result<Beep, SoundError> {
+    let device = crate::default_output().ok_or(SoundError::NotReady)?;
+    beep_on(&device, frequency, duration)
+}
+
+/// Starts a beep of `frequency` Hz lasting `duration` on `device`.
+///
+/// Fails with [`SoundError::Unsupported`] if the device cannot play in pull mode.
+pub fn beep_on(
+    device: &Arc<dyn AudioOutput>,
+    frequency: u32,
+    duration: Duration,
+) -> Result<Beep, SoundError> {
+    let mut stream = open_output(device, BEEP_PARAMS)?;
+    // The device asks for its own format and rate, which the tone is generated in.
+    let format = stream.device_format();
+    let rate = stream.device_rate();
+    if !can_convert(SampleFormat::S16, format) {
+        return Err(SoundError::Unsupported);
+    }
+    let frame_bytes = sample_bytes(format).unwrap() * BEEP_PARAMS.channels as usize;
+
+    let frames = (duration.as_micros() * rate as u128 / 1_000_000) as usize;
+    let remaining_frames = Arc::new(AtomicUsize::new(frames));
+    let generator = SpinLock::new(ToneGenerator::new(
+        Waveform::Square,
+        frequency,
+        rate,
+        BEEP_AMPLITUDE,
+    ));
+    let callback = {
+        let remaining_frames = remaining_frames.clone();
+        Arc::new(move |mut writer: VmWriter<Infallible>| {
+            let remaining = remaining_frames.load(Ordering::Relaxed);
+            let frames = remaining.min(writer.avail() / frame_bytes);
+            if frames == 0 {
+                // The rest of the period is played as silence.
+                return;
+            }
+            let tone = generator
+                .lock()
+                .generate(format, BEEP_PARAMS.channels, frames)
+                .unwrap();
+            writer.write(&mut VmReader::from(tone.as_slice()));
+            remaining_frames.store(remaining - frames, Ordering::Relaxed);
+        })
+    };
+    let callback = device.register_playback_callback(stream.stream_id(), callback)?;
+    stream.start()?;
+
+    Ok(Beep {
+        remaining_frames,
+        _callback: callback,
+        _stream: stream,
+    })
+}
+
+#[cfg(ktest)]
+mod test {
+    use alloc::vec;
+
+    use ostd::prelude::*;
+
+    use super::*;
+    use crate::{fake::FakeSoundDevice, StreamDirection};
+
+    #[ktest]
+    fn beep_in_pull_mode() {
+        let fake = Arc::new(FakeSoundDevice::new(vec![FakeSoundDevice::capability(
+            0,
+            StreamDirection::Output,
+        )]));
+        let device: Arc<dyn AudioOutput> = fake.clone();
+
+        // 15 ms are a period and a half.
+        let beep = beep_on(&device, 1000, Duration::from_millis(15)).unwrap();
+        assert!(fake.is_running(0));
+        assert!(fake.elapse_period(0));
+        assert!(!beep.is_finished());
+        assert!(fake.elapse_period(0));
+        assert!(beep.is_finished());
+
+        let played = fake.played(0);
+        assert_eq!(played.len(), 1920);
+        assert_eq!(played[..2], BEEP_AMPLITUDE.to_le_bytes());
+        assert!(played[1440..].iter().all(|byte| *byte == 0));
+    }
+}
@@ -0,0 +1,142 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! Stream capabilities reported by sound devices.
+
+use alloc::{sync::Arc, vec::Vec};
+use core::ops::RangeInclusive;
+
+use int_to_c_enum::TryFromInt;
+
+/// The direction of data flow of a PCM stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamDirection {
+    /// The stream plays frames to the device.
+    Output,
+    /// The stream records frames from the device.
+    Input,
+}
+
+/// A PCM sample format.
+///
+/// The discriminants follow the `VIRTIO_SND_PCM_FMT_*` numbering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TryFromInt)]
+#[repr(u8)]
+pub enum SampleFormat {
+    ImaAdpcm = 0,
+    MuLaw = 1,
+    ALaw = 2,
+    S8 = 3,
+    U8 = 4,
+    S16 = 5,
+    U16 = 6,
+    S18_3 = 7,
+    U18_3 = 8,
+    S20_3 = 9,
+    U20_3 = 10,
+    S24_3 = 11,
+    U24_3 = 12,
+    S20 = 13,
+    U20 = 14,
+    S24 = 15,
+    U24 = 16,
+    S32 = 17,
+    U32 = 18,
+    Float = 19,
+    Float64 = 20,
+    DsdU8 = 21,
+    DsdU16 = 22,
+    DsdU32 = 23,
+    Iec958Subframe = 24,
+}
+
+/// What a single PCM stream of a sound device is able to do.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StreamCapability {
+    /// The identifier of the stream within its device.
+    pub stream_id: u32,
+    pub direction: StreamDirection,
+    /// The sample formats accepted by the stream.
+    pub formats: Vec<SampleFormat>,
+    /// The frame rates accepted by the stream, in Hz.
+    pub rates: Vec<u32>,
+    /// The accepted number of channels.
+    pub channels: RangeInclusive<u8>,
+    /// The channel maps of the stream, each one listing a position per channel.
+    pub channel_maps: Vec<Vec<u8>>,
+    /// Whether the channel map of an opened stream can be switched to another
+    /// one of `channel_maps` with [`AudioOutput::set_channel_map`].
+    ///
+    /// [`AudioOutput::set_channel_map`]: crate::AudioOutput::set_channel_map
+    pub remappable: bool,
+    /// The jacks the stream is routed to.
+    pub jacks: Vec<u32>,
+}
+
+impl StreamCapability {
+    /// Returns whether the stream accepts the given parameters.
+    pub fn supports(&self, format: SampleFormat, rate: u32, channels: u8) -> bool {
+        self.formats.contains(&format)
+            && self.rates.contains(&rate)
+            && self.channels.contains(&channels)
+    }
+
+    /// Returns the position of each channel in frames of `channels` channels,
+    /// if the device reports a channel map for them.
+    pub fn channel_map(&self, channels: u8) -> Option<&[u8]> {
+        self.channel_maps
+            .iter()
+            .find(|map| map.len() == channels as usize)
+            .map(Vec::as_slice)
+    }
+
+    /// Returns the channel maps of the surround layouts, i.e., those of more
+    /// than two channels, that the stream can be opened with.
+    pub fn surround_layouts(&self) -> impl Iterator<Item = &[u8]> {
+        self.channel_maps
+            .iter()
+            .filter(|map| map.len() > 2)
+            .filter(|map| u8::try_from(map.len()).is_ok_and(|len| self.channels.contains(&len)))
+            .map(Vec::as_slice)
+    }
+
+    /// Returns whether the stream can be opened with `channels` channels
+    /// without the frames being routed to unknown positions.
+    ///
+    /// A stream with more than two channels needs a channel map for them, if
+    /// the device reports any, so that each channel reaches a known position.
+    pub fn supports_layout(&self, channels: u8) -> bool {
+        self.channels.contains(&channels)
+            && (channels <= 2
+                || self.channel_maps.is_empty()
+                || self.channel_map(channels).is_some())
+    }
+}
+
+/// The capabilities of every stream of a device, taken at once.
+///
+/// A snapshot is cheap to clone, so the upper layers can keep it instead of
+/// querying the device again for each stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CapabilitiesSnapshot {
+    streams: Arc<[StreamCapability]>,
+}
+
+impl CapabilitiesSnapshot {
+    pub fn new(streams: Vec<StreamCapability>) -> Self {
+        Self {
+            streams: streams.into(),
+        }
+    }
+
+    /// Returns the capabilities of the streams, in stream ID order.
+    pub fn streams(&self) -> &[StreamCapability] {
+        &self.streams
+    }
+
+    /// Returns the capability of the stream with `stream_id`.
+    pub fn stream(&self, stream_id: u32) -> Option<&StreamCapability> {
+        self.streams
+            .iter()
+            .find(|capability| capability.stream_id == stream_id)
+    }
+}
@@ -0,0 +1,288 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! Conversions between PCM sample formats and channel layouts.
+//!
+//! The samples are in little-endian byte order, as virtio-sound requires.
+
+use alloc::vec::Vec;
+
+use crate::{SampleFormat, StreamDirection};
+
+/// The formats a stream may be opened with when the device does not accept
+/// them directly, in the order the device formats are tried.
+const CONVERTIBLE_FORMATS: [SampleFormat; 8] = [
+    SampleFormat::S16,
+    SampleFormat::U8,
+    SampleFormat::S32,
+    SampleFormat::Float,
+    SampleFormat::S20_3,
+    SampleFormat::U20_3,
+    SampleFormat::S18_3,
+    SampleFormat::U18_3,
+];
+
+/// Returns the size of a sample of `format` in bytes, if it is a format that can be converted.
+pub fn sample_bytes(format: SampleFormat) -> Option<usize> {
+    match format {
+        SampleFormat::U8 => Some(1),
+        SampleFormat::S16 => Some(2),
+        SampleFormat::S18_3 | SampleFormat::U18_3 | SampleFormat::S20_3 | SampleFormat::U20_3 => {
+            Some(3)
+        }
+        SampleFormat::S32 | SampleFormat::Float => Some(4),
+        _ => None,
+    }
+}
+
+/// Returns whether [`convert`] supports converting samples from `from` to `to`.
+pub fn can_convert(from: SampleFormat, to: SampleFormat) -> bool {
+    use SampleFormat::*;
+
+    from == to && sample_bytes(from).is_some()
+        || matches!(
+            (from, to),
+            (U8, S16) | (S16, U8) | (S32, S16) | (Float, S16)
+        )
+        || matches!(from, S16 | S32) && packed_layout(to).is_some()
+        || packed_layout(from).is_some() && matches!(to, S16 | S32)
+}
+
+/// Returns the formats the device may be asked for when a stream of `direction`
+/// is opened with `format`, the preferred one first.
+pub(crate) fn fallback_formats(
+    format: SampleFormat,
+    direction: StreamDirection,
+) -> impl Iterator<Item = SampleFormat> {
+    CONVERTIBLE_FORMATS
+        .into_iter()
+        .filter(move |device_format| match direction {
+            StreamDirection::Output => can_convert(format, *device_format),
+            StreamDirection::Input => can_convert(*device_format, format),
+        })
+        .filter(move |device_format| *device_format != format)
+}
+
+/// Converts the samples in `src` from `from` to `to`.
+///
+/// A trailing partial sample is ignored. Returns `None` if the conversion is not supported.
+pub fn convert(from: SampleFormat, to: SampleFormat, src: &[u8]) -> Option<Vec<u8>> {
+    use SampleFormat::*;
+
+    if !can_convert(from, to) {
+        return None;
+    }
+    let src_bytes = sample_bytes(from)?;
+    let samples = src.chunks_exact(src_bytes);
+    let converted = match (from, to) {
+        _ if from == to => src[..src.len() - src.len() % src_bytes].to_vec(),
+        (U8, S16) => samples
+            .flat_map(|sample| (((sample[0] as i16) - 0x80) << 8).to_le_bytes())
+            .collect(),
+        (S16, U8) => samples
+            .map(|sample| ((i16::from_le_bytes([sample[0], sample[1]]) >> 8) + 0x80) as u8)
+            .collect(),
+        (S32, S16) => samples
+            .flat_map(|sample| {
+                let value = i32::from_le_bytes([sample[0], sample[1], sample[2], sample[3]]);
+                ((value >> 16) as i16).to_le_bytes()
+            })
+            .collect(),
+        (Float, S16) => samples
+            .flat_map(|sample| {
+                let value = f32::from_le_bytes([sample[0], sample[1], sample[2], sample[3]]);
+                float_to_s16(value).to_le_bytes()
+            })
+            .collect(),
+        (S16 | S32, _) => samples
+            .flat_map(|sample| {
+                let value = if from == S16 {
+                    (i16::from_le_bytes([sample[0], sample[1]]) as i32) << 16
+                } else {
+                    i32::from_le_bytes([sample[0], sample[1], sample[2], sample[3]])
+                };
+                pack(to, value)
+            })
+            .collect(),
+        (_, S16) => samples
+            .flat_map(|sample| ((unpack(from, sample) >> 16) as i16).to_le_bytes())
+            .collect(),
+        (_, S32) => samples
+            .flat_map(|sample| unpack(from, sample).to_le_bytes())
+            .collect(),
+        _ => unreachable!(),
+    };
+    Some(converted)
+}
+
+/// Returns the number of significant bits of a packed 3-byte format and whether it is signed.
+///
+/// The significant bits are the low bits of the 24-bit container.
+fn packed_layout(format: SampleFormat) -> Option<(u32, bool)> {
+    match format {
+        SampleFormat::S18_3 => Some((18, true)),
+        SampleFormat::U18_3 => Some((18, false)),
+        SampleFormat::S20_3 => Some((20, true)),
+        SampleFormat::U20_3 => Some((20, false)),
+        _ => None,
+    }
+}
+
+/// Packs a sample scaled to the full `i32` range into a packed 3-byte format.
+///
+/// The padding bits above the significant ones are a sign extension for the
+/// signed formats and zero for the unsigned ones.
+fn pack(format: SampleFormat, value: i32) -> [u8; 3] {
+    let (bits, signed) = packed_layout(format).unwrap();
+    let mut packed = value >> (32 - bits);
+    if !signed {
+        packed = (packed + (1 << (bits - 1))) & ((1 << bits) - 1);
+    }
+    let bytes = packed.to_le_bytes();
+    [bytes[0], bytes[1], bytes[2]]
+}
+
+/// Unpacks a sample of a packed 3-byte format, scaled to the full `i32` range.
+///
+/// The padding bits are ignored, since devices do not agree on their content.
+fn unpack(format: SampleFormat, sample: &[u8]) -> i32 {
+    let (bits, signed) = packed_layout(format).unwrap();
+    let container = i32::from_le_bytes([sample[0], sample[1], sample[2], 0]);
+    // Moving the significant bits to the top drops the padding.
+    let value = container << (32 - bits);
+    if signed {
+        value
+    } else {
+        value ^ i32::MIN
+    }
+}
+
+/// Fills `buf` with silent samples of `format`.
+///
+/// Silence is zero for the signed formats and the middle of the range for the
+/// unsigned ones. A trailing partial sample is left untouched.
+pub fn fill_silence(format: SampleFormat, buf: &mut [u8]) {
+    let Some(sample_bytes) = sample_bytes(format) else {
+        return;
+    };
+    let mut silence = [0u8; 4];
+    match format {
+        SampleFormat::U8 => silence[0] = 0x80,
+        format if packed_layout(format).is_some() => silence[..3].copy_from_slice(&pack(format, 0)),
+        _ => {}
+    }
+    for sample in buf.chunks_exact_mut(sample_bytes) {
+        sample.copy_from_slice(&silence[..sample_bytes]);
+    }
+}
+
+fn float_to_s16(value: f32) -> i16 {
+    // `as` saturates and maps NaN to zero.
+    (value.clamp(-1.0, 1.0) * i16::MAX as f32) as i16
+}
+
+/// Interleaves one buffer of samples per channel into a single buffer of frames.
+///
+/// The frames stop at the end of the shortest channel.
+pub fn interleave<T: Copy>(channels: &[&[T]]) -> Vec<T> {
+    let frames = channels
+        .iter()
+        .map(|channel| channel.len())
+        .min()
+        .unwrap_or(0);
+    let mut interleaved = Vec::with_capacity(frames * channels.len());
+    for frame in 0..frames {
+        interleaved.extend(channels.iter().map(|channel| channel[frame]));
+    }
+    interleaved
+}
+
+/// Splits a buffer of frames of `channels` samples into one buffer per channel.
+///
+/// A trailing partial frame is ignored.
+pub fn deinterleave<T: Copy>(frames: &[T], channels: usize) -> Vec<Vec<T>> {
+    (0..channels)
+        .map(|channel| {
+            frames
+                .chunks_exact(channels)
+                .map(|frame| frame[channel])
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(ktest)]
+mod test {
+    use alloc::vec;
+
+    use ostd::prelude::*;
+
+    use super::*;
+
+    #[ktest]
+    fn convert_formats() {
+        assert_eq!(
+            convert(SampleFormat::U8, SampleFormat::S16, &[0x80, 0xff, 0x00]),
+            Some(vec![0x00, 0x00, 0x00, 0x7f, 0x00, 0x80])
+        );
+        assert_eq!(
+            convert(
+                SampleFormat::S16,
+                SampleFormat::U8,
+                &[0x00, 0x80, 0xff, 0x7f]
+            ),
+            Some(vec![0x00, 0xff])
+        );
+        assert_eq!(
+            convert(
+                SampleFormat::Float,
+                SampleFormat::S16,
+                &2.0f32.to_le_bytes()
+            ),
+            Some(i16::MAX.to_le_bytes().to_vec())
+        );
+        assert_eq!(
+            convert(SampleFormat::S16, SampleFormat::Float, &[0; 2]),
+            None
+        );
+    }
+
+    #[ktest]
+    fn convert_packed_formats() {
+        assert_eq!(
+            convert(SampleFormat::S16, SampleFormat::S18_3, &[0x00, 0x80]),
+            Some(vec![0x00, 0x00, 0xfe])
+        );
+        assert_eq!(
+            convert(SampleFormat::S16, SampleFormat::U20_3, &[0x00, 0x00]),
+            Some(vec![0x00, 0x00, 0x08])
+        );
+        assert_eq!(
+            convert(
+                SampleFormat::S32,
+                SampleFormat::S20_3,
+                &i32::MAX.to_le_bytes()
+            ),
+            Some(vec![0xff, 0xff, 0x07])
+        );
+        // The padding bits do not affect the samples.
+        assert_eq!(
+            convert(SampleFormat::S18_3, SampleFormat::S16, &[0x00, 0x00, 0x02]),
+            Some(vec![0x00, 0x80])
+        );
+        assert_eq!(
+            convert(SampleFormat::U20_3, SampleFormat::S16, &[0x00, 0x00, 0xf8]),
+            Some(vec![0x00, 0x00])
+        );
+        assert_eq!(
+            convert(SampleFormat::S20_3, SampleFormat::S32, &[0x01, 0x00, 0x00]),
+            Some((1i32 << 12).to_le_bytes().to_vec())
+        );
+    }
+
+    #[ktest]
+    fn interleave_round_trip() {
+        let frames = interleave(&[&[1, 3, 5], &[2, 4]]);
+        assert_eq!(frames, vec![1, 2, 3, 4]);
+        assert_eq!(deinterleave(&frames, 2), vec![vec![1, 3], vec![2, 4]]);
+    }
+}
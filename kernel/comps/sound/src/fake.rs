@@ -0,0 +1,809 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! A sound device that is not backed by any hardware.
+//!
+//! [`FakeSoundDevice`] reports scripted capabilities, records what is played,
+//! replays scripted frames on capture and can be told to fail operations,
+//! so the layers built on top of [`AnySoundDevice`] can be tested alone.
+
+use alloc::{
+    collections::{BTreeMap, VecDeque},
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
+use core::{
+    fmt::{self, Debug},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use ostd::{mm::VmWriter, sync::SpinLock};
+
+use crate::{
+    convert::sample_bytes, AnySoundDevice, AudioInput, AudioOutput, CallbackHandle, CompletionMode,
+    CompletionPriority, EventCallback, Frames, JackState, LatencyHistogram, PlaybackCallback,
+    RecordToken, SampleFormat, SoundCallback, SoundError, SoundEvent, StreamCapability,
+    StreamDirection, StreamParams, StreamPosition, XrunEvent,
+};
+
+/// The operations of a [`FakeSoundDevice`] that can be made to fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FakeOp {
+    Open,
+    Start,
+    Stop,
+    Write,
+    Read,
+    Drain,
+    Close,
+}
+
+#[derive(Default)]
+struct FakeStream {
+    opened: bool,
+    disabled: bool,
+    period_bytes: usize,
+    /// Fills the periods of the stream in pull mode.
+    playback_callback: Option<Arc<PlaybackCallback>>,
+    running: bool,
+    frame_bytes: usize,
+    channels: u8,
+    /// The channel map selected with [`AudioOutput::set_channel_map`], if any.
+    channel_map: Option<Vec<u8>>,
+    /// Whether the stream was running when the device was suspended.
+    running_before_suspend: bool,
+    completion_mode: CompletionMode,
+    /// The frames played on the stream.
+    played: Vec<u8>,
+    /// The frames returned by the next reads of the stream.
+    to_capture: VecDeque<u8>,
+}
+
+impl Debug for FakeStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FakeStream")
+            .field("opened", &self.opened)
+            .field("disabled", &self.disabled)
+            .field("period_bytes", &self.period_bytes)
+            .field("running", &self.running)
+            .field("frame_bytes", &self.frame_bytes)
+            .field("channels", &self.channels)
+            .field("channel_map", &self.channel_map)
+            .field("running_before_suspend", &self.running_before_suspend)
+            .field("completion_mode", &self.completion_mode)
+            .field("played", &self.played)
+            .field("to_capture", &self.to_capture)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A scriptable sound device for tests.
+pub struct FakeSoundDevice {
+    state: SpinLock<FakeState>,
+    /// The event callbacks, keyed by the ID given at registration.
+    event_callbacks: Arc<SpinLock<BTreeMap<usize, Arc<EventCallback>>>>,
+    next_event_callback_id: AtomicUsize,
+}
+
+impl Debug for FakeSoundDevice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FakeSoundDevice")
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug)]
+struct FakeState {
+    capabilities: Vec<StreamCapability>,
+    streams: Vec<FakeStream>,
+    /// The latency the device pretends to have, in bytes.
+    latency_bytes: u32,
+    /// The errors to return from the next calls of each operation.
+    failures: BTreeMap<FakeOp, VecDeque<SoundError>>,
+    completion_priority: CompletionPriority,
+    dma_quota: usize,
+    jack_auto_pause: bool,
+    /// Whether each jack routed to a stream is connected.
+    jacks: BTreeMap<u32, bool>,
+    suspended: bool,
+    /// Whether the frames played are fed to the opened input streams.
+    loopback: bool,
+    /// The stream and length of each pending record request.
+    records: BTreeMap<RecordToken, (u32, usize)>,
+    next_record_token: u32,
+}
+
+impl FakeSoundDevice {
+    /// Returns the capability of a stream of S16 frames at 48 kHz, in mono or
+    /// stereo, which is what most tests need.
+    pub fn capability(stream_id: u32, direction: StreamDirection) -> StreamCapability {
+        StreamCapability {
+            stream_id,
+            direction,
+            formats: vec![SampleFormat::S16],
+            rates: vec![48000],
+            channels: 1..=2,
+            channel_maps: vec![],
+            remappable: false,
+            jacks: vec![],
+        }
+    }
+
+    /// Creates a device whose streams have the given capabilities.
+    ///
+    /// The stream IDs of `capabilities` must be their indexes. The jacks the
+    /// streams are routed to start connected.
+    pub fn new(capabilities: Vec<StreamCapability>) -> Self {
+        let streams = capabilities.iter().map(|_| FakeStream::default()).collect();
+        let jacks = capabilities
+            .iter()
+            .flat_map(|capability| capability.jacks.iter().map(|jack_id| (*jack_id, true)))
+            .collect();
+        let state = FakeState {
+            capabilities,
+            streams,
+            latency_bytes: 0,
+            failures: BTreeMap::new(),
+            completion_priority: CompletionPriority::default(),
+            dma_quota: usize::MAX,
+            jack_auto_pause: false,
+            jacks,
+            suspended: false,
+            loopback: false,
+            records: BTreeMap::new(),
+            next_record_token: 0,
+        };
+        Self {
+            state: SpinLock::new(state),
+            event_callbacks: Arc::new(SpinLock::new(BTreeMap::new())),
+            next_event_callback_id: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn latency_bytes(&self) -> u32 {
+        self.state.lock().latency_bytes
+    }
+
+    pub fn set_latency_bytes(&self, latency_bytes: u32) {
+        self.state.lock().latency_bytes = latency_bytes;
+    }
+
+    /// Makes the next call of `op` fail with `error`.
+    ///
+    /// Failures injected for the same operation are returned in order.
+    pub fn fail_next(&self, op: FakeOp, error: SoundError) {
+        self.state
+            .lock()
+            .failures
+            .entry(op)
+            .or_default()
+            .push_back(error);
+    }
+
+    /// Sets whether the frames played are fed to the opened input streams.
+    pub fn set_loopback(&self, loopback: bool) {
+        self.state.lock().loopback = loopback;
+    }
+
+    /// Queues frames to be returned by the reads of an input stream.
+    pub fn push_capture(&self, stream_id: u32, frames: &[u8]) {
+        self.state.lock().streams[stream_id as usize]
+            .to_capture
+            .extend(frames.iter().copied());
+    }
+
+    /// Returns the frames played on a stream so far.
+    pub fn played(&self, stream_id: u32) -> Vec<u8> {
+        self.state.lock().streams[stream_id as usize].played.clone()
+    }
+
+    /// Makes a period of a stream elapse, playing the frames its playback callback
+    /// fills, and reports it to the event callbacks.
+    ///
+    /// Returns whether the stream has a playback callback.
+    pub fn elapse_period(&self, stream_id: u32) -> bool {
+        let (callback, period_bytes) = {
+            let state = self.state.lock();
+            let stream = &state.streams[stream_id as usize];
+            let Some(callback) = stream.playback_callback.clone() else {
+                return false;
+            };
+            (callback, stream.period_bytes)
+        };
+        // The callback is run without the lock, as a device would run it.
+        let mut period = vec![0u8; period_bytes];
+        callback(VmWriter::from(period.as_mut_slice()));
+        self.state.lock().streams[stream_id as usize]
+            .played
+            .extend_from_slice(&period);
+        self.report_event(SoundEvent::PeriodElapsed { stream_id });
+        true
+    }
+
+    /// Reports an xrun of a stream to the event callbacks.
+    pub fn report_xrun(&self, stream_id: u32) {
+        self.report_event(SoundEvent::Xrun(XrunEvent { stream_id }));
+    }
+
+    /// Plugs something into a jack, or unplugs it, and reports the change.
+    pub fn set_jack(&self, jack_id: u32, connected: bool) {
+        self.state.lock().jacks.insert(jack_id, connected);
+        self.report_event(SoundEvent::Jack(JackState { jack_id, connected }));
+    }
+
+    fn report_event(&self, event: SoundEvent) {
+        let callbacks: Vec<_> = self.event_callbacks.lock().values().cloned().collect();
+        // The callbacks are run without the lock, as a device would run them.
+        for callback in callbacks {
+            callback(event);
+        }
+    }
+
+    pub fn is_running(&self, stream_id: u32) -> bool {
+        self.state.lock().streams[stream_id as usize].running
+    }
+
+    pub fn completion_mode(&self, stream_id: u32) -> CompletionMode {
+        self.state.lock().streams[stream_id as usize].completion_mode
+    }
+
+    pub fn completion_priority(&self) -> CompletionPriority {
+        self.state.lock().completion_priority
+    }
+
+    pub fn dma_quota(&self) -> usize {
+        self.state.lock().dma_quota
+    }
+
+    pub fn jack_auto_pause(&self) -> bool {
+        self.state.lock().jack_auto_pause
+    }
+
+    pub fn is_suspended(&self) -> bool {
+        self.state.lock().suspended
+    }
+
+    /// Returns the channel map selected for a stream, if any.
+    pub fn channel_map(&self, stream_id: u32) -> Option<Vec<u8>> {
+        self.state.lock().streams[stream_id as usize]
+            .channel_map
+            .clone()
+    }
+}
+
+impl FakeState {
+    fn check(&mut self, op: FakeOp) -> Result<(), SoundError> {
+        match self
+            .failures
+            .get_mut(&op)
+            .and_then(|errors| errors.pop_front())
+        {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    fn opened_stream(&mut self, stream_id: u32) -> Result<&mut FakeStream, SoundError> {
+        match self.streams.get_mut(stream_id as usize) {
+            Some(stream) if stream.opened => Ok(stream),
+            _ => Err(SoundError::InvalidParam),
+        }
+    }
+
+    fn enabled_stream(&mut self, stream_id: u32) -> Result<&mut FakeStream, SoundError> {
+        if self.suspended {
+            return Err(SoundError::NotReady);
+        }
+        let stream = self.opened_stream(stream_id)?;
+        if stream.disabled {
+            return Err(SoundError::NotReady);
+        }
+        Ok(stream)
+    }
+}
+
+impl AnySoundDevice for FakeSoundDevice {
+    fn register_event_callback(&self, callback: Arc<EventCallback>) -> CallbackHandle {
+        let id = self.next_event_callback_id.fetch_add(1, Ordering::Relaxed);
+        self.event_callbacks.lock().insert(id, callback);
+
+        let callbacks = Arc::downgrade(&self.event_callbacks);
+        CallbackHandle::new(move || {
+            if let Some(callbacks) = callbacks.upgrade() {
+                callbacks.lock().remove(&id);
+            }
+        })
+    }
+
+    fn capabilities(&self) -> Result<Vec<StreamCapability>, SoundError> {
+        Ok(self.state.lock().capabilities.clone())
+    }
+
+    fn set_completion_mode(&self, stream_id: u32, mode: CompletionMode) -> Result<(), SoundError> {
+        let mut state = self.state.lock();
+        let stream = state
+            .streams
+            .get_mut(stream_id as usize)
+            .ok_or(SoundError::InvalidParam)?;
+        stream.completion_mode = mode;
+        Ok(())
+    }
+
+    fn set_completion_priority(&self, priority: CompletionPriority) {
+        self.state.lock().completion_priority = priority;
+    }
+
+    fn set_dma_quota(&self, bytes: usize) {
+        self.state.lock().dma_quota = bytes;
+    }
+
+    fn jack_states(&self) -> Result<Vec<JackState>, SoundError> {
+        Ok(self
+            .state
+            .lock()
+            .jacks
+            .iter()
+            .map(|(jack_id, connected)| JackState {
+                jack_id: *jack_id,
+                connected: *connected,
+            })
+            .collect())
+    }
+
+    fn set_jack_auto_pause(&self, enabled: bool) {
+        self.state.lock().jack_auto_pause = enabled;
+    }
+
+    fn latency_histogram(&self, stream_id: u32) -> Option<LatencyHistogram> {
+        // Transfers complete immediately, so there is nothing to record.
+        self.state
+            .lock()
+            .streams
+            .get(stream_id as usize)
+            .map(|_| LatencyHistogram::new())
+    }
+
+    fn open_stream(
+        &self,
+        direction: StreamDirection,
+        params: &StreamParams,
+    ) -> Result<u32, SoundError> {
+        let mut state = self.state.lock();
+        state.check(FakeOp::Open)?;
+        if params.buffer_bytes as usize > state.dma_quota {
+            return Err(SoundError::QuotaExceeded);
+        }
+        let stream_id = state
+            .capabilities
+            .iter()
+            .find(|capability| {
+                capability.direction == direction
+                    && !state.streams[capability.stream_id as usize].opened
+                    && !state.streams[capability.stream_id as usize].disabled
+                    && capability.supports(params.format, params.rate, params.channels)
+            })
+            .map(|capability| capability.stream_id)
+            .ok_or(SoundError::InvalidParam)?;
+        let stream = &mut state.streams[stream_id as usize];
+        stream.opened = true;
+        stream.period_bytes = params.period_bytes as usize;
+        stream.frame_bytes = sample_bytes(params.format).unwrap_or(1) * params.channels as usize;
+        stream.channels = params.channels;
+        stream.channel_map = None;
+        stream.played = vec![];
+        Ok(stream_id)
+    }
+
+    fn start_stream(&self, stream_id: u32) -> Result<(), SoundError> {
+        let mut state = self.state.lock();
+        state.check(FakeOp::Start)?;
+        state.enabled_stream(stream_id)?.running = true;
+        Ok(())
+    }
+
+    fn stop_stream(&self, stream_id: u32) -> Result<(), SoundError> {
+        let mut state = self.state.lock();
+        state.check(FakeOp::Stop)?;
+        state.opened_stream(stream_id)?.running = false;
+        Ok(())
+    }
+
+    fn stream_position(&self, stream_id: u32) -> Result<StreamPosition, SoundError> {
+        let state = self.state.lock();
+        let stream = state
+            .streams
+            .get(stream_id as usize)
+            .filter(|stream| stream.opened)
+            .ok_or(SoundError::InvalidParam)?;
+        // The frames still in the pretended latency have not been played yet.
+        let played_bytes = stream
+            .played
+            .len()
+            .saturating_sub(state.latency_bytes as usize);
+        Ok(StreamPosition {
+            frames: (played_bytes / stream.frame_bytes.max(1)) as Frames,
+            // The device has no clock.
+            timestamp: Duration::ZERO,
+        })
+    }
+
+    fn drain_stream(&self, stream_id: u32) -> Result<(), SoundError> {
+        let mut state = self.state.lock();
+        state.check(FakeOp::Drain)?;
+        // Transfers complete immediately, so the stream is drained already.
+        state.opened_stream(stream_id)?.running = false;
+        Ok(())
+    }
+
+    fn close_stream(&self, stream_id: u32) -> Result<(), SoundError> {
+        let mut state = self.state.lock();
+        state.check(FakeOp::Close)?;
+        let stream = state.opened_stream(stream_id)?;
+        stream.opened = false;
+        stream.running = false;
+        stream.playback_callback = None;
+        Ok(())
+    }
+
+    fn disable_stream(&self, stream_id: u32) -> Result<(), SoundError> {
+        let mut state = self.state.lock();
+        let stream = state
+            .streams
+            .get_mut(stream_id as usize)
+            .ok_or(SoundError::InvalidParam)?;
+        stream.disabled = true;
+        stream.running = false;
+        Ok(())
+    }
+
+    fn enable_stream(&self, stream_id: u32) -> Result<(), SoundError> {
+        let mut state = self.state.lock();
+        let stream = state
+            .streams
+            .get_mut(stream_id as usize)
+            .ok_or(SoundError::InvalidParam)?;
+        stream.disabled = false;
+        Ok(())
+    }
+
+    fn suspend(&self) -> Result<(), SoundError> {
+        let mut state = self.state.lock();
+        if state.suspended {
+            return Ok(());
+        }
+        for stream in state.streams.iter_mut() {
+            stream.running_before_suspend = stream.running;
+            stream.running = false;
+        }
+        state.suspended = true;
+        Ok(())
+    }
+
+    fn resume(&self) -> Result<(), SoundError> {
+        let mut state = self.state.lock();
+        if !state.suspended {
+            return Ok(());
+        }
+        for stream in state.streams.iter_mut() {
+            stream.running = stream.opened && stream.running_before_suspend;
+        }
+        state.suspended = false;
+        Ok(())
+    }
+}
+
+impl AudioOutput for FakeSoundDevice {
+    fn register_playback_callback(
+        &self,
+        stream_id: u32,
+        callback: Arc<PlaybackCallback>,
+    ) -> Result<CallbackHandle, SoundError> {
+        let mut state = self.state.lock();
+        state.opened_stream(stream_id)?.playback_callback = Some(callback);
+        // The callback is dropped when the stream is closed.
+        Ok(CallbackHandle::new(|| {}))
+    }
+
+    fn write_stream(&self, stream_id: u32, frames: &[u8]) -> Result<usize, SoundError> {
+        let mut state = self.state.lock();
+        state.check(FakeOp::Write)?;
+        state
+            .enabled_stream(stream_id)?
+            .played
+            .extend_from_slice(frames);
+        let state = &mut *state;
+        if state.loopback {
+            for (capability, stream) in state.capabilities.iter().zip(state.streams.iter_mut()) {
+                if capability.direction == StreamDirection::Input && stream.opened {
+                    stream.to_capture.extend(frames.iter().copied());
+                }
+            }
+        }
+        Ok(frames.len())
+    }
+
+    fn set_channel_map(&self, stream_id: u32, map: &[u8]) -> Result<(), SoundError> {
+        let mut state = self.state.lock();
+        let capability = state
+            .capabilities
+            .get(stream_id as usize)
+            .cloned()
+            .ok_or(SoundError::InvalidParam)?;
+        let stream = state.opened_stream(stream_id)?;
+        if !capability.remappable {
+            return Err(SoundError::Unsupported);
+        }
+        if map.len() != stream.channels as usize
+            || !capability
+                .channel_maps
+                .iter()
+                .any(|channel_map| channel_map == map)
+        {
+            return Err(SoundError::InvalidParam);
+        }
+        stream.channel_map = Some(map.to_vec());
+        Ok(())
+    }
+}
+
+impl AudioInput for FakeSoundDevice {
+    fn register_callback(&self, _callback: Arc<SoundCallback>) -> CallbackHandle {
+        // The callbacks are never invoked, since the device records nothing by itself.
+        CallbackHandle::new(|| {})
+    }
+
+    fn capture_overrun_bytes(&self) -> u64 {
+        // The frames to capture are queued without bound.
+        0
+    }
+
+    fn read_stream(&self, stream_id: u32, frames: &mut [u8]) -> Result<usize, SoundError> {
+        let mut state = self.state.lock();
+        state.check(FakeOp::Read)?;
+        let stream = state.enabled_stream(stream_id)?;
+        let len = frames.len().min(stream.to_capture.len());
+        for (dst, src) in frames.iter_mut().zip(stream.to_capture.drain(..len)) {
+            *dst = src;
+        }
+        Ok(len)
+    }
+
+    fn record_nb(&self, stream_id: u32, len: usize) -> Result<RecordToken, SoundError> {
+        let mut state = self.state.lock();
+        state.check(FakeOp::Read)?;
+        state.enabled_stream(stream_id)?;
+        let token = RecordToken(state.next_record_token);
+        state.next_record_token = state.next_record_token.wrapping_add(1);
+        state.records.insert(token, (stream_id, len));
+        Ok(token)
+    }
+
+    fn record_poll(
+        &self,
+        token: RecordToken,
+        frames: &mut [u8],
+    ) -> Option<Result<usize, SoundError>> {
+        let mut state = self.state.lock();
+        // A request completes once the scripted frames can fill it.
+        let (stream_id, len) = *state.records.get(&token)?;
+        if state.streams[stream_id as usize].to_capture.len() < len {
+            return None;
+        }
+        state.records.remove(&token);
+        let stream = &mut state.streams[stream_id as usize];
+        let len = len.min(frames.len());
+        for (dst, src) in frames.iter_mut().zip(stream.to_capture.drain(..len)) {
+            *dst = src;
+        }
+        Some(Ok(len))
+    }
+}
+
+#[cfg(ktest)]
+mod test {
+    use ostd::{mm::Infallible, prelude::*};
+
+    use super::*;
+    use crate::{open_output, SampleFormat};
+
+    const PARAMS: StreamParams = StreamParams {
+        format: SampleFormat::S16,
+        rate: 48000,
+        channels: 2,
+        buffer_bytes: 4096,
+        period_bytes: 1024,
+    };
+
+    #[ktest]
+    fn write_through_output_stream() {
+        let capability = FakeSoundDevice::capability(0, StreamDirection::Output);
+        let fake = Arc::new(FakeSoundDevice::new(vec![capability]));
+        let device: Arc<dyn AudioOutput> = fake.clone();
+
+        let mut stream = open_output(&device, PARAMS).unwrap();
+        stream.start().unwrap();
+        assert_eq!(stream.write(&[1, 2, 3, 4]).unwrap(), 4);
+        assert_eq!(stream.position(), 4);
+        assert!(fake.is_running(0));
+        assert_eq!(fake.played(0), [1, 2, 3, 4]);
+
+        // The only output stream is taken until the handle is dropped.
+        assert!(open_output(&device, PARAMS).is_err());
+        drop(stream);
+        assert!(open_output(&device, PARAMS).is_ok());
+    }
+
+    #[ktest]
+    fn drain_stops_stream() {
+        let capability = FakeSoundDevice::capability(0, StreamDirection::Output);
+        let fake = Arc::new(FakeSoundDevice::new(vec![capability]));
+        let device: Arc<dyn AudioOutput> = fake.clone();
+
+        let mut stream = open_output(&device, PARAMS).unwrap();
+        stream.start().unwrap();
+        stream.write(&[0; 8]).unwrap();
+        stream.drain().unwrap();
+        assert!(!fake.is_running(0));
+        assert_eq!(fake.played(0), [0; 8]);
+    }
+
+    #[ktest]
+    fn channel_map_remap() {
+        use crate::mix::position::{FL, FR};
+
+        let mut capability = FakeSoundDevice::capability(0, StreamDirection::Output);
+        capability.channel_maps = vec![vec![FL, FR], vec![FR, FL]];
+        let fake = Arc::new(FakeSoundDevice::new(vec![capability.clone()]));
+        let device: Arc<dyn AudioOutput> = fake.clone();
+
+        // The device cannot remap, so the channels are swapped in software.
+        let mut stream = open_output(&device, PARAMS).unwrap();
+        stream.set_channel_map(&[FR, FL]).unwrap();
+        stream.write(&[1, 2, 3, 4]).unwrap();
+        assert_eq!(fake.played(0), [3, 4, 1, 2]);
+        assert_eq!(fake.channel_map(0), None);
+        drop(stream);
+
+        capability.remappable = true;
+        let fake = Arc::new(FakeSoundDevice::new(vec![capability]));
+        let device: Arc<dyn AudioOutput> = fake.clone();
+        let mut stream = open_output(&device, PARAMS).unwrap();
+        stream.set_channel_map(&[FR, FL]).unwrap();
+        stream.write(&[1, 2, 3, 4]).unwrap();
+        assert_eq!(fake.played(0), [1, 2, 3, 4]);
+        assert_eq!(fake.channel_map(0), Some(vec![FR, FL]));
+    }
+
+    #[ktest]
+    fn injected_failures() {
+        let capability = FakeSoundDevice::capability(0, StreamDirection::Output);
+        let fake = Arc::new(FakeSoundDevice::new(vec![capability]));
+        let device: Arc<dyn AudioOutput> = fake.clone();
+        fake.fail_next(FakeOp::Write, SoundError::IoError);
+
+        let mut stream = open_output(&device, PARAMS).unwrap();
+        assert_eq!(stream.write(&[0; 8]), Err(SoundError::IoError));
+        assert_eq!(stream.write(&[0; 8]), Ok(8));
+        assert_eq!(stream.position(), 8);
+    }
+
+    #[ktest]
+    fn pull_mode_playback() {
+        let capability = FakeSoundDevice::capability(0, StreamDirection::Output);
+        let fake = Arc::new(FakeSoundDevice::new(vec![capability]));
+        let device: Arc<dyn AudioOutput> = fake.clone();
+
+        let _stream = open_output(&device, PARAMS).unwrap();
+        let _handle = device
+            .register_playback_callback(
+                0,
+                Arc::new(|mut writer: VmWriter<Infallible>| {
+                    writer.fill(0x5au8);
+                }),
+            )
+            .unwrap();
+        assert!(fake.elapse_period(0));
+        assert_eq!(fake.played(0), [0x5a; 1024]);
+    }
+
+    #[ktest]
+    fn xrun_events() {
+        let capability = FakeSoundDevice::capability(0, StreamDirection::Output);
+        let fake = FakeSoundDevice::new(vec![capability]);
+        let xruns = Arc::new(SpinLock::new(Vec::new()));
+        let handle = {
+            let xruns = xruns.clone();
+            fake.register_event_callback(Arc::new(move |event| {
+                if let SoundEvent::Xrun(xrun) = event {
+                    xruns.lock().push(xrun.stream_id)
+                }
+            }))
+        };
+
+        fake.report_xrun(0);
+        assert_eq!(*xruns.lock(), [0]);
+        // The callback is unregistered with its handle.
+        drop(handle);
+        fake.report_xrun(0);
+        assert_eq!(*xruns.lock(), [0]);
+    }
+
+    #[ktest]
+    fn jack_events() {
+        let mut capability = FakeSoundDevice::capability(0, StreamDirection::Output);
+        capability.jacks = vec![0];
+        let fake = FakeSoundDevice::new(vec![capability]);
+        let events = Arc::new(SpinLock::new(Vec::new()));
+        let _handle = {
+            let events = events.clone();
+            fake.register_event_callback(Arc::new(move |event| events.lock().push(event)))
+        };
+
+        let unplugged = JackState {
+            jack_id: 0,
+            connected: false,
+        };
+        assert_eq!(
+            fake.jack_states().unwrap(),
+            [JackState {
+                jack_id: 0,
+                connected: true
+            }]
+        );
+        fake.set_jack(0, false);
+        assert_eq!(fake.jack_states().unwrap(), [unplugged]);
+        assert_eq!(*events.lock(), [SoundEvent::Jack(unplugged)]);
+    }
+
+    #[ktest]
+    fn disabled_stream() {
+        let capability = FakeSoundDevice::capability(0, StreamDirection::Output);
+        let fake = Arc::new(FakeSoundDevice::new(vec![capability]));
+        let device: Arc<dyn AudioOutput> = fake.clone();
+
+        let mut stream = open_output(&device, PARAMS).unwrap();
+        stream.start().unwrap();
+        device.disable_stream(0).unwrap();
+        assert!(!fake.is_running(0));
+        assert_eq!(stream.write(&[0; 8]), Err(SoundError::NotReady));
+
+        drop(stream);
+        assert!(open_output(&device, PARAMS).is_err());
+        device.enable_stream(0).unwrap();
+        assert!(open_output(&device, PARAMS).is_ok());
+    }
+
+    #[ktest]
+    fn suspend_and_resume() {
+        let capability = FakeSoundDevice::capability(0, StreamDirection::Output);
+        let fake = FakeSoundDevice::new(vec![capability]);
+        let stream_id = fake.open_stream(StreamDirection::Output, &PARAMS).unwrap();
+        fake.start_stream(stream_id).unwrap();
+
+        fake.suspend().unwrap();
+        assert!(!fake.is_running(stream_id));
+        assert_eq!(
+            fake.write_stream(stream_id, &[0; 4]),
+            Err(SoundError::NotReady)
+        );
+
+        fake.resume().unwrap();
+        assert!(fake.is_running(stream_id));
+        assert_eq!(fake.write_stream(stream_id, &[0; 4]), Ok(4));
+    }
+
+    #[ktest]
+    fn position_excludes_latency() {
+        let capability = FakeSoundDevice::capability(0, StreamDirection::Output);
+        let fake = FakeSoundDevice::new(vec![capability]);
+        fake.set_latency_bytes(4);
+        let stream_id = fake.open_stream(StreamDirection::Output, &PARAMS).unwrap();
+        fake.write_stream(stream_id, &[0; 12]).unwrap();
+
+        // Two of the three stereo S16 frames have been played.
+        let position = fake.stream_position(stream_id).unwrap();
+        assert_eq!(position.frames, 2);
+    }
+}
@@ -0,0 +1,316 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! The integration test suite of the sound drivers.
+//!
+//! The suite is run on every registered device when the kernel boots with
+//! `sound.integration_test` on its command line. It goes through the whole
+//! matrix of stream parameters the device advertises, the ordering of the
+//! stream operations and transfers of various sizes, then records a tone
+//! played on the loopback device. Each case yields a [`CaseResult`], so that
+//! a regression shows up in the QEMU log instead of being heard.
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
+use core::fmt;
+
+use crate::{
+    convert::sample_bytes, loopback::LOOPBACK_STABLE_ID, verify::verify_capture_path,
+    AnySoundDevice, AudioOutput, DeviceInfo, SampleFormat, SoundError, StreamCapability,
+    StreamDirection, StreamParams,
+};
+
+/// The outcome of a test case.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Passed,
+    Failed(SoundError),
+    /// The device does not advertise what the case needs.
+    Skipped,
+}
+
+/// The result of a test case run on a device.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaseResult {
+    /// The stable ID of the device.
+    pub device: String,
+    /// The name of the case, e.g., `set_params/0/S16/48000`.
+    pub case: String,
+    pub outcome: Outcome,
+}
+
+impl fmt::Display for CaseResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.outcome {
+            Outcome::Passed => write!(f, "{} {} ok", self.device, self.case),
+            Outcome::Failed(error) => {
+                write!(f, "{} {} FAILED ({:?})", self.device, self.case, error)
+            }
+            Outcome::Skipped => write!(f, "{} {} skipped", self.device, self.case),
+        }
+    }
+}
+
+/// The results of a run of the suite.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntegrationReport {
+    pub results: Vec<CaseResult>,
+}
+
+impl IntegrationReport {
+    /// Returns the number of cases with the given outcome.
+    pub fn count(&self, outcome: Outcome) -> usize {
+        self.results
+            .iter()
+            .filter(|result| result.outcome == outcome)
+            .count()
+    }
+
+    /// Returns the cases that failed.
+    pub fn failures(&self) -> impl Iterator<Item = &CaseResult> {
+        self.results
+            .iter()
+            .filter(|result| matches!(result.outcome, Outcome::Failed(_)))
+    }
+
+    /// Returns whether no case failed.
+    pub fn passed(&self) -> bool {
+        self.failures().next().is_none()
+    }
+
+    fn record(&mut self, device: &str, case: String, result: Result<(), SoundError>) {
+        let outcome = match result {
+            Ok(()) => Outcome::Passed,
+            Err(SoundError::Unsupported) => Outcome::Skipped,
+            Err(error) => Outcome::Failed(error),
+        };
+        self.results.push(CaseResult {
+            device: device.to_string(),
+            case,
+            outcome,
+        });
+    }
+}
+
+/// The sizes of the transfers, in periods of the stream.
+///
+/// A stray frame and a partial period come first, then whole periods up to
+/// several times the buffer.
+const TRANSFER_SIZES: [(usize, usize); 5] = [(0, 1), (1, 2), (1, 1), (4, 1), (12, 1)];
+
+/// Runs the suite on every registered device, in card order.
+pub fn run_integration_tests() -> IntegrationReport {
+    let mut report = IntegrationReport::default();
+    for info in crate::device_infos() {
+        run_device_tests(&mut report, &info);
+    }
+    if let Some(DeviceInfo {
+        output: Some(output),
+        input: Some(input),
+        ..
+    }) = crate::get_device_info(LOOPBACK_STABLE_ID)
+    {
+        let result = verify_capture_path(&output, &input).map(|_| ());
+        report.record(LOOPBACK_STABLE_ID, "capture_round_trip".to_string(), result);
+    }
+    report
+}
+
+/// Runs the cases of the suite that apply to any device.
+///
+/// Transfers are only tested in the directions the device was registered with.
+pub fn run_device_tests(report: &mut IntegrationReport, info: &DeviceInfo) {
+    let name = info.stable_id.as_str();
+    let device = &info.device;
+    let capabilities = match device.capabilities() {
+        Ok(capabilities) => capabilities,
+        Err(error) => {
+            report.record(name, "capabilities".to_string(), Err(error));
+            return;
+        }
+    };
+    for capability in capabilities.iter() {
+        let stream_id = capability.stream_id;
+        for format in capability.formats.iter() {
+            for rate in capability.rates.iter() {
+                let case = format!("set_params/{}/{:?}/{}", stream_id, format, rate);
+                let result = test_params(device, capability, *format, *rate);
+                report.record(name, case, result);
+            }
+        }
+        let Some(params) = default_params(capability) else {
+            continue;
+        };
+        let result = test_lifecycle(device, capability.direction, &params);
+        report.record(name, format!("lifecycle/{}", stream_id), result);
+        let Some(output) = info
+            .output
+            .as_ref()
+            .filter(|_| capability.direction == StreamDirection::Output)
+        else {
+            continue;
+        };
+        for (periods, divisor) in TRANSFER_SIZES {
+            let len = transfer_len(&params, periods, divisor);
+            let result = test_transfer(output, &params, len);
+            report.record(name, format!("xfer/{}/{}", stream_id, len), result);
+        }
+    }
+}
+
+/// Returns parameters of 40 ms in periods of 10 ms, or `None` for formats of
+/// unknown sample width.
+fn stream_params(format: SampleFormat, rate: u32, channels: u8) -> Option<StreamParams> {
+    let frame_bytes = sample_bytes(format)? as u32 * channels as u32;
+    let period_bytes = frame_bytes * (rate / 100).max(1);
+    Some(StreamParams {
+        format,
+        rate,
+        channels,
+        buffer_bytes: period_bytes * 4,
+        period_bytes,
+    })
+}
+
+/// Returns the parameters the lifecycle and transfer cases run a stream with.
+fn default_params(capability: &StreamCapability) -> Option<StreamParams> {
+    let channels = *capability.channels.start();
+    capability
+        .formats
+        .iter()
+        .flat_map(|format| {
+            capability
+                .rates
+                .iter()
+                .map(move |rate| stream_params(*format, *rate, channels))
+        })
+        .flatten()
+        .next()
+}
+
+fn transfer_len(params: &StreamParams, periods: usize, divisor: usize) -> usize {
+    let frame_bytes = sample_bytes(params.format).unwrap() * params.channels as usize;
+    if periods == 0 {
+        return frame_bytes;
+    }
+    let len = params.period_bytes as usize * periods / divisor;
+    len - len % frame_bytes
+}
+
+/// Opens a stream with the parameters, then closes it.
+fn test_params(
+    device: &Arc<dyn AnySoundDevice>,
+    capability: &StreamCapability,
+    format: SampleFormat,
+    rate: u32,
+) -> Result<(), SoundError> {
+    let params =
+        stream_params(format, rate, *capability.channels.start()).ok_or(SoundError::Unsupported)?;
+    let stream_id = device.open_stream(capability.direction, &params)?;
+    device.close_stream(stream_id)
+}
+
+/// Goes through the operations of a stream in order, twice, and checks that a
+/// closed stream can no longer be started.
+fn test_lifecycle(
+    device: &Arc<dyn AnySoundDevice>,
+    direction: StreamDirection,
+    params: &StreamParams,
+) -> Result<(), SoundError> {
+    for _ in 0..2 {
+        let stream_id = device.open_stream(direction, params)?;
+        let result = device
+            .start_stream(stream_id)
+            .and_then(|()| device.stop_stream(stream_id))
+            .and_then(|()| device.start_stream(stream_id))
+            .and_then(|()| device.stop_stream(stream_id));
+        device.close_stream(stream_id)?;
+        result?;
+        if device.start_stream(stream_id).is_ok() {
+            return Err(SoundError::InvalidParam);
+        }
+    }
+    Ok(())
+}
+
+/// Plays `len` bytes of silence on an output stream and waits for them.
+fn test_transfer(
+    device: &Arc<dyn AudioOutput>,
+    params: &StreamParams,
+    len: usize,
+) -> Result<(), SoundError> {
+    let stream_id = device.open_stream(StreamDirection::Output, params)?;
+    let result = play_silence(device, stream_id, params, len);
+    device.close_stream(stream_id)?;
+    result
+}
+
+fn play_silence(
+    device: &Arc<dyn AudioOutput>,
+    stream_id: u32,
+    params: &StreamParams,
+    len: usize,
+) -> Result<(), SoundError> {
+    let mut frames = vec![0u8; len];
+    crate::convert::fill_silence(params.format, &mut frames);
+    device.start_stream(stream_id)?;
+    let mut written = 0;
+    while written < len {
+        match device.write_stream(stream_id, &frames[written..])? {
+            0 => return Err(SoundError::IoError),
+            n => written += n,
+        }
+    }
+    // Draining stops the stream.
+    device.drain_stream(stream_id)
+}
+
+#[cfg(ktest)]
+mod test {
+    use ostd::prelude::*;
+
+    use super::*;
+    use crate::fake::FakeSoundDevice;
+
+    fn capability(stream_id: u32, direction: StreamDirection) -> StreamCapability {
+        StreamCapability {
+            formats: vec![SampleFormat::U8, SampleFormat::S16, SampleFormat::MuLaw],
+            rates: vec![44100, 48000],
+            ..FakeSoundDevice::capability(stream_id, direction)
+        }
+    }
+
+    #[ktest]
+    fn run_suite_on_fake_device() {
+        let fake = Arc::new(FakeSoundDevice::new(vec![
+            capability(0, StreamDirection::Output),
+            capability(1, StreamDirection::Input),
+        ]));
+        let info = DeviceInfo {
+            name: "Fake".to_string(),
+            stable_id: "fake".to_string(),
+            device: fake.clone(),
+            output: Some(fake.clone()),
+            input: Some(fake),
+        };
+        let mut report = IntegrationReport::default();
+        run_device_tests(&mut report, &info);
+
+        assert!(report.passed());
+        // The µ-law cases are skipped, since its sample width is unknown.
+        assert_eq!(report.count(Outcome::Skipped), 4);
+        // 4 parameter cases and a lifecycle case per stream, then the transfers.
+        assert_eq!(
+            report.count(Outcome::Passed),
+            2 * 4 + 2 + TRANSFER_SIZES.len()
+        );
+        assert_eq!(
+            report.results[0].to_string(),
+            "fake set_params/0/U8/44100 ok"
+        );
+    }
+}
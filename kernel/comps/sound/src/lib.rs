@@ -7,46 +7,812 @@
 
 extern crate alloc;
 
-use alloc::{collections::BTreeMap, fmt::Debug, string::String, sync::Arc, vec::Vec};
-use core::any::Any;
+pub mod beep;
+pub mod capability;
+pub mod convert;
+pub mod fake;
+pub mod integration;
+pub mod loopback;
+pub mod metrics;
+pub mod mix;
+pub mod null;
+pub mod policy;
+pub mod resample;
+pub mod ring;
+pub mod routing;
+pub mod stream;
+pub mod tone;
+pub mod verify;
+
+use alloc::{
+    boxed::Box,
+    collections::BTreeMap,
+    fmt::Debug,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use core::{
+    any::Any,
+    fmt,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
 
 use component::{init_component, ComponentInitError};
 use ostd::{
     // mm::{Infallible, VmReader},
-    mm::{Infallible, VmReader},
-    sync::SpinLock,
+    mm::{Infallible, VmReader, VmWriter},
+    sync::{LocalIrqDisabled, RwLock, SpinLock},
 };
 use spin::Once;
 
+pub use self::{
+    capability::{CapabilitiesSnapshot, SampleFormat, StreamCapability, StreamDirection},
+    loopback::LoopbackSoundDevice,
+    metrics::LatencyHistogram,
+    null::NullSoundDevice,
+    ring::CaptureRing,
+    stream::{
+        open_input, open_input_with_priority, open_output, open_output_with_priority, InputStream,
+        OutputStream, StreamParams,
+    },
+    tone::ToneSoundDevice,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SoundError {
+    /// The stream or device is not ready for the operation.
+    NotReady,
+    /// The given parameters are not accepted by the device.
+    InvalidParam,
+    /// The device reported an error.
+    IoError,
+    /// The stream would use more DMA memory than allowed.
+    QuotaExceeded,
+    /// The operation is not supported by the device.
+    Unsupported,
+    /// Capture is blocked by the kill-switch.
+    CaptureBlocked,
+    /// Every stream is claimed by clients that cannot be preempted.
+    Busy,
+    /// The stream was preempted by a client of higher priority.
+    Preempted,
+    /// The stream underran or overran, and must be recovered before it is used again.
+    Xrun,
+    /// The operation cannot complete until the device catches up, and should be retried.
+    WouldBlock,
+}
+
+/// How the completion of submitted PCM transfers is detected.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CompletionMode {
+    /// Sleep until the device raises an interrupt.
+    #[default]
+    Interrupt,
+    /// Poll the device, trading CPU time for latency.
+    Polling,
+}
+
+/// The priority at which the deferred processing of transfer completions runs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CompletionPriority {
+    /// Run along with the other deferred work of the system.
+    #[default]
+    Normal,
+    /// Run ahead of the other deferred work, so that loaded CPUs do not cause underruns.
+    Boosted,
+}
+
+/// What the capture streams get while capture is blocked.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CaptureBlockMode {
+    /// The recorded frames are replaced by silence.
+    #[default]
+    Silence,
+    /// Reads fail with [`SoundError::CaptureBlocked`] and no frames reach the callbacks.
+    Error,
+}
+
+/// The privacy state of capture across all sound devices.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CapturePrivacy {
+    /// Whether capture is blocked by the kill-switch.
+    pub blocked: bool,
+    pub mode: CaptureBlockMode,
+    /// Whether any input stream is running, i.e., whether the privacy indicator is lit.
+    pub active: bool,
+}
+
+/// Called with the new privacy state whenever it changes.
+pub type PrivacyObserver = dyn Fn(CapturePrivacy) + Send + Sync;
+
+/// Identifies a record request submitted by [`AudioInput::record_nb`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RecordToken(pub u32);
+
+/// A number of PCM frames.
+pub type Frames = u64;
+
+/// How far a stream has been played or recorded at a point in time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StreamPosition {
+    /// The frames that have left the speaker, or entered the microphone, since
+    /// the stream was opened.
+    pub frames: Frames,
+    /// The monotonic time at which `frames` was reached.
+    pub timestamp: Duration,
+}
+
 pub type SoundCallback = dyn Fn(VmReader<Infallible>) + Send + Sync;
 
+/// Fills the next period of an output stream with frames.
+///
+/// The writer covers exactly one period and the bytes left unwritten are played as
+/// silence. The callback may be invoked in interrupt context, so it must not sleep.
+pub type PlaybackCallback = dyn Fn(VmWriter<Infallible>) + Send + Sync;
+
+/// An underrun of an output stream, or an overrun of an input stream, reported by a device.
+///
+/// Frames were lost or a gap of silence was played, so a client keeping the
+/// stream in sync with other media should resynchronize it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct XrunEvent {
+    pub stream_id: u32,
+}
+
+/// Whether a jack of a device, e.g., a headphone jack, has something plugged in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JackState {
+    pub jack_id: u32,
+    pub connected: bool,
+}
+
+/// An event reported by a device.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SoundEvent {
+    /// A period of a stream has been played or captured.
+    PeriodElapsed {
+        stream_id: u32,
+    },
+    Xrun(XrunEvent),
+    /// A jack was connected or disconnected.
+    Jack(JackState),
+    /// The streams, jacks or channel maps of the device changed.
+    ///
+    /// The capabilities and jack states should be queried again.
+    ConfigChanged,
+}
+
+/// A change of the devices registered to the component, identified by their stable IDs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HotplugEvent {
+    /// A device was registered, or registered again in place of a device with
+    /// the same stable ID.
+    Added(String),
+    /// The streams, jacks or channel maps of a registered device changed.
+    Changed(String),
+    /// A device was unregistered.
+    Removed(String),
+}
+
+/// Called with the changes of the registered devices.
+///
+/// The observer may be invoked in interrupt context, so it must not sleep.
+pub type HotplugObserver = dyn Fn(&HotplugEvent) + Send + Sync;
+
+/// Called with the events reported by a device.
+///
+/// The callback may be invoked in interrupt context, so it must not sleep.
+pub type EventCallback = dyn Fn(SoundEvent) + Send + Sync;
+
+/// Keeps a callback registered to a sound device.
+///
+/// The callback is unregistered when the handle is dropped.
+#[must_use]
+pub struct CallbackHandle {
+    unregister: Option<Box<dyn FnOnce() + Send + Sync>>,
+}
+
+impl CallbackHandle {
+    /// Creates a handle that runs `unregister` when dropped.
+    pub fn new(unregister: impl FnOnce() + Send + Sync + 'static) -> Self {
+        Self {
+            unregister: Some(Box::new(unregister)),
+        }
+    }
+}
+
+impl Debug for CallbackHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CallbackHandle").finish_non_exhaustive()
+    }
+}
+
+impl Drop for CallbackHandle {
+    fn drop(&mut self) {
+        if let Some(unregister) = self.unregister.take() {
+            unregister();
+        }
+    }
+}
+
+/// A sound device.
+///
+/// The methods take `&self` and devices synchronize their state themselves, so
+/// that e.g. a capability query does not wait for a transfer in progress.
+///
+/// This trait holds what all devices share; moving frames is done through
+/// [`AudioOutput`] and [`AudioInput`], so that a device only implements the
+/// directions it has.
 pub trait AnySoundDevice: Send + Sync + Any + Debug {
+    /// Exercises the device, e.g., by playing a short tone, and reports whether it works.
+    ///
+    /// This is never done implicitly; it is meant for debugging, on request.
+    /// Devices without hardware have nothing to test.
+    fn self_test(&self) -> Result<(), SoundError> {
+        Ok(())
+    }
+
+    /// Registers a callback invoked with the events of the device, e.g., the xruns
+    /// of its streams or the changes of its jacks.
+    ///
+    /// Devices that cannot detect such events never invoke the callback.
+    fn register_event_callback(&self, _callback: Arc<EventCallback>) -> CallbackHandle {
+        CallbackHandle::new(|| {})
+    }
+
+    /// Returns the capabilities of every PCM stream of the device.
+    fn capabilities(&self) -> Result<Vec<StreamCapability>, SoundError>;
+
+    /// Returns the capabilities of every PCM stream of the device in one snapshot.
+    ///
+    /// Devices may cache the snapshot until [`AnySoundDevice::invalidate_capabilities`]
+    /// is called, so that repeated queries do not reach the device.
+    fn capabilities_snapshot(&self) -> Result<CapabilitiesSnapshot, SoundError> {
+        Ok(CapabilitiesSnapshot::new(self.capabilities()?))
+    }
+
+    /// Drops the capabilities cached by the device, e.g., after its configuration changed.
+    fn invalidate_capabilities(&self) -> Result<(), SoundError> {
+        Ok(())
+    }
+
+    /// Switches the way transfer completions of a stream are waited for.
+    fn set_completion_mode(&self, stream_id: u32, mode: CompletionMode) -> Result<(), SoundError>;
+
+    /// Sets the priority of the deferred work that processes the transfer completions.
+    fn set_completion_priority(&self, priority: CompletionPriority);
+
+    /// Limits the DMA memory, in bytes, that all streams of the device may consume.
+    fn set_dma_quota(&self, bytes: usize);
+
+    /// Returns whether each jack of the device is connected.
+    ///
+    /// Devices without jacks return no states.
+    fn jack_states(&self) -> Result<Vec<JackState>, SoundError> {
+        Ok(Vec::new())
+    }
+
+    /// Sets whether output streams are paused while all their jacks are disconnected.
+    fn set_jack_auto_pause(&self, enabled: bool);
+
+    /// Returns the submission-to-completion latencies of the periods of a stream.
+    fn latency_histogram(&self, stream_id: u32) -> Option<LatencyHistogram>;
+
+    // ==================Stream Operation===================
+
+    /// Claims a free stream of the given direction and configures it with `params`.
+    ///
+    /// Returns the identifier of the claimed stream.
+    fn open_stream(
+        &self,
+        direction: StreamDirection,
+        params: &StreamParams,
+    ) -> Result<u32, SoundError>;
+
+    fn start_stream(&self, stream_id: u32) -> Result<(), SoundError>;
+
+    fn stop_stream(&self, stream_id: u32) -> Result<(), SoundError>;
+
+    /// Returns the position of the stream, so that other media can be synchronized with it.
+    fn stream_position(&self, stream_id: u32) -> Result<StreamPosition, SoundError>;
+
+    /// Returns the time the device takes to play, or to hand over, the frames it
+    /// has buffered for the stream, as last reported by the device.
+    ///
+    /// Fails with [`SoundError::Unsupported`] if the device does not report its latency.
+    fn stream_latency(&self, _stream_id: u32) -> Result<Duration, SoundError> {
+        Err(SoundError::Unsupported)
+    }
+
+    /// Returns the position of each channel of an opened stream, as reported by
+    /// the device.
+    ///
+    /// Fails with [`SoundError::Unsupported`] if the device reports no channel map
+    /// for the stream.
+    fn channel_map(&self, _stream_id: u32) -> Result<Vec<u8>, SoundError> {
+        Err(SoundError::Unsupported)
+    }
+
+    /// Waits until the device has consumed every period queued on the stream,
+    /// then stops it.
+    ///
+    /// The caller sleeps instead of spinning if the device is able to wake it up.
+    fn drain_stream(&self, stream_id: u32) -> Result<(), SoundError>;
+
+    /// Stops the stream if needed and gives it back to the device.
+    fn close_stream(&self, stream_id: u32) -> Result<(), SoundError>;
+
+    // ==================Stream Management===================
+
+    /// Prevents the stream from being opened, e.g., to mute a capture device.
+    ///
+    /// A current user of the stream has its pending transfers drained and the stream
+    /// stopped, then gets [`SoundError::NotReady`] until it closes the stream.
+    fn disable_stream(&self, stream_id: u32) -> Result<(), SoundError>;
+
+    /// Lets a stream disabled by [`AnySoundDevice::disable_stream`] be used again.
+    fn enable_stream(&self, stream_id: u32) -> Result<(), SoundError>;
+
+    // ==================Power Management===================
+
+    /// Quiesces the device before it loses power.
+    ///
+    /// The opened streams are stopped and their parameters saved. Until the
+    /// device is resumed, using a stream fails with [`SoundError::NotReady`].
+    fn suspend(&self) -> Result<(), SoundError>;
+
+    /// Restores the streams saved by [`AnySoundDevice::suspend`], restarting
+    /// those that were running.
+    fn resume(&self) -> Result<(), SoundError>;
+}
+
+/// A sound device with output streams.
+pub trait AudioOutput: AnySoundDevice {
+    /// Plays PCM frames on an output stream, returning the number of bytes written.
+    fn write_stream(&self, stream_id: u32, frames: &[u8]) -> Result<usize, SoundError>;
 
     /// 注册播放回调
-    // fn register_playback_callback(&self, callback: &'static SoundCallback);
-    fn test_device(&mut self);
+    ///
+    /// The callback is invoked each time a period of the opened output stream
+    /// has elapsed, to fill the next period, so that frames can be produced
+    /// just in time instead of being written in advance.
+    fn register_playback_callback(
+        &self,
+        stream_id: u32,
+        callback: Arc<PlaybackCallback>,
+    ) -> Result<CallbackHandle, SoundError>;
 
+    /// Switches the channel map of an opened stream to `map`, one of the channel
+    /// maps of its capability with as many channels as the stream.
+    ///
+    /// Fails with [`SoundError::Unsupported`] unless the capability of the stream
+    /// is [`remappable`](StreamCapability::remappable).
+    fn set_channel_map(&self, _stream_id: u32, _map: &[u8]) -> Result<(), SoundError> {
+        Err(SoundError::Unsupported)
+    }
+
+    /// Plays `data` on a free output stream configured with `params`, returning
+    /// once it has been played.
+    ///
+    /// The stream is claimed and started, then stopped and released once the data
+    /// has been played or playing it fails. Fails with [`SoundError::Unsupported`]
+    /// if the device does not manage the lifecycle of the stream itself.
+    fn play(&self, _data: &[u8], _params: &StreamParams) -> Result<(), SoundError> {
+        Err(SoundError::Unsupported)
+    }
+
+    /// Returns the number of silent frames played on an output stream because
+    /// its periods were not written in time.
+    ///
+    /// A client can compare it between two writes to detect a gap in the playback.
+    fn silence_frames(&self, _stream_id: u32) -> Frames {
+        0
+    }
+}
+
+/// A sound device with input streams.
+pub trait AudioInput: AnySoundDevice {
     /// 注册录制回调
-    fn register_callback(&self, callback: &'static SoundCallback);
+    fn register_callback(&self, callback: Arc<SoundCallback>) -> CallbackHandle;
+
+    /// Records PCM frames from an input stream, returning the number of bytes read.
+    fn read_stream(&self, stream_id: u32, frames: &mut [u8]) -> Result<usize, SoundError>;
+
+    /// Asks the device to record `len` bytes of an input stream without waiting for them.
+    ///
+    /// The recorded frames are collected with [`AudioInput::record_poll`].
+    fn record_nb(&self, stream_id: u32, len: usize) -> Result<RecordToken, SoundError>;
+
+    /// Collects the frames of a record request into `frames`.
+    ///
+    /// Returns `None` if the device has not completed the request yet, and the
+    /// number of bytes recorded otherwise. A completed request is forgotten.
+    fn record_poll(
+        &self,
+        token: RecordToken,
+        frames: &mut [u8],
+    ) -> Option<Result<usize, SoundError>>;
+
+    /// Returns the number of captured bytes dropped because no one read them in time.
+    fn capture_overrun_bytes(&self) -> u64;
 }
 
-pub fn register_device(name: String, device: Arc<SpinLock<dyn AnySoundDevice>>) {
+/// A sound device with both output and input streams.
+pub trait AudioDevice: AudioOutput + AudioInput {}
+
+impl<T: AudioOutput + AudioInput + ?Sized> AudioDevice for T {}
+
+/// A registered sound device.
+#[derive(Debug, Clone)]
+pub struct DeviceInfo {
+    /// The name of the device, e.g., `Virtio-Sound-0`.
+    ///
+    /// A driver numbers its devices, so that several devices of the same driver
+    /// can be told apart.
+    pub name: String,
+    /// An identifier derived from where the device sits on its bus, e.g.,
+    /// `pci-0000:00:04.0`.
+    ///
+    /// It stays the same across boots, so it can key the persistent
+    /// configuration of a device.
+    pub stable_id: String,
+    pub device: Arc<dyn AnySoundDevice>,
+    /// The device as an [`AudioOutput`], if it has output streams.
+    pub output: Option<Arc<dyn AudioOutput>>,
+    /// The device as an [`AudioInput`], if it has input streams.
+    pub input: Option<Arc<dyn AudioInput>>,
+}
+
+impl DeviceInfo {
+    /// Returns whether the device was registered with streams of `direction`.
+    pub fn has_direction(&self, direction: StreamDirection) -> bool {
+        match direction {
+            StreamDirection::Output => self.output.is_some(),
+            StreamDirection::Input => self.input.is_some(),
+        }
+    }
+}
+
+/// Registers a device with both output and input streams, replacing any device
+/// registered with the same `stable_id`.
+pub fn register_device<D: AudioDevice>(name: String, stable_id: String, device: Arc<D>) {
+    insert_device(DeviceInfo {
+        name,
+        stable_id,
+        device: device.clone(),
+        output: Some(device.clone()),
+        input: Some(device),
+    });
+}
+
+/// Registers a device with output streams only, replacing any device registered
+/// with the same `stable_id`.
+pub fn register_output_device<D: AudioOutput>(name: String, stable_id: String, device: Arc<D>) {
+    insert_device(DeviceInfo {
+        name,
+        stable_id,
+        device: device.clone(),
+        output: Some(device),
+        input: None,
+    });
+}
+
+/// Registers a device with input streams only, replacing any device registered
+/// with the same `stable_id`.
+pub fn register_input_device<D: AudioInput>(name: String, stable_id: String, device: Arc<D>) {
+    insert_device(DeviceInfo {
+        name,
+        stable_id,
+        device: device.clone(),
+        output: None,
+        input: Some(device),
+    });
+}
+
+/// Registers the null device if the drivers registered no device, so that the
+/// sound stack can be used on machines without a sound device.
+///
+/// The kernel calls this once the drivers have probed their devices.
+pub fn register_null_device_if_none() {
+    if device_infos().is_empty() {
+        null::register_null_device();
+    }
+}
+
+fn insert_device(info: DeviceInfo) {
+    let stable_id = info.stable_id.clone();
     COMPONENT
         .get()
         .unwrap()
         .audio_device_table
+        .write()
+        .insert(stable_id.clone(), info);
+    notify_hotplug(HotplugEvent::Added(stable_id));
+}
+
+/// Unregisters the device with `stable_id`, e.g., when it is unplugged, and
+/// returns its registration.
+pub fn unregister_device(stable_id: &str) -> Option<DeviceInfo> {
+    let info = COMPONENT
+        .get()
+        .unwrap()
+        .audio_device_table
+        .write()
+        .remove(stable_id)?;
+    notify_hotplug(HotplugEvent::Removed(stable_id.to_string()));
+    Some(info)
+}
+
+/// Notes that the streams, jacks or channel maps of the device with `stable_id` changed.
+///
+/// Drivers call this when the device reports a new configuration, so that the
+/// observers can query the device again.
+pub fn device_changed(stable_id: &str) {
+    notify_hotplug(HotplugEvent::Changed(stable_id.to_string()));
+}
+
+/// Registers an observer of the changes of the registered devices.
+pub fn register_hotplug_observer(observer: Arc<HotplugObserver>) -> CallbackHandle {
+    let component = COMPONENT.get().unwrap();
+    let id = component.next_observer_id.fetch_add(1, Ordering::Relaxed);
+    component
+        .hotplug_observers
+        .disable_irq()
+        .lock()
+        .insert(id, observer);
+    CallbackHandle::new(move || {
+        COMPONENT
+            .get()
+            .unwrap()
+            .hotplug_observers
+            .disable_irq()
+            .lock()
+            .remove(&id);
+    })
+}
+
+fn notify_hotplug(event: HotplugEvent) {
+    // Notify the observers without holding any lock, since they may look the device up.
+    let observers: Vec<_> = COMPONENT
+        .get()
+        .unwrap()
+        .hotplug_observers
+        .disable_irq()
         .lock()
-        .insert(name, device);
+        .values()
+        .cloned()
+        .collect();
+    for observer in observers {
+        observer(&event);
+    }
+}
+
+/// Returns the first device, in card order, registered with `name`.
+pub fn get_device(name: &str) -> Option<Arc<dyn AnySoundDevice>> {
+    COMPONENT
+        .get()
+        .unwrap()
+        .audio_device_table
+        .read()
+        .values()
+        .find(|info| info.name == name)
+        .map(|info| info.device.clone())
+}
+
+pub fn get_device_by_id(stable_id: &str) -> Option<Arc<dyn AnySoundDevice>> {
+    COMPONENT
+        .get()
+        .unwrap()
+        .audio_device_table
+        .read()
+        .get(stable_id)
+        .map(|info| info.device.clone())
+}
+
+/// Returns the registration of the device with `stable_id`, tagged with its directions.
+pub fn get_device_info(stable_id: &str) -> Option<DeviceInfo> {
+    COMPONENT
+        .get()
+        .unwrap()
+        .audio_device_table
+        .read()
+        .get(stable_id)
+        .cloned()
+}
+
+/// Returns the registered devices in card order.
+///
+/// The devices are ordered by their stable IDs and the index of a device is its
+/// card number. Since the order does not depend on the order the devices were
+/// probed in, the card numbers stay the same across boots.
+pub fn device_infos() -> Vec<DeviceInfo> {
+    COMPONENT
+        .get()
+        .unwrap()
+        .audio_device_table
+        .read()
+        .values()
+        .cloned()
+        .collect()
+}
+
+/// Returns the device used for playback when none is named explicitly.
+///
+/// This is the first device, in card order, that has an output stream.
+pub fn default_output() -> Option<Arc<dyn AudioOutput>> {
+    default_device(StreamDirection::Output)?.output
 }
 
-pub fn all_devices() -> Vec<(String, Arc<SpinLock<dyn AnySoundDevice>>)> {
-    let audio_devs = COMPONENT.get().unwrap().audio_device_table.lock();
+/// Returns the device used for recording when none is named explicitly.
+///
+/// This is the first device, in card order, that has an input stream.
+pub fn default_input() -> Option<Arc<dyn AudioInput>> {
+    default_device(StreamDirection::Input)?.input
+}
+
+/// Returns the states of the jacks of the default output device, where
+/// headphones are plugged in.
+///
+/// Changes are reported to the callbacks registered with
+/// [`AnySoundDevice::register_event_callback`].
+pub fn jack_states() -> Vec<JackState> {
+    default_device(StreamDirection::Output)
+        .and_then(|info| info.device.jack_states().ok())
+        .unwrap_or_default()
+}
+
+fn default_device(direction: StreamDirection) -> Option<DeviceInfo> {
+    // Query the devices without holding the table lock, since they may block.
+    device_infos()
+        .into_iter()
+        .filter(|info| info.has_direction(direction))
+        .find(|info| {
+            info.device.capabilities_snapshot().is_ok_and(|snapshot| {
+                snapshot
+                    .streams()
+                    .iter()
+                    .any(|capability| capability.direction == direction)
+            })
+        })
+}
+
+/// Returns the name and the device of each registered device, in card order.
+pub fn all_devices() -> Vec<(String, Arc<dyn AnySoundDevice>)> {
+    let audio_devs = COMPONENT.get().unwrap().audio_device_table.read();
     audio_devs
-        .iter()
-        .map(|(name, device)| (name.clone(), device.clone()))
+        .values()
+        .map(|info| (info.name.clone(), info.device.clone()))
         .collect()
 }
 
+/// Calls `f` with the name and the device of each registered device, in card order.
+///
+/// Unlike [`all_devices`], nothing is cloned. The table is read-locked while `f`
+/// runs, so `f` must not register devices and should not block.
+pub fn for_each_device(mut f: impl FnMut(&str, &Arc<dyn AnySoundDevice>)) {
+    let audio_devs = COMPONENT.get().unwrap().audio_device_table.read();
+    for info in audio_devs.values() {
+        f(&info.name, &info.device);
+    }
+}
+
+/// Suspends every registered device, in card order.
+///
+/// All the devices are suspended even if some fail; the first error is returned.
+pub fn suspend_all() -> Result<(), SoundError> {
+    // Suspend the devices without holding the table lock, since they may block.
+    all_devices()
+        .into_iter()
+        .map(|(_, device)| device.suspend())
+        .fold(Ok(()), Result::and)
+}
+
+/// Resumes every registered device, in card order.
+///
+/// All the devices are resumed even if some fail; the first error is returned.
+pub fn resume_all() -> Result<(), SoundError> {
+    all_devices()
+        .into_iter()
+        .map(|(_, device)| device.resume())
+        .fold(Ok(()), Result::and)
+}
+
+/// Returns the privacy state of capture.
+pub fn capture_privacy() -> CapturePrivacy {
+    *COMPONENT.get().unwrap().privacy.lock()
+}
+
+/// Blocks or unblocks capture on every sound device.
+///
+/// The drivers enforce the block in their receive paths, before the frames reach
+/// any reader or callback.
+pub fn set_capture_blocked(blocked: bool, mode: CaptureBlockMode) {
+    update_privacy(|privacy| {
+        privacy.blocked = blocked;
+        privacy.mode = mode;
+    });
+}
+
+/// Notes that an input stream of a device has started running.
+///
+/// Drivers call this so that the privacy indicator can be lit.
+pub fn capture_started() {
+    let component = COMPONENT.get().unwrap();
+    if component.running_captures.fetch_add(1, Ordering::Relaxed) == 0 {
+        update_privacy(|privacy| privacy.active = true);
+    }
+}
+
+/// Notes that an input stream reported by [`capture_started`] has stopped running.
+pub fn capture_stopped() {
+    let component = COMPONENT.get().unwrap();
+    if component.running_captures.fetch_sub(1, Ordering::Relaxed) == 1 {
+        update_privacy(|privacy| privacy.active = false);
+    }
+}
+
+/// Registers an observer of the privacy state of capture.
+pub fn register_privacy_observer(observer: Arc<PrivacyObserver>) -> CallbackHandle {
+    let component = COMPONENT.get().unwrap();
+    let id = component.next_observer_id.fetch_add(1, Ordering::Relaxed);
+    component.privacy_observers.lock().insert(id, observer);
+    CallbackHandle::new(move || {
+        COMPONENT
+            .get()
+            .unwrap()
+            .privacy_observers
+            .lock()
+            .remove(&id);
+    })
+}
+
+fn update_privacy(update: impl FnOnce(&mut CapturePrivacy)) {
+    let component = COMPONENT.get().unwrap();
+    let privacy = {
+        let mut privacy = component.privacy.lock();
+        let old = *privacy;
+        update(&mut privacy);
+        if *privacy == old {
+            return;
+        }
+        *privacy
+    };
+
+    // Notify the observers without holding any lock, since they may query the state.
+    let observers: Vec<_> = component
+        .privacy_observers
+        .lock()
+        .values()
+        .cloned()
+        .collect();
+    for observer in observers {
+        observer(privacy);
+    }
+}
+
+/// Runs a function on a new kernel thread.
+///
+/// The components cannot create schedulable threads themselves, so the kernel
+/// provides the spawner with [`set_thread_spawner`].
+pub type ThreadSpawner = fn(Box<dyn FnOnce() + Send>);
+
+static THREAD_SPAWNER: Once<ThreadSpawner> = Once::new();
+
+/// Sets how the drivers run their kernel threads, e.g., the pumps of output streams.
+pub fn set_thread_spawner(spawner: ThreadSpawner) {
+    THREAD_SPAWNER.call_once(|| spawner);
+}
+
+/// Runs `f` on a new kernel thread.
+///
+/// Fails with [`SoundError::Unsupported`] if the kernel has not set a spawner.
+pub fn spawn_thread(f: impl FnOnce() + Send + 'static) -> Result<(), SoundError> {
+    let spawner = THREAD_SPAWNER.get().ok_or(SoundError::Unsupported)?;
+    spawner(Box::new(f));
+    Ok(())
+}
 
 static COMPONENT: Once<Component> = Once::new();
 
@@ -57,16 +823,41 @@ fn component_init() -> Result<(), ComponentInitError> {
     Ok(())
 }
 
-#[derive(Debug)]
 struct Component {
-    audio_device_table: SpinLock<BTreeMap<String, Arc<SpinLock<dyn AnySoundDevice>>>>,
+    /// The registered devices, keyed by their stable IDs.
+    ///
+    /// They are looked up far more often than registered.
+    audio_device_table: RwLock<BTreeMap<String, DeviceInfo>, LocalIrqDisabled>,
+    /// Also read by the drivers when they deliver captured frames in their bottom
+    /// halves, so it is taken with the local IRQs disabled.
+    privacy: SpinLock<CapturePrivacy, LocalIrqDisabled>,
+    /// The number of input streams running on all devices.
+    running_captures: AtomicUsize,
+    privacy_observers: SpinLock<BTreeMap<usize, Arc<PrivacyObserver>>, LocalIrqDisabled>,
+    hotplug_observers: SpinLock<BTreeMap<usize, Arc<HotplugObserver>>>,
+    next_observer_id: AtomicUsize,
+}
+
+impl Debug for Component {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Component")
+            .field("audio_device_table", &self.audio_device_table)
+            .field("privacy", &self.privacy)
+            .field("running_captures", &self.running_captures)
+            .finish_non_exhaustive()
+    }
 }
 
 impl Component {
     /// 初始化组件
     pub fn init() -> Result<Self, ComponentInitError> {
         Ok(Self {
-            audio_device_table: SpinLock::new(BTreeMap::new()),
+            audio_device_table: RwLock::new(BTreeMap::new()),
+            privacy: SpinLock::new(CapturePrivacy::default()),
+            running_captures: AtomicUsize::new(0),
+            privacy_observers: SpinLock::new(BTreeMap::new()),
+            hotplug_observers: SpinLock::new(BTreeMap::new()),
+            next_observer_id: AtomicUsize::new(0),
         })
     }
 }
@@ -0,0 +1,451 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! A software sound device that loops its output back into its input.
+//!
+//! [`LoopbackSoundDevice`] has one output stream and one input stream. The
+//! frames played on the output stream are captured by the input stream while
+//! it is running, so the whole playback and capture stack can be exercised
+//! without any audio backend.
+
+use alloc::{collections::BTreeMap, string::ToString, sync::Arc, vec, vec::Vec};
+use core::{
+    fmt::{self, Debug},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use ostd::{
+    mm::VmReader,
+    sync::{LocalIrqDisabled, RwLock, SpinLock},
+};
+
+use crate::{
+    convert::sample_bytes, AnySoundDevice, AudioInput, AudioOutput, CallbackHandle, CaptureRing,
+    CompletionMode, CompletionPriority, Frames, LatencyHistogram, PlaybackCallback, RecordToken,
+    SampleFormat, SoundCallback, SoundError, StreamCapability, StreamDirection, StreamParams,
+    StreamPosition,
+};
+
+/// The name the loopback device is registered with.
+pub const LOOPBACK_DEVICE_NAME: &str = "Loopback";
+
+/// The stable ID the loopback device is registered with.
+///
+/// It sorts after the IDs of the devices on a bus, so a hardware device, if
+/// any, comes first in card order and is the default one.
+pub const LOOPBACK_STABLE_ID: &str = "virtual-loopback";
+
+const OUTPUT_STREAM: u32 = 0;
+const INPUT_STREAM: u32 = 1;
+
+/// Holds more than a second of stereo S16 frames at 48 kHz.
+const RING_SIZE: usize = 256 * 1024;
+
+/// Registers a loopback device.
+///
+/// It is only meant for testing, so the kernel registers it only when asked to
+/// run the sound tests.
+pub fn register_loopback_device() {
+    crate::register_device(
+        LOOPBACK_DEVICE_NAME.to_string(),
+        LOOPBACK_STABLE_ID.to_string(),
+        Arc::new(LoopbackSoundDevice::new()),
+    );
+}
+
+#[derive(Debug, Default)]
+struct LoopbackStream {
+    opened: bool,
+    disabled: bool,
+    running: bool,
+    /// Whether the stream was running when the device was suspended.
+    running_before_suspend: bool,
+    params: Option<StreamParams>,
+    /// The bytes of frames played or read since the stream was opened.
+    transferred_bytes: u64,
+}
+
+impl LoopbackStream {
+    fn frame_bytes(&self) -> usize {
+        self.params.map_or(1, |params| {
+            sample_bytes(params.format).unwrap_or(1) * params.channels as usize
+        })
+    }
+}
+
+#[derive(Debug)]
+struct LoopbackState {
+    /// The output stream followed by the input stream.
+    streams: [LoopbackStream; 2],
+    dma_quota: usize,
+    suspended: bool,
+    /// The length of each pending record request.
+    records: BTreeMap<RecordToken, usize>,
+    next_record_token: u32,
+}
+
+impl LoopbackState {
+    fn stream(&mut self, stream_id: u32) -> Result<&mut LoopbackStream, SoundError> {
+        self.streams
+            .get_mut(stream_id as usize)
+            .ok_or(SoundError::InvalidParam)
+    }
+
+    fn opened_stream(&mut self, stream_id: u32) -> Result<&mut LoopbackStream, SoundError> {
+        match self.stream(stream_id)? {
+            stream if stream.opened => Ok(stream),
+            _ => Err(SoundError::InvalidParam),
+        }
+    }
+
+    fn enabled_stream(&mut self, stream_id: u32) -> Result<&mut LoopbackStream, SoundError> {
+        if self.suspended {
+            return Err(SoundError::NotReady);
+        }
+        let stream = self.opened_stream(stream_id)?;
+        if stream.disabled {
+            return Err(SoundError::NotReady);
+        }
+        Ok(stream)
+    }
+}
+
+/// A sound device whose input stream captures what its output stream plays.
+///
+/// The frames are looped back unchanged, so both streams must be opened with
+/// the same format, rate and channels. Only the frames played while the input
+/// stream is running are captured.
+///
+/// The device captures nothing but what is played on it, so it is not subject
+/// to the capture kill-switch and does not light the privacy indicator.
+pub struct LoopbackSoundDevice {
+    state: SpinLock<LoopbackState>,
+    /// The frames played that have not been read from the input stream yet.
+    ring: CaptureRing,
+    /// The record callbacks, keyed by the ID given at registration.
+    callbacks: Arc<RwLock<BTreeMap<usize, Arc<SoundCallback>>, LocalIrqDisabled>>,
+    next_callback_id: AtomicUsize,
+}
+
+impl LoopbackSoundDevice {
+    /// The frame rates accepted by the streams, in Hz.
+    const RATES: [u32; 7] = [8000, 11025, 16000, 22050, 32000, 44100, 48000];
+
+    pub fn new() -> Self {
+        let state = LoopbackState {
+            streams: Default::default(),
+            dma_quota: usize::MAX,
+            suspended: false,
+            records: BTreeMap::new(),
+            next_record_token: 0,
+        };
+        Self {
+            state: SpinLock::new(state),
+            ring: CaptureRing::new(RING_SIZE),
+            callbacks: Arc::new(RwLock::new(BTreeMap::new())),
+            next_callback_id: AtomicUsize::new(0),
+        }
+    }
+
+    fn capability(stream_id: u32, direction: StreamDirection) -> StreamCapability {
+        StreamCapability {
+            stream_id,
+            direction,
+            formats: vec![
+                SampleFormat::U8,
+                SampleFormat::S16,
+                SampleFormat::S32,
+                SampleFormat::Float,
+            ],
+            rates: Self::RATES.to_vec(),
+            channels: 1..=2,
+            channel_maps: vec![],
+            remappable: false,
+            jacks: vec![],
+        }
+    }
+
+    /// Reads the looped-back frames into `frames`, returning the number of bytes read.
+    fn capture(&self, state: &mut LoopbackState, frames: &mut [u8]) -> usize {
+        let len = self.ring.pop(frames);
+        state.streams[INPUT_STREAM as usize].transferred_bytes += len as u64;
+        len
+    }
+}
+
+impl Debug for LoopbackSoundDevice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LoopbackSoundDevice")
+            .field("state", &self.state)
+            .field("ring", &self.ring)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for LoopbackSoundDevice {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AnySoundDevice for LoopbackSoundDevice {
+    fn capabilities(&self) -> Result<Vec<StreamCapability>, SoundError> {
+        Ok(vec![
+            Self::capability(OUTPUT_STREAM, StreamDirection::Output),
+            Self::capability(INPUT_STREAM, StreamDirection::Input),
+        ])
+    }
+
+    fn set_completion_mode(&self, stream_id: u32, _mode: CompletionMode) -> Result<(), SoundError> {
+        // Transfers complete immediately, whatever the mode.
+        self.state.lock().stream(stream_id)?;
+        Ok(())
+    }
+
+    fn set_completion_priority(&self, _priority: CompletionPriority) {}
+
+    fn set_dma_quota(&self, bytes: usize) {
+        self.state.lock().dma_quota = bytes;
+    }
+
+    fn set_jack_auto_pause(&self, _enabled: bool) {
+        // The device has no jacks.
+    }
+
+    fn latency_histogram(&self, stream_id: u32) -> Option<LatencyHistogram> {
+        // Transfers complete immediately, so there is nothing to record.
+        (stream_id <= INPUT_STREAM).then(LatencyHistogram::new)
+    }
+
+    fn open_stream(
+        &self,
+        direction: StreamDirection,
+        params: &StreamParams,
+    ) -> Result<u32, SoundError> {
+        let (stream_id, other_id) = match direction {
+            StreamDirection::Output => (OUTPUT_STREAM, INPUT_STREAM),
+            StreamDirection::Input => (INPUT_STREAM, OUTPUT_STREAM),
+        };
+        if !Self::capability(stream_id, direction).supports(
+            params.format,
+            params.rate,
+            params.channels,
+        ) {
+            return Err(SoundError::InvalidParam);
+        }
+
+        let mut state = self.state.lock();
+        if params.buffer_bytes as usize > state.dma_quota {
+            return Err(SoundError::QuotaExceeded);
+        }
+        // The frames are looped back unchanged.
+        let other = &state.streams[other_id as usize];
+        if other.opened
+            && other.params.is_some_and(|other_params| {
+                other_params.format != params.format
+                    || other_params.rate != params.rate
+                    || other_params.channels != params.channels
+            })
+        {
+            return Err(SoundError::InvalidParam);
+        }
+        let stream = state.stream(stream_id)?;
+        if stream.opened || stream.disabled {
+            return Err(SoundError::InvalidParam);
+        }
+        *stream = LoopbackStream {
+            opened: true,
+            params: Some(*params),
+            ..Default::default()
+        };
+        Ok(stream_id)
+    }
+
+    fn start_stream(&self, stream_id: u32) -> Result<(), SoundError> {
+        self.state.lock().enabled_stream(stream_id)?.running = true;
+        Ok(())
+    }
+
+    fn stop_stream(&self, stream_id: u32) -> Result<(), SoundError> {
+        self.state.lock().opened_stream(stream_id)?.running = false;
+        Ok(())
+    }
+
+    fn stream_position(&self, stream_id: u32) -> Result<StreamPosition, SoundError> {
+        let mut state = self.state.lock();
+        let stream = state.opened_stream(stream_id)?;
+        let mut bytes = stream.transferred_bytes;
+        if stream_id == INPUT_STREAM {
+            // The frames left in the ring have been captured but not read yet.
+            bytes += self.ring.len() as u64;
+        }
+        Ok(StreamPosition {
+            frames: bytes / stream.frame_bytes() as Frames,
+            // The device has no clock.
+            timestamp: Duration::ZERO,
+        })
+    }
+
+    fn drain_stream(&self, stream_id: u32) -> Result<(), SoundError> {
+        // Transfers complete immediately, so there is nothing to wait for.
+        self.state.lock().opened_stream(stream_id)?.running = false;
+        Ok(())
+    }
+
+    fn close_stream(&self, stream_id: u32) -> Result<(), SoundError> {
+        let mut state = self.state.lock();
+        let stream = state.opened_stream(stream_id)?;
+        stream.opened = false;
+        stream.running = false;
+        if stream_id == INPUT_STREAM {
+            state.records.clear();
+            // Leave no looped-back audio to the next user of the input stream.
+            self.ring.scrub();
+        }
+        Ok(())
+    }
+
+    fn disable_stream(&self, stream_id: u32) -> Result<(), SoundError> {
+        let mut state = self.state.lock();
+        let stream = state.stream(stream_id)?;
+        stream.disabled = true;
+        stream.running = false;
+        Ok(())
+    }
+
+    fn enable_stream(&self, stream_id: u32) -> Result<(), SoundError> {
+        self.state.lock().stream(stream_id)?.disabled = false;
+        Ok(())
+    }
+
+    fn suspend(&self) -> Result<(), SoundError> {
+        let mut state = self.state.lock();
+        if state.suspended {
+            return Ok(());
+        }
+        for stream in state.streams.iter_mut() {
+            stream.running_before_suspend = stream.running;
+            stream.running = false;
+        }
+        state.suspended = true;
+        Ok(())
+    }
+
+    fn resume(&self) -> Result<(), SoundError> {
+        let mut state = self.state.lock();
+        if !state.suspended {
+            return Ok(());
+        }
+        for stream in state.streams.iter_mut() {
+            stream.running = stream.opened && stream.running_before_suspend;
+        }
+        state.suspended = false;
+        Ok(())
+    }
+}
+
+impl AudioOutput for LoopbackSoundDevice {
+    fn register_playback_callback(
+        &self,
+        _stream_id: u32,
+        _callback: Arc<PlaybackCallback>,
+    ) -> Result<CallbackHandle, SoundError> {
+        // The device has no clock to pace the periods.
+        Err(SoundError::Unsupported)
+    }
+
+    fn write_stream(&self, stream_id: u32, frames: &[u8]) -> Result<usize, SoundError> {
+        if stream_id != OUTPUT_STREAM {
+            return Err(SoundError::InvalidParam);
+        }
+        {
+            let mut state = self.state.lock();
+            state.enabled_stream(stream_id)?.transferred_bytes += frames.len() as u64;
+            let input = &state.streams[INPUT_STREAM as usize];
+            if !input.opened || input.disabled || !input.running {
+                return Ok(frames.len());
+            }
+            self.ring.push(frames);
+        }
+
+        let callbacks = self.callbacks.read();
+        for callback in callbacks.values() {
+            callback(VmReader::from(frames));
+        }
+        Ok(frames.len())
+    }
+}
+
+impl AudioInput for LoopbackSoundDevice {
+    fn register_callback(&self, callback: Arc<SoundCallback>) -> CallbackHandle {
+        let id = self.next_callback_id.fetch_add(1, Ordering::Relaxed);
+        self.callbacks.write().insert(id, callback);
+
+        let callbacks = Arc::downgrade(&self.callbacks);
+        CallbackHandle::new(move || {
+            if let Some(callbacks) = callbacks.upgrade() {
+                callbacks.write().remove(&id);
+            }
+        })
+    }
+
+    fn capture_overrun_bytes(&self) -> u64 {
+        self.ring.overrun_bytes()
+    }
+
+    fn read_stream(&self, stream_id: u32, frames: &mut [u8]) -> Result<usize, SoundError> {
+        if stream_id != INPUT_STREAM {
+            return Err(SoundError::InvalidParam);
+        }
+        let mut state = self.state.lock();
+        state.enabled_stream(stream_id)?;
+        Ok(self.capture(&mut state, frames))
+    }
+
+    fn record_nb(&self, stream_id: u32, len: usize) -> Result<RecordToken, SoundError> {
+        if stream_id != INPUT_STREAM {
+            return Err(SoundError::InvalidParam);
+        }
+        let mut state = self.state.lock();
+        state.enabled_stream(stream_id)?;
+        let token = RecordToken(state.next_record_token);
+        state.next_record_token = state.next_record_token.wrapping_add(1);
+        state.records.insert(token, len);
+        Ok(token)
+    }
+
+    fn record_poll(
+        &self,
+        token: RecordToken,
+        frames: &mut [u8],
+    ) -> Option<Result<usize, SoundError>> {
+        let mut state = self.state.lock();
+        // A request completes once enough frames have been looped back to fill it.
+        let len = *state.records.get(&token)?;
+        if self.ring.len() < len {
+            return None;
+        }
+        state.records.remove(&token);
+        let len = len.min(frames.len());
+        Some(Ok(self.capture(&mut state, &mut frames[..len])))
+    }
+}
+
+#[cfg(ktest)]
+mod test {
+    use ostd::prelude::*;
+
+    use super::*;
+    use crate::verify::{verify_capture_path, TEST_TONE_FREQUENCY};
+
+    #[ktest]
+    fn verify_loopback_device() {
+        let device = Arc::new(LoopbackSoundDevice::new());
+        let (output, input): (Arc<dyn AudioOutput>, Arc<dyn AudioInput>) =
+            (device.clone(), device.clone());
+
+        let analysis = verify_capture_path(&output, &input).unwrap();
+        assert_eq!(analysis.frequency, TEST_TONE_FREQUENCY);
+        assert_eq!(device.capture_overrun_bytes(), 0);
+    }
+}
@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! Latency statistics of the audio path.
+
+/// The upper bounds, in microseconds, of the buckets of a [`LatencyHistogram`].
+///
+/// Latencies above the last bound fall into an extra overflow bucket.
+pub const LATENCY_BUCKETS_US: [u64; 10] =
+    [100, 250, 500, 1000, 2500, 5000, 10000, 25000, 50000, 100000];
+
+/// A fixed-bucket histogram of the time from submitting a period to the device
+/// to the device completing it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LatencyHistogram {
+    counts: [u64; LATENCY_BUCKETS_US.len() + 1],
+    sum_us: u64,
+}
+
+impl LatencyHistogram {
+    pub const fn new() -> Self {
+        Self {
+            counts: [0; LATENCY_BUCKETS_US.len() + 1],
+            sum_us: 0,
+        }
+    }
+
+    /// Records one completed period.
+    pub fn record(&mut self, latency_us: u64) {
+        let bucket = LATENCY_BUCKETS_US
+            .iter()
+            .position(|bound| latency_us <= *bound)
+            .unwrap_or(LATENCY_BUCKETS_US.len());
+        self.counts[bucket] += 1;
+        self.sum_us = self.sum_us.saturating_add(latency_us);
+    }
+
+    /// Returns the number of periods in each bucket.
+    ///
+    /// The last count is for the periods slower than every bound of [`LATENCY_BUCKETS_US`].
+    pub fn counts(&self) -> &[u64] {
+        &self.counts
+    }
+
+    /// Returns the number of recorded periods.
+    pub fn total(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// Returns the mean latency in microseconds, if any period has been recorded.
+    pub fn mean_us(&self) -> Option<u64> {
+        let total = self.total();
+        (total != 0).then(|| self.sum_us / total)
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
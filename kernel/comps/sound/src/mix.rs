@@ -0,0 +1,315 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! Up- and down-mixing between channel layouts.
+//!
+//! A layout lists the position of each channel of a frame, with the numbering
+//! of the `VIRTIO_SND_CHMAP_*` positions used by
+//! [`StreamCapability::channel_maps`].
+
+use alloc::{vec, vec::Vec};
+
+use crate::{AnySoundDevice, StreamCapability};
+
+/// The channel positions the mixer knows about.
+pub mod position {
+    /// A silent channel.
+    pub const NA: u8 = 1;
+    pub const MONO: u8 = 2;
+    /// Front left.
+    pub const FL: u8 = 3;
+    /// Front right.
+    pub const FR: u8 = 4;
+    /// Rear left.
+    pub const RL: u8 = 5;
+    /// Rear right.
+    pub const RR: u8 = 6;
+    /// Front center.
+    pub const FC: u8 = 7;
+    /// Low frequency effects.
+    pub const LFE: u8 = 8;
+    /// Side left.
+    pub const SL: u8 = 9;
+    /// Side right.
+    pub const SR: u8 = 10;
+}
+
+use position::*;
+
+/// The gain of a channel mixed into two others, 1/sqrt(2) in Q15.
+const HALF_POWER: i32 = 23170;
+/// Unity gain in Q15.
+const UNITY: i32 = 1 << 15;
+
+/// Returns the usual layout of frames of `channels` channels.
+///
+/// 5.1 and 7.1 frames are in the WAVE order: FL, FR, FC, LFE, RL, RR, then SL, SR.
+pub fn default_layout(channels: u8) -> Vec<u8> {
+    match channels {
+        1 => vec![MONO],
+        2 => vec![FL, FR],
+        4 => vec![FL, FR, RL, RR],
+        6 => vec![FL, FR, FC, LFE, RL, RR],
+        8 => vec![FL, FR, FC, LFE, RL, RR, SL, SR],
+        _ => (0..channels).map(|_| NA).collect(),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Left,
+    Right,
+    Center,
+}
+
+fn side(position: u8) -> Option<Side> {
+    match position {
+        FL | RL | SL => Some(Side::Left),
+        FR | RR | SR => Some(Side::Right),
+        MONO | FC => Some(Side::Center),
+        _ => None,
+    }
+}
+
+/// Mixes interleaved S16 frames from one channel layout to another.
+#[derive(Debug, Clone)]
+pub struct ChannelMixer {
+    from_channels: usize,
+    to_channels: usize,
+    /// The Q15 gain of each input channel in each output channel, output-major.
+    gains: Vec<i32>,
+}
+
+impl ChannelMixer {
+    /// Creates a mixer from the channel layout `from` to the layout `to`.
+    ///
+    /// A channel present in both layouts is copied. Otherwise, a mono or center
+    /// channel is spread over the front channels, a rear or side channel is
+    /// folded into the front channel of its side, and everything but LFE is
+    /// averaged into a mono output. Channels with no counterpart are dropped.
+    pub fn new(from: &[u8], to: &[u8]) -> Self {
+        let mut gains = vec![0; from.len() * to.len()];
+        for (input, from_position) in from.iter().enumerate() {
+            let targets = Self::targets(*from_position, to);
+            for (output, gain) in targets {
+                gains[output * from.len() + input] = gain;
+            }
+        }
+
+        // Scale the outputs fed by several inputs so that they cannot clip.
+        for output in 0..to.len() {
+            let row = &mut gains[output * from.len()..(output + 1) * from.len()];
+            let total: i32 = row.iter().sum();
+            if total > UNITY {
+                for gain in row.iter_mut() {
+                    *gain = *gain * UNITY / total;
+                }
+            }
+        }
+
+        Self {
+            from_channels: from.len(),
+            to_channels: to.len(),
+            gains,
+        }
+    }
+
+    /// Creates a mixer between the default layouts of the channel counts.
+    pub fn for_channels(from: u8, to: u8) -> Self {
+        Self::new(&default_layout(from), &default_layout(to))
+    }
+
+    /// Creates a mixer from the layout `from` to the layout the device uses for
+    /// frames of `channels` channels on the stream of `capability`.
+    ///
+    /// The layout is taken from the channel maps the device reports, falling back
+    /// to [`default_layout`] if none has `channels` channels.
+    pub fn to_stream(from: &[u8], capability: &StreamCapability, channels: u8) -> Self {
+        let to = capability
+            .channel_map(channels)
+            .map_or_else(|| default_layout(channels), <[u8]>::to_vec);
+        Self::new(from, &to)
+    }
+
+    /// Creates a mixer from the layout `from` to the layout of the opened stream
+    /// `stream_id` of `device`, which has `channels` channels.
+    ///
+    /// The layout is the channel map the device reports for the stream, falling
+    /// back to [`default_layout`] if it reports none.
+    pub fn to_opened_stream(
+        from: &[u8],
+        device: &(impl AnySoundDevice + ?Sized),
+        stream_id: u32,
+        channels: u8,
+    ) -> Self {
+        let to = device
+            .channel_map(stream_id)
+            .ok()
+            .filter(|map| map.len() == channels as usize)
+            .unwrap_or_else(|| default_layout(channels));
+        Self::new(from, &to)
+    }
+
+    /// Returns the output channels fed by an input channel at `position`, with their gains.
+    fn targets(position: u8, to: &[u8]) -> Vec<(usize, i32)> {
+        let find = |wanted: u8| to.iter().position(|p| *p == wanted);
+        if position == NA {
+            return vec![];
+        }
+        if let Some(output) = find(position) {
+            return vec![(output, UNITY)];
+        }
+        if let Some(output) = find(MONO) {
+            // Everything but LFE is averaged into mono by the scaling in `new`.
+            return if position == LFE {
+                vec![]
+            } else {
+                vec![(output, UNITY)]
+            };
+        }
+        match side(position) {
+            Some(Side::Center) => {
+                let fronts: Vec<_> = [find(FL), find(FR)].into_iter().flatten().collect();
+                if fronts.is_empty() {
+                    return find(FC).map_or(vec![], |output| vec![(output, UNITY)]);
+                }
+                let gain = if position == MONO { UNITY } else { HALF_POWER };
+                fronts.into_iter().map(|output| (output, gain)).collect()
+            }
+            Some(Side::Left) => find(FL).map_or(vec![], |output| vec![(output, HALF_POWER)]),
+            Some(Side::Right) => find(FR).map_or(vec![], |output| vec![(output, HALF_POWER)]),
+            None => vec![],
+        }
+    }
+
+    pub fn from_channels(&self) -> usize {
+        self.from_channels
+    }
+
+    pub fn to_channels(&self) -> usize {
+        self.to_channels
+    }
+
+    /// Mixes the frames in `input`, appending the resulting frames to `output`.
+    ///
+    /// A trailing partial frame is ignored.
+    pub fn mix(&self, input: &[i16], output: &mut Vec<i16>) {
+        if self.from_channels == 0 {
+            return;
+        }
+        for frame in input.chunks_exact(self.from_channels) {
+            for row in self.gains.chunks_exact(self.from_channels) {
+                let sum: i32 = frame
+                    .iter()
+                    .zip(row)
+                    .map(|(sample, gain)| (*sample as i32 * gain) >> 15)
+                    .sum();
+                output.push(sum.clamp(i16::MIN as i32, i16::MAX as i32) as i16);
+            }
+        }
+    }
+}
+
+/// Reorders the channels of interleaved frames from one layout to another
+/// with the same number of channels.
+///
+/// Unlike [`ChannelMixer`], the samples are moved without being decoded, so
+/// frames of any format can be routed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChannelRouter {
+    /// The input channel each output channel is taken from.
+    sources: Vec<usize>,
+}
+
+impl ChannelRouter {
+    /// Creates a router from the channel layout `from` to the layout `to`.
+    ///
+    /// A channel goes to the output channel at the same position. The channels
+    /// whose position is missing from the other layout are paired in order.
+    /// Returns `None` if the layouts have different numbers of channels, and if
+    /// the channels would stay in place.
+    pub fn new(from: &[u8], to: &[u8]) -> Option<Self> {
+        if from.len() != to.len() {
+            return None;
+        }
+        let mut routed = vec![false; from.len()];
+        let mut sources = vec![None; to.len()];
+        for (output, position) in to.iter().enumerate() {
+            let input = (0..from.len()).find(|input| !routed[*input] && from[*input] == *position);
+            if let Some(input) = input {
+                routed[input] = true;
+                sources[output] = Some(input);
+            }
+        }
+        let mut unrouted = (0..from.len()).filter(|input| !routed[*input]);
+        let sources: Vec<usize> = sources
+            .into_iter()
+            .map(|source| source.or_else(|| unrouted.next()).unwrap())
+            .collect();
+        if sources
+            .iter()
+            .enumerate()
+            .all(|(output, input)| output == *input)
+        {
+            return None;
+        }
+        Some(Self { sources })
+    }
+
+    pub fn channels(&self) -> usize {
+        self.sources.len()
+    }
+
+    /// Routes the frames in `input`, made of samples of `sample_bytes` bytes.
+    ///
+    /// A trailing partial frame is ignored.
+    pub fn route(&self, input: &[u8], sample_bytes: usize) -> Vec<u8> {
+        let frame_bytes = self.sources.len() * sample_bytes;
+        let mut output = Vec::with_capacity(input.len() / frame_bytes * frame_bytes);
+        for frame in input.chunks_exact(frame_bytes) {
+            for source in self.sources.iter() {
+                output
+                    .extend_from_slice(&frame[source * sample_bytes..(source + 1) * sample_bytes]);
+            }
+        }
+        output
+    }
+}
+
+#[cfg(ktest)]
+mod test {
+    use ostd::prelude::*;
+
+    use super::*;
+
+    #[ktest]
+    fn upmix_mono_to_stereo() {
+        let mixer = ChannelMixer::for_channels(1, 2);
+        let mut output = Vec::new();
+        mixer.mix(&[1000, -1000], &mut output);
+        assert_eq!(output, vec![1000, 1000, -1000, -1000]);
+    }
+
+    #[ktest]
+    fn downmix_5_1_to_stereo() {
+        let mixer = ChannelMixer::for_channels(6, 2);
+        let mut output = Vec::new();
+        // Only the front left channel is playing.
+        mixer.mix(&[8000, 0, 0, 0, 0, 0], &mut output);
+        assert_eq!(output.len(), 2);
+        assert!(output[0] > 0 && output[0] <= 8000);
+        assert_eq!(output[1], 0);
+    }
+
+    #[ktest]
+    fn route_5_1_to_device_order() {
+        // ALSA orders 5.1 channels as FL, FR, RL, RR, FC, LFE.
+        let router = ChannelRouter::new(&default_layout(6), &[FL, FR, RL, RR, FC, LFE]).unwrap();
+        let frames: Vec<u8> = (0..6u16).flat_map(|sample| sample.to_le_bytes()).collect();
+        let routed = router.route(&frames, 2);
+        assert_eq!(routed, vec![0, 0, 1, 0, 4, 0, 5, 0, 2, 0, 3, 0]);
+
+        // Side channels stand in for the missing rear ones.
+        let router = ChannelRouter::new(&default_layout(6), &[FL, FR, FC, LFE, SL, SR]);
+        assert_eq!(router, None);
+    }
+}
@@ -0,0 +1,582 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! A software sound device that plays to nowhere and records silence.
+//!
+//! [`NullSoundDevice`] has one output stream and one input stream, both
+//! running on a clock derived from the TSC. The frames played are discarded
+//! at the pace real hardware would consume them, and the input stream
+//! captures silence at the pace real hardware would produce it, so the sound
+//! stack can be exercised on machines without a sound device.
+
+use alloc::{collections::BTreeMap, string::ToString, sync::Arc, vec, vec::Vec};
+use core::time::Duration;
+
+use ostd::{
+    arch::{read_tsc, tsc_freq},
+    sync::SpinLock,
+    task::Task,
+};
+
+use crate::{
+    convert::{fill_silence, sample_bytes},
+    AnySoundDevice, AudioInput, AudioOutput, CallbackHandle, CompletionMode, CompletionPriority,
+    Frames, LatencyHistogram, PlaybackCallback, RecordToken, SampleFormat, SoundCallback,
+    SoundError, StreamCapability, StreamDirection, StreamParams, StreamPosition,
+};
+
+/// The name the null device is registered with.
+pub const NULL_DEVICE_NAME: &str = "Null";
+
+/// The stable ID the null device is registered with.
+///
+/// It sorts after the IDs of the devices on a bus and before
+/// [`LOOPBACK_STABLE_ID`](crate::loopback::LOOPBACK_STABLE_ID), so the null
+/// device, which is only registered when there is no hardware device, stays
+/// behind the devices hot-plugged later.
+pub const NULL_STABLE_ID: &str = "virtual-dummy";
+
+const OUTPUT_STREAM: u32 = 0;
+const INPUT_STREAM: u32 = 1;
+
+/// Registers a null device.
+pub(crate) fn register_null_device() {
+    crate::register_device(
+        NULL_DEVICE_NAME.to_string(),
+        NULL_STABLE_ID.to_string(),
+        Arc::new(NullSoundDevice::new()),
+    );
+}
+
+#[derive(Debug, Default)]
+struct NullStream {
+    opened: bool,
+    disabled: bool,
+    running: bool,
+    /// Whether the stream was running when the device was suspended.
+    running_before_suspend: bool,
+    params: Option<StreamParams>,
+    /// The bytes of frames written to, or read from, the stream since it was opened.
+    transferred_bytes: u64,
+    /// The bytes of frames the device had played or captured at `since`.
+    clock_bytes: u64,
+    /// The TSC value at which the stream last started running, if it is running.
+    since: Option<u64>,
+    /// The bytes of captured frames dropped because no one read them in time.
+    overrun_bytes: u64,
+}
+
+impl NullStream {
+    fn params(&self) -> StreamParams {
+        self.params.unwrap()
+    }
+
+    fn frame_bytes(&self) -> u64 {
+        let params = self.params();
+        (sample_bytes(params.format).unwrap_or(1) * params.channels as usize) as u64
+    }
+
+    /// Returns the bytes of frames the device has consumed or produced by `now`,
+    /// were the output stream never to run dry.
+    fn clock(&self, now: u64) -> u64 {
+        let Some(since) = self.since else {
+            return self.clock_bytes;
+        };
+        let params = self.params();
+        let elapsed = now.saturating_sub(since) as u128;
+        let frames = elapsed * params.rate as u128 / tsc_freq().max(1) as u128;
+        self.clock_bytes + frames as u64 * self.frame_bytes()
+    }
+
+    /// Returns the bytes of frames played by `now`.
+    fn played(&self, now: u64) -> u64 {
+        self.clock(now).min(self.transferred_bytes)
+    }
+
+    /// Returns the bytes of frames captured but not read by `now`.
+    fn backlog(&self, now: u64) -> u64 {
+        self.clock(now) - self.transferred_bytes
+    }
+
+    /// Accounts for the underruns and overruns that happened by `now`.
+    ///
+    /// An output stream that ran dry has its clock restarted at `now`, so that
+    /// it resumes from where its frames ran out. An input stream drops the
+    /// captured frames that no longer fit in its buffer.
+    ///
+    /// Otherwise the clock is left alone, since restarting it would lose the
+    /// time elapsed since the last whole frame.
+    fn settle(&mut self, direction: StreamDirection, now: u64) {
+        match direction {
+            StreamDirection::Output => {
+                if self.clock(now) > self.transferred_bytes {
+                    self.clock_bytes = self.transferred_bytes;
+                    self.since = self.running.then_some(now);
+                }
+            }
+            StreamDirection::Input => {
+                let buffer_bytes = self.params().buffer_bytes as u64;
+                let backlog = self.backlog(now);
+                if backlog > buffer_bytes {
+                    self.overrun_bytes += backlog - buffer_bytes;
+                    self.transferred_bytes += backlog - buffer_bytes;
+                }
+            }
+        }
+    }
+
+    fn set_running(&mut self, direction: StreamDirection, running: bool) {
+        let now = read_tsc();
+        self.settle(direction, now);
+        self.clock_bytes = self.clock(now);
+        self.running = running;
+        self.since = running.then_some(now);
+    }
+}
+
+#[derive(Debug)]
+struct NullState {
+    /// The output stream followed by the input stream.
+    streams: [NullStream; 2],
+    dma_quota: usize,
+    suspended: bool,
+    /// The length of each pending record request.
+    records: BTreeMap<RecordToken, usize>,
+    next_record_token: u32,
+}
+
+impl NullState {
+    fn stream(&mut self, stream_id: u32) -> Result<&mut NullStream, SoundError> {
+        self.streams
+            .get_mut(stream_id as usize)
+            .ok_or(SoundError::InvalidParam)
+    }
+
+    fn opened_stream(&mut self, stream_id: u32) -> Result<&mut NullStream, SoundError> {
+        match self.stream(stream_id)? {
+            stream if stream.opened => Ok(stream),
+            _ => Err(SoundError::InvalidParam),
+        }
+    }
+
+    fn enabled_stream(&mut self, stream_id: u32) -> Result<&mut NullStream, SoundError> {
+        if self.suspended {
+            return Err(SoundError::NotReady);
+        }
+        let stream = self.opened_stream(stream_id)?;
+        if stream.disabled {
+            return Err(SoundError::NotReady);
+        }
+        Ok(stream)
+    }
+}
+
+/// A sound device that discards what it plays and records silence, in real time.
+///
+/// Writes block while the buffer of the output stream is full and reads block
+/// until enough silence has been captured, as they would on hardware. The
+/// streams accept every format the conversion layer knows, at any common rate.
+#[derive(Debug)]
+pub struct NullSoundDevice {
+    state: SpinLock<NullState>,
+}
+
+impl NullSoundDevice {
+    const FORMATS: [SampleFormat; 8] = [
+        SampleFormat::U8,
+        SampleFormat::S16,
+        SampleFormat::S18_3,
+        SampleFormat::U18_3,
+        SampleFormat::S20_3,
+        SampleFormat::U20_3,
+        SampleFormat::S32,
+        SampleFormat::Float,
+    ];
+
+    /// The frame rates accepted by the streams, in Hz.
+    const RATES: [u32; 14] = [
+        5512, 8000, 11025, 16000, 22050, 32000, 44100, 48000, 64000, 88200, 96000, 176400, 192000,
+        384000,
+    ];
+
+    pub fn new() -> Self {
+        let state = NullState {
+            streams: Default::default(),
+            dma_quota: usize::MAX,
+            suspended: false,
+            records: BTreeMap::new(),
+            next_record_token: 0,
+        };
+        Self {
+            state: SpinLock::new(state),
+        }
+    }
+
+    fn capability(stream_id: u32, direction: StreamDirection) -> StreamCapability {
+        StreamCapability {
+            stream_id,
+            direction,
+            formats: Self::FORMATS.to_vec(),
+            rates: Self::RATES.to_vec(),
+            channels: 1..=u8::MAX,
+            channel_maps: vec![],
+            remappable: false,
+            jacks: vec![],
+        }
+    }
+
+    /// Calls `poll` with the state until it returns a result, yielding in between.
+    ///
+    /// `poll` returns `None` to wait for the clock of a stream to advance.
+    fn wait_until<T>(
+        &self,
+        mut poll: impl FnMut(&mut NullState) -> Option<Result<T, SoundError>>,
+    ) -> Result<T, SoundError> {
+        loop {
+            if let Some(result) = poll(&mut self.state.lock()) {
+                return result;
+            }
+            Task::yield_now();
+        }
+    }
+}
+
+impl Default for NullSoundDevice {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AnySoundDevice for NullSoundDevice {
+    fn capabilities(&self) -> Result<Vec<StreamCapability>, SoundError> {
+        Ok(vec![
+            Self::capability(OUTPUT_STREAM, StreamDirection::Output),
+            Self::capability(INPUT_STREAM, StreamDirection::Input),
+        ])
+    }
+
+    fn set_completion_mode(&self, stream_id: u32, _mode: CompletionMode) -> Result<(), SoundError> {
+        // Waits are always polled, whatever the mode.
+        self.state.lock().stream(stream_id)?;
+        Ok(())
+    }
+
+    fn set_completion_priority(&self, _priority: CompletionPriority) {}
+
+    fn set_dma_quota(&self, bytes: usize) {
+        self.state.lock().dma_quota = bytes;
+    }
+
+    fn set_jack_auto_pause(&self, _enabled: bool) {
+        // The device has no jacks.
+    }
+
+    fn latency_histogram(&self, stream_id: u32) -> Option<LatencyHistogram> {
+        // No transfers are submitted, so there is nothing to record.
+        (stream_id <= INPUT_STREAM).then(LatencyHistogram::new)
+    }
+
+    fn open_stream(
+        &self,
+        direction: StreamDirection,
+        params: &StreamParams,
+    ) -> Result<u32, SoundError> {
+        let stream_id = match direction {
+            StreamDirection::Output => OUTPUT_STREAM,
+            StreamDirection::Input => INPUT_STREAM,
+        };
+        if !Self::capability(stream_id, direction).supports(
+            params.format,
+            params.rate,
+            params.channels,
+        ) || params.buffer_bytes == 0
+        {
+            return Err(SoundError::InvalidParam);
+        }
+
+        let mut state = self.state.lock();
+        if params.buffer_bytes as usize > state.dma_quota {
+            return Err(SoundError::QuotaExceeded);
+        }
+        let stream = state.stream(stream_id)?;
+        if stream.opened || stream.disabled {
+            return Err(SoundError::InvalidParam);
+        }
+        *stream = NullStream {
+            opened: true,
+            params: Some(*params),
+            ..Default::default()
+        };
+        Ok(stream_id)
+    }
+
+    fn start_stream(&self, stream_id: u32) -> Result<(), SoundError> {
+        let direction = direction_of(stream_id);
+        let mut state = self.state.lock();
+        let stream = state.enabled_stream(stream_id)?;
+        if !stream.running {
+            stream.set_running(direction, true);
+        }
+        Ok(())
+    }
+
+    fn stop_stream(&self, stream_id: u32) -> Result<(), SoundError> {
+        let direction = direction_of(stream_id);
+        let mut state = self.state.lock();
+        let stream = state.opened_stream(stream_id)?;
+        if stream.running {
+            stream.set_running(direction, false);
+        }
+        Ok(())
+    }
+
+    fn stream_position(&self, stream_id: u32) -> Result<StreamPosition, SoundError> {
+        let mut state = self.state.lock();
+        let stream = state.opened_stream(stream_id)?;
+        let now = read_tsc();
+        let bytes = match direction_of(stream_id) {
+            StreamDirection::Output => stream.played(now),
+            StreamDirection::Input => stream.clock(now),
+        };
+        Ok(StreamPosition {
+            frames: bytes / stream.frame_bytes() as Frames,
+            timestamp: tsc_to_duration(now),
+        })
+    }
+
+    fn drain_stream(&self, stream_id: u32) -> Result<(), SoundError> {
+        if stream_id == OUTPUT_STREAM {
+            self.wait_until(|state| {
+                let stream = match state.opened_stream(stream_id) {
+                    Ok(stream) => stream,
+                    Err(error) => return Some(Err(error)),
+                };
+                // The frames of a stopped stream are kept until it is started again.
+                let drained = stream.played(read_tsc()) == stream.transferred_bytes;
+                (drained || !stream.running).then_some(Ok(()))
+            })?;
+        }
+        self.stop_stream(stream_id)
+    }
+
+    fn close_stream(&self, stream_id: u32) -> Result<(), SoundError> {
+        let mut state = self.state.lock();
+        let stream = state.opened_stream(stream_id)?;
+        let disabled = stream.disabled;
+        *stream = NullStream {
+            disabled,
+            ..Default::default()
+        };
+        if stream_id == INPUT_STREAM {
+            state.records.clear();
+        }
+        Ok(())
+    }
+
+    fn disable_stream(&self, stream_id: u32) -> Result<(), SoundError> {
+        let direction = direction_of(stream_id);
+        let mut state = self.state.lock();
+        let stream = state.stream(stream_id)?;
+        stream.disabled = true;
+        if stream.running {
+            stream.set_running(direction, false);
+        }
+        Ok(())
+    }
+
+    fn enable_stream(&self, stream_id: u32) -> Result<(), SoundError> {
+        self.state.lock().stream(stream_id)?.disabled = false;
+        Ok(())
+    }
+
+    fn suspend(&self) -> Result<(), SoundError> {
+        let mut state = self.state.lock();
+        if state.suspended {
+            return Ok(());
+        }
+        for (stream_id, stream) in state.streams.iter_mut().enumerate() {
+            stream.running_before_suspend = stream.running;
+            if stream.running {
+                stream.set_running(direction_of(stream_id as u32), false);
+            }
+        }
+        state.suspended = true;
+        Ok(())
+    }
+
+    fn resume(&self) -> Result<(), SoundError> {
+        let mut state = self.state.lock();
+        if !state.suspended {
+            return Ok(());
+        }
+        for (stream_id, stream) in state.streams.iter_mut().enumerate() {
+            if stream.opened && stream.running_before_suspend {
+                stream.set_running(direction_of(stream_id as u32), true);
+            }
+        }
+        state.suspended = false;
+        Ok(())
+    }
+}
+
+impl AudioOutput for NullSoundDevice {
+    fn register_playback_callback(
+        &self,
+        _stream_id: u32,
+        _callback: Arc<PlaybackCallback>,
+    ) -> Result<CallbackHandle, SoundError> {
+        // There is no interrupt to invoke the callback from.
+        Err(SoundError::Unsupported)
+    }
+
+    fn write_stream(&self, stream_id: u32, frames: &[u8]) -> Result<usize, SoundError> {
+        if stream_id != OUTPUT_STREAM {
+            return Err(SoundError::InvalidParam);
+        }
+        self.wait_until(|state| {
+            let stream = match state.enabled_stream(stream_id) {
+                Ok(stream) => stream,
+                Err(error) => return Some(Err(error)),
+            };
+            let now = read_tsc();
+            let buffered = stream.transferred_bytes - stream.played(now);
+            // A write larger than the buffer is taken once the buffer is empty.
+            let room = (stream.params().buffer_bytes as u64).saturating_sub(buffered);
+            if room < frames.len() as u64 && buffered > 0 {
+                // A stopped stream would never make room.
+                return (!stream.running).then_some(Err(SoundError::NotReady));
+            }
+            stream.settle(StreamDirection::Output, now);
+            stream.transferred_bytes += frames.len() as u64;
+            Some(Ok(frames.len()))
+        })
+    }
+}
+
+impl AudioInput for NullSoundDevice {
+    fn register_callback(&self, _callback: Arc<SoundCallback>) -> CallbackHandle {
+        // The callbacks are never invoked, since the device records nothing by itself.
+        CallbackHandle::new(|| {})
+    }
+
+    fn capture_overrun_bytes(&self) -> u64 {
+        self.state.lock().streams[INPUT_STREAM as usize].overrun_bytes
+    }
+
+    fn read_stream(&self, stream_id: u32, frames: &mut [u8]) -> Result<usize, SoundError> {
+        if stream_id != INPUT_STREAM {
+            return Err(SoundError::InvalidParam);
+        }
+        let captured = self.wait_until(|state| {
+            let stream = match state.enabled_stream(stream_id) {
+                Ok(stream) => stream,
+                Err(error) => return Some(Err(error)),
+            };
+            let now = read_tsc();
+            stream.settle(StreamDirection::Input, now);
+            let buffer_bytes = stream.params().buffer_bytes as usize;
+            let frame_bytes = stream.frame_bytes() as usize;
+            // A read larger than the buffer gets a buffer of frames.
+            let len = frames.len().min(buffer_bytes) / frame_bytes * frame_bytes;
+            if stream.backlog(now) < len as u64 {
+                // A stopped stream would never capture enough.
+                return (!stream.running).then_some(Err(SoundError::NotReady));
+            }
+            stream.transferred_bytes += len as u64;
+            Some(Ok((len, stream.params().format)))
+        });
+        let (len, format) = captured?;
+        fill_silence(format, &mut frames[..len]);
+        Ok(len)
+    }
+
+    fn record_nb(&self, stream_id: u32, len: usize) -> Result<RecordToken, SoundError> {
+        if stream_id != INPUT_STREAM {
+            return Err(SoundError::InvalidParam);
+        }
+        let mut state = self.state.lock();
+        state.enabled_stream(stream_id)?;
+        let token = RecordToken(state.next_record_token);
+        state.next_record_token = state.next_record_token.wrapping_add(1);
+        state.records.insert(token, len);
+        Ok(token)
+    }
+
+    fn record_poll(
+        &self,
+        token: RecordToken,
+        frames: &mut [u8],
+    ) -> Option<Result<usize, SoundError>> {
+        let mut state = self.state.lock();
+        let len = *state.records.get(&token)?;
+        let stream = &mut state.streams[INPUT_STREAM as usize];
+        if !stream.opened {
+            state.records.remove(&token);
+            return Some(Err(SoundError::NotReady));
+        }
+        let now = read_tsc();
+        stream.settle(StreamDirection::Input, now);
+        // A request completes once enough silence has been captured to fill it.
+        if stream.backlog(now) < len as u64 {
+            return None;
+        }
+        let len = len.min(frames.len());
+        stream.transferred_bytes += len as u64;
+        let format = stream.params().format;
+        state.records.remove(&token);
+        fill_silence(format, &mut frames[..len]);
+        Some(Ok(len))
+    }
+}
+
+fn direction_of(stream_id: u32) -> StreamDirection {
+    if stream_id == OUTPUT_STREAM {
+        StreamDirection::Output
+    } else {
+        StreamDirection::Input
+    }
+}
+
+fn tsc_to_duration(tsc: u64) -> Duration {
+    let nanos = tsc as u128 * 1_000_000_000 / tsc_freq().max(1) as u128;
+    Duration::from_nanos(nanos as u64)
+}
+
+#[cfg(ktest)]
+mod test {
+    use ostd::prelude::*;
+
+    use super::*;
+
+    const PARAMS: StreamParams = StreamParams {
+        format: SampleFormat::U8,
+        rate: 8000,
+        channels: 1,
+        buffer_bytes: 800,
+        period_bytes: 80,
+    };
+
+    #[ktest]
+    fn null_device_runs_in_real_time() {
+        let device = NullSoundDevice::new();
+        let output = device
+            .open_stream(StreamDirection::Output, &PARAMS)
+            .unwrap();
+        let input = device.open_stream(StreamDirection::Input, &PARAMS).unwrap();
+        device.start_stream(output).unwrap();
+        device.start_stream(input).unwrap();
+
+        // The first write fills the buffer at once. The second one waits for
+        // the buffer to be played, and so does the drain: 0.2 s in all.
+        let start = read_tsc();
+        device.write_stream(output, &[0; 800]).unwrap();
+        device.write_stream(output, &[0; 800]).unwrap();
+        device.drain_stream(output).unwrap();
+        let elapsed = tsc_to_duration(read_tsc() - start);
+        assert!(elapsed >= Duration::from_millis(190));
+        assert_eq!(device.stream_position(output).unwrap().frames, 1600);
+
+        let mut frames = [0; 80];
+        assert_eq!(device.read_stream(input, &mut frames), Ok(80));
+        assert!(frames.iter().all(|sample| *sample == 0x80));
+    }
+}
@@ -0,0 +1,252 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! The priorities of the clients of the streams, and what happens when they contend.
+//!
+//! A device has few streams, e.g., a virtio card usually has one per direction.
+//! Every stream opened by [`open_output`] or [`open_input`] holds a claim with the
+//! priority of its client. When a client opens a stream while every stream of that
+//! direction is claimed, the [`PreemptionPolicy`] decides whether the claim of lowest
+//! priority below the one of the client is preempted, or the open is rejected with
+//! [`SoundError::Busy`].
+//!
+//! A preempted stream is closed under its client: the operations of its handle
+//! fail with [`SoundError::Preempted`], and its preemption callback is invoked.
+//!
+//! [`open_output`]: crate::open_output
+//! [`open_input`]: crate::open_input
+
+use alloc::{sync::Arc, vec::Vec};
+use core::{
+    fmt,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use ostd::sync::SpinLock;
+
+use crate::{AnySoundDevice, SoundError, StreamDirection};
+
+/// The priority of the client of a stream.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum StreamPriority {
+    /// E.g., notification sounds, which may be cut short.
+    Low,
+    #[default]
+    Normal,
+    /// E.g., a voice call, which should not be interrupted.
+    High,
+}
+
+/// What happens when a client opens a stream while every stream is claimed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PreemptionPolicy {
+    /// The open is rejected, whatever the priorities.
+    #[default]
+    Reject,
+    /// The stream of lowest priority below the one of the client is preempted.
+    ///
+    /// The open is rejected if every stream has at least the priority of the client.
+    PreemptLower,
+}
+
+/// Called when the stream of a client is preempted by a client of higher priority.
+pub type PreemptCallback = dyn Fn() + Send + Sync;
+
+static POLICY: SpinLock<PreemptionPolicy> = SpinLock::new(PreemptionPolicy::Reject);
+
+/// The streams held by the stream handles.
+static CLAIMS: SpinLock<Vec<Arc<Claim>>> = SpinLock::new(Vec::new());
+
+pub fn policy() -> PreemptionPolicy {
+    *POLICY.lock()
+}
+
+pub fn set_policy(policy: PreemptionPolicy) {
+    *POLICY.lock() = policy;
+}
+
+/// A stream held by a stream handle.
+pub(crate) struct Claim {
+    /// The address of the device, which identifies it.
+    device: usize,
+    direction: StreamDirection,
+    stream_id: u32,
+    priority: StreamPriority,
+    preempted: AtomicBool,
+    callback: SpinLock<Option<Arc<PreemptCallback>>>,
+}
+
+impl fmt::Debug for Claim {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Claim")
+            .field("direction", &self.direction)
+            .field("stream_id", &self.stream_id)
+            .field("priority", &self.priority)
+            .field("preempted", &self.preempted)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Claim {
+    pub(crate) fn priority(&self) -> StreamPriority {
+        self.priority
+    }
+
+    pub(crate) fn is_preempted(&self) -> bool {
+        self.preempted.load(Ordering::Acquire)
+    }
+
+    /// Fails with [`SoundError::Preempted`] once the stream has been preempted.
+    pub(crate) fn check(&self) -> Result<(), SoundError> {
+        if self.is_preempted() {
+            return Err(SoundError::Preempted);
+        }
+        Ok(())
+    }
+
+    /// Sets the callback invoked when the stream is preempted.
+    pub(crate) fn set_callback(&self, callback: Arc<PreemptCallback>) {
+        *self.callback.lock() = Some(callback);
+    }
+
+    /// Gives the stream up, returning whether it was still held.
+    ///
+    /// The stream must only be closed if it was, since a preempted stream may
+    /// have been opened again by another client.
+    pub(crate) fn release(self: &Arc<Self>) -> bool {
+        let mut claims = CLAIMS.lock();
+        let Some(index) = claims.iter().position(|claim| Arc::ptr_eq(claim, self)) else {
+            return false;
+        };
+        claims.swap_remove(index);
+        true
+    }
+}
+
+fn device_key(device: &(impl AnySoundDevice + ?Sized)) -> usize {
+    device as *const _ as *const () as usize
+}
+
+/// Records that a stream of `device` is held by a client of `priority`.
+pub(crate) fn claim(
+    device: &(impl AnySoundDevice + ?Sized),
+    direction: StreamDirection,
+    stream_id: u32,
+    priority: StreamPriority,
+) -> Arc<Claim> {
+    let claim = Arc::new(Claim {
+        device: device_key(device),
+        direction,
+        stream_id,
+        priority,
+        preempted: AtomicBool::new(false),
+        callback: SpinLock::new(None),
+    });
+    CLAIMS.lock().push(claim.clone());
+    claim
+}
+
+/// Opens a stream of `device` with `open` for a client of `priority`.
+///
+/// If `open` fails while every stream of `direction` is claimed, the policy is
+/// applied, and `open` is retried once a stream has been preempted.
+pub(crate) fn open_with_priority<T>(
+    device: &(impl AnySoundDevice + ?Sized),
+    direction: StreamDirection,
+    priority: StreamPriority,
+    open: impl Fn() -> Result<T, SoundError>,
+) -> Result<T, SoundError> {
+    let error = match open() {
+        Ok(opened) => return Ok(opened),
+        Err(error) => error,
+    };
+    let streams = device
+        .capabilities_snapshot()?
+        .streams()
+        .iter()
+        .filter(|capability| capability.direction == direction)
+        .count();
+
+    let victim = {
+        let mut claims = CLAIMS.lock();
+        let contenders = claims
+            .iter()
+            .filter(|claim| claim.device == device_key(device) && claim.direction == direction);
+        if contenders.clone().count() < streams {
+            // A stream is free, so it is the parameters that are not accepted.
+            return Err(error);
+        }
+        if policy() == PreemptionPolicy::Reject {
+            return Err(SoundError::Busy);
+        }
+        let Some(index) = contenders
+            .filter(|claim| claim.priority < priority)
+            .min_by_key(|claim| claim.priority)
+            .and_then(|victim| claims.iter().position(|claim| Arc::ptr_eq(claim, victim)))
+        else {
+            return Err(SoundError::Busy);
+        };
+        claims.swap_remove(index)
+    };
+
+    victim.preempted.store(true, Ordering::Release);
+    let _ = device.close_stream(victim.stream_id);
+    if let Some(callback) = victim.callback.lock().clone() {
+        callback();
+    }
+    open()
+}
+
+#[cfg(ktest)]
+mod test {
+    use alloc::vec;
+    use core::sync::atomic::AtomicUsize;
+
+    use ostd::prelude::*;
+
+    use super::*;
+    use crate::{
+        fake::FakeSoundDevice, open_output_with_priority, AudioOutput, SampleFormat, StreamParams,
+    };
+
+    const PARAMS: StreamParams = StreamParams {
+        format: SampleFormat::S16,
+        rate: 48000,
+        channels: 2,
+        buffer_bytes: 4096,
+        period_bytes: 1024,
+    };
+
+    #[ktest]
+    fn preempt_lower_priority() {
+        let capability = FakeSoundDevice::capability(0, StreamDirection::Output);
+        let device: Arc<dyn AudioOutput> = Arc::new(FakeSoundDevice::new(vec![capability]));
+
+        let mut chime = open_output_with_priority(&device, PARAMS, StreamPriority::Low).unwrap();
+        let preemptions = Arc::new(AtomicUsize::new(0));
+        {
+            let preemptions = preemptions.clone();
+            chime.set_preempt_callback(Arc::new(move || {
+                preemptions.fetch_add(1, Ordering::Relaxed);
+            }));
+        }
+
+        set_policy(PreemptionPolicy::Reject);
+        assert_eq!(
+            open_output_with_priority(&device, PARAMS, StreamPriority::High).map(|_| ()),
+            Err(SoundError::Busy)
+        );
+
+        set_policy(PreemptionPolicy::PreemptLower);
+        let call = open_output_with_priority(&device, PARAMS, StreamPriority::High).unwrap();
+        assert_eq!(preemptions.load(Ordering::Relaxed), 1);
+        assert_eq!(chime.write(&[0; 4]), Err(SoundError::Preempted));
+        // The preempted handle does not close the stream of the call.
+        drop(chime);
+        assert_eq!(
+            open_output_with_priority(&device, PARAMS, StreamPriority::Normal).map(|_| ()),
+            Err(SoundError::Busy)
+        );
+        drop(call);
+        set_policy(PreemptionPolicy::Reject);
+    }
+}
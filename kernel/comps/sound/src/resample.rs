@@ -0,0 +1,101 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! Sample-rate conversion of playback streams.
+
+use alloc::{vec, vec::Vec};
+use core::fmt::Debug;
+
+/// Converts interleaved S16 frames from one frame rate to another.
+///
+/// A resampler keeps state between calls, so the frames of a stream must be
+/// passed in order and a resampler must not be shared between streams.
+pub trait Resampler: Send + Sync + Debug {
+    /// Resamples the frames in `input`, appending the resulting frames to `output`.
+    fn resample(&mut self, input: &[i16], output: &mut Vec<i16>);
+
+    /// Returns the delay the resampler adds to the stream, in output frames.
+    fn latency_frames(&self) -> u32;
+}
+
+/// A resampler that linearly interpolates between adjacent frames.
+#[derive(Debug)]
+pub struct LinearResampler {
+    channels: usize,
+    /// The distance between two output frames, in input frames, as a 32.32 fixed-point number.
+    step: u64,
+    /// The position of the next output frame, in input frames after `last_frame`,
+    /// as a 32.32 fixed-point number.
+    position: u64,
+    /// The last frame of the previous input.
+    last_frame: Vec<i16>,
+}
+
+impl LinearResampler {
+    /// Creates a resampler from `from_rate` to `to_rate` for frames of `channels` samples.
+    pub fn new(from_rate: u32, to_rate: u32, channels: u8) -> Self {
+        let channels = (channels as usize).max(1);
+        Self {
+            channels,
+            step: ((from_rate as u64) << 32) / to_rate.max(1) as u64,
+            position: 0,
+            last_frame: vec![0; channels],
+        }
+    }
+
+    fn sample(&self, input: &[i16], frame: usize, channel: usize) -> i16 {
+        if frame == 0 {
+            self.last_frame[channel]
+        } else {
+            input[(frame - 1) * self.channels + channel]
+        }
+    }
+}
+
+impl Resampler for LinearResampler {
+    fn resample(&mut self, input: &[i16], output: &mut Vec<i16>) {
+        // The frames are indexed from `last_frame`, which is frame 0.
+        let frames = input.len() / self.channels;
+        if frames == 0 {
+            return;
+        }
+        while (self.position >> 32) < frames as u64 {
+            let frame = (self.position >> 32) as usize;
+            let fraction = (self.position & 0xffff_ffff) as i64;
+            for channel in 0..self.channels {
+                let from = self.sample(input, frame, channel) as i64;
+                let to = self.sample(input, frame + 1, channel) as i64;
+                output.push((from + (((to - from) * fraction) >> 32)) as i16);
+            }
+            self.position += self.step;
+        }
+        self.position -= (frames as u64) << 32;
+        self.last_frame
+            .copy_from_slice(&input[(frames - 1) * self.channels..frames * self.channels]);
+    }
+
+    fn latency_frames(&self) -> u32 {
+        // Every output frame waits for the input frame after it.
+        1
+    }
+}
+
+#[cfg(ktest)]
+mod test {
+    use ostd::prelude::*;
+
+    use super::*;
+
+    #[ktest]
+    fn resample_44100_to_48000() {
+        let mut resampler = LinearResampler::new(44100, 48000, 2);
+        let input = vec![1000i16; 4410 * 2];
+        let mut output = Vec::new();
+        for chunk in input.chunks(441 * 2) {
+            resampler.resample(chunk, &mut output);
+        }
+
+        assert!((4800..=4801).contains(&(output.len() / 2)));
+        // Apart from the frames interpolated from the initial silence, the level is kept.
+        assert!(output[4..].iter().all(|sample| *sample == 1000));
+    }
+}
@@ -0,0 +1,135 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! A lock-free buffer of captured PCM frames.
+
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicU64, AtomicU8, AtomicUsize, Ordering};
+
+/// A single-producer, single-consumer ring of captured bytes.
+///
+/// The interrupt handler of a device pushes the frames it receives and the
+/// readers of the device pop them, without either side taking a lock. When
+/// the ring is full the newly received bytes are dropped and counted as an
+/// overrun, so that a slow reader never blocks the interrupt handler.
+///
+/// At most one context may push and at most one context may pop at a time.
+#[derive(Debug)]
+pub struct CaptureRing {
+    buffer: Vec<AtomicU8>,
+    /// The total number of bytes popped.
+    head: AtomicUsize,
+    /// The total number of bytes pushed.
+    tail: AtomicUsize,
+    /// The total number of bytes dropped because the ring was full.
+    overrun_bytes: AtomicU64,
+}
+
+impl CaptureRing {
+    /// Creates a ring that holds up to `capacity` bytes.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            buffer: (0..capacity).map(|_| AtomicU8::new(0)).collect(),
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+            overrun_bytes: AtomicU64::new(0),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns the number of bytes that can be popped.
+    pub fn len(&self) -> usize {
+        let tail = self.tail.load(Ordering::Acquire);
+        let head = self.head.load(Ordering::Acquire);
+        tail.wrapping_sub(head)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of bytes dropped because the ring was full.
+    pub fn overrun_bytes(&self) -> u64 {
+        self.overrun_bytes.load(Ordering::Relaxed)
+    }
+
+    /// Pushes captured bytes, returning the number of bytes stored.
+    ///
+    /// The bytes that do not fit are dropped and accounted as an overrun.
+    pub fn push(&self, data: &[u8]) -> usize {
+        let tail = self.tail.load(Ordering::Relaxed);
+        let head = self.head.load(Ordering::Acquire);
+        let free = self.capacity() - tail.wrapping_sub(head);
+        let len = data.len().min(free);
+        for (i, byte) in data[..len].iter().enumerate() {
+            self.buffer[tail.wrapping_add(i) % self.capacity()].store(*byte, Ordering::Relaxed);
+        }
+        self.tail.store(tail.wrapping_add(len), Ordering::Release);
+
+        let dropped = data.len() - len;
+        if dropped > 0 {
+            self.overrun_bytes
+                .fetch_add(dropped as u64, Ordering::Relaxed);
+        }
+        len
+    }
+
+    /// Pops captured bytes into `data`, returning the number of bytes popped.
+    pub fn pop(&self, data: &mut [u8]) -> usize {
+        let head = self.head.load(Ordering::Relaxed);
+        let tail = self.tail.load(Ordering::Acquire);
+        let len = data.len().min(tail.wrapping_sub(head));
+        for (i, byte) in data[..len].iter_mut().enumerate() {
+            *byte = self.buffer[head.wrapping_add(i) % self.capacity()].load(Ordering::Relaxed);
+        }
+        self.head.store(head.wrapping_add(len), Ordering::Release);
+        len
+    }
+
+    /// Discards every captured byte.
+    ///
+    /// This must be called from the popping side.
+    pub fn clear(&self) {
+        let tail = self.tail.load(Ordering::Acquire);
+        self.head.store(tail, Ordering::Release);
+    }
+
+    /// Discards every captured byte and zeroes the storage, so that no residual
+    /// audio stays in memory.
+    ///
+    /// This must be called from the popping side while no bytes are being pushed.
+    pub fn scrub(&self) {
+        self.clear();
+        for byte in self.buffer.iter() {
+            byte.store(0, Ordering::Relaxed);
+        }
+    }
+}
+
+#[cfg(ktest)]
+mod test {
+    use ostd::prelude::*;
+
+    use super::*;
+
+    #[ktest]
+    fn wrap_around_and_overrun() {
+        let ring = CaptureRing::new(4);
+        assert_eq!(ring.push(&[1, 2, 3]), 3);
+
+        let mut data = [0; 2];
+        assert_eq!(ring.pop(&mut data), 2);
+        assert_eq!(data, [1, 2]);
+
+        // Only three of the four bytes fit after wrapping around.
+        assert_eq!(ring.push(&[4, 5, 6, 7]), 3);
+        assert_eq!(ring.overrun_bytes(), 1);
+
+        let mut data = [0; 8];
+        assert_eq!(ring.pop(&mut data), 4);
+        assert_eq!(data[..4], [3, 4, 5, 6]);
+        assert!(ring.is_empty());
+    }
+}
@@ -0,0 +1,210 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! Routing of captured audio from input devices to output devices.
+//!
+//! A link connects an input device to an output device, e.g., to monitor a
+//! microphone or to play what the loopback device captures on a virtio card.
+//! Each link has its own gain. Frames move along the links when [`pump`] is
+//! called, one period per link at a time.
+
+use alloc::{collections::BTreeMap, sync::Arc, vec, vec::Vec};
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use ostd::sync::Mutex;
+
+use crate::{
+    open_input, open_output, AudioInput, AudioOutput, InputStream, OutputStream, SampleFormat,
+    SoundError, StreamParams,
+};
+
+/// The gain that leaves the samples unchanged, in Q15.
+pub const UNITY_GAIN: u32 = 1 << 15;
+
+/// The parameters the streams of a link are opened with by [`connect`].
+pub const LINK_PARAMS: StreamParams = StreamParams {
+    format: SampleFormat::S16,
+    rate: 48000,
+    channels: 2,
+    // 40 ms, in periods of 10 ms.
+    buffer_bytes: 7680,
+    period_bytes: 1920,
+};
+
+/// Identifies a link made by [`connect`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LinkId(u32);
+
+/// A link between an input and an output stream.
+#[derive(Debug)]
+pub struct Link {
+    input: InputStream,
+    output: OutputStream,
+    /// The gain applied to the samples, in Q15.
+    gain: u32,
+    /// Holds a period of frames on their way to the output.
+    period: Vec<u8>,
+}
+
+impl Link {
+    /// Opens streams of `src` and `dst` with the same `params` and starts them.
+    ///
+    /// Only [`SampleFormat::S16`] frames can be routed, since the gain is applied to them.
+    pub fn new(
+        src: &Arc<dyn AudioInput>,
+        dst: &Arc<dyn AudioOutput>,
+        params: StreamParams,
+    ) -> Result<Self, SoundError> {
+        if params.format != SampleFormat::S16 {
+            return Err(SoundError::InvalidParam);
+        }
+        let mut input = open_input(src, params)?;
+        let mut output = open_output(dst, params)?;
+        input.start()?;
+        output.start()?;
+        Ok(Self {
+            input,
+            output,
+            gain: UNITY_GAIN,
+            period: vec![0; params.period_bytes as usize],
+        })
+    }
+
+    /// Returns the gain of the link, in Q15.
+    pub fn gain(&self) -> u32 {
+        self.gain
+    }
+
+    /// Sets the gain of the link, in Q15; gains above [`UNITY_GAIN`] amplify.
+    pub fn set_gain(&mut self, gain: u32) {
+        self.gain = gain;
+    }
+
+    /// Moves up to a period of frames from the input to the output.
+    ///
+    /// Returns the number of bytes moved.
+    pub fn pump(&mut self) -> Result<usize, SoundError> {
+        let len = self.input.read(&mut self.period)?;
+        let frames = &mut self.period[..len];
+        if self.gain != UNITY_GAIN {
+            apply_gain(frames, self.gain);
+        }
+
+        let mut written = 0;
+        while written < len {
+            match self.output.write(&frames[written..])? {
+                0 => return Err(SoundError::IoError),
+                n => written += n,
+            }
+        }
+        Ok(len)
+    }
+}
+
+/// Scales little-endian S16 samples by a Q15 `gain`, saturating.
+fn apply_gain(frames: &mut [u8], gain: u32) {
+    for sample in frames.chunks_exact_mut(2) {
+        let value = i16::from_le_bytes([sample[0], sample[1]]) as i64;
+        let scaled = (value * gain as i64) >> 15;
+        let scaled = scaled.clamp(i16::MIN as i64, i16::MAX as i64) as i16;
+        sample.copy_from_slice(&scaled.to_le_bytes());
+    }
+}
+
+/// The links are pumped with the lock held, and reads may sleep.
+static LINKS: Mutex<BTreeMap<LinkId, Link>> = Mutex::new(BTreeMap::new());
+static NEXT_LINK_ID: AtomicU32 = AtomicU32::new(0);
+
+/// Connects `src` to `dst` with [`LINK_PARAMS`] and unity gain.
+pub fn connect(
+    src: &Arc<dyn AudioInput>,
+    dst: &Arc<dyn AudioOutput>,
+) -> Result<LinkId, SoundError> {
+    connect_with(src, dst, LINK_PARAMS)
+}
+
+/// Connects `src` to `dst`, opening their streams with `params`.
+pub fn connect_with(
+    src: &Arc<dyn AudioInput>,
+    dst: &Arc<dyn AudioOutput>,
+    params: StreamParams,
+) -> Result<LinkId, SoundError> {
+    let link = Link::new(src, dst, params)?;
+    let id = LinkId(NEXT_LINK_ID.fetch_add(1, Ordering::Relaxed));
+    LINKS.lock().insert(id, link);
+    Ok(id)
+}
+
+/// Removes a link, closing its streams.
+pub fn disconnect(id: LinkId) -> Result<(), SoundError> {
+    LINKS
+        .lock()
+        .remove(&id)
+        .map(drop)
+        .ok_or(SoundError::InvalidParam)
+}
+
+/// Sets the gain of a link, in Q15.
+pub fn set_gain(id: LinkId, gain: u32) -> Result<(), SoundError> {
+    LINKS
+        .lock()
+        .get_mut(&id)
+        .map(|link| link.set_gain(gain))
+        .ok_or(SoundError::InvalidParam)
+}
+
+/// Returns the links and their gains.
+pub fn links() -> Vec<(LinkId, u32)> {
+    LINKS
+        .lock()
+        .iter()
+        .map(|(id, link)| (*id, link.gain()))
+        .collect()
+}
+
+/// Moves up to a period of frames along every link.
+///
+/// Every link is pumped even if some fail; the first error is returned.
+pub fn pump() -> Result<usize, SoundError> {
+    let mut links = LINKS.lock();
+    let mut moved = 0;
+    let mut result = Ok(());
+    for link in links.values_mut() {
+        match link.pump() {
+            Ok(len) => moved += len,
+            Err(error) => result = result.and(Err(error)),
+        }
+    }
+    result.map(|()| moved)
+}
+
+#[cfg(ktest)]
+mod test {
+    use ostd::prelude::*;
+
+    use super::*;
+    use crate::{fake::FakeSoundDevice, StreamDirection};
+
+    #[ktest]
+    fn route_with_gain() {
+        let mic = Arc::new(FakeSoundDevice::new(vec![FakeSoundDevice::capability(
+            0,
+            StreamDirection::Input,
+        )]));
+        let speaker = Arc::new(FakeSoundDevice::new(vec![FakeSoundDevice::capability(
+            0,
+            StreamDirection::Output,
+        )]));
+        let (src, dst): (Arc<dyn AudioInput>, Arc<dyn AudioOutput>) =
+            (mic.clone(), speaker.clone());
+
+        let mut link = Link::new(&src, &dst, LINK_PARAMS).unwrap();
+        mic.push_capture(0, &[0x00, 0x10, 0x00, 0x60, 0x00, 0xa0, 0x00, 0x00]);
+        link.set_gain(UNITY_GAIN * 2);
+        assert_eq!(link.pump(), Ok(8));
+        // 0x1000 is doubled, 0x6000 and -0x6000 saturate.
+        assert_eq!(
+            speaker.played(0),
+            [0x00, 0x20, 0xff, 0x7f, 0x00, 0x80, 0x00, 0x00]
+        );
+    }
+}
@@ -0,0 +1,531 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! Handles to the PCM streams of sound devices.
+
+use alloc::{boxed::Box, sync::Arc, vec, vec::Vec};
+
+use crate::{
+    convert::{can_convert, convert, fallback_formats, sample_bytes},
+    mix::{default_layout, ChannelRouter},
+    policy::{self, Claim, PreemptCallback, StreamPriority},
+    resample::{LinearResampler, Resampler},
+    AnySoundDevice, AudioInput, AudioOutput, SampleFormat, SoundError, StreamCapability,
+    StreamDirection, StreamPosition,
+};
+
+/// The parameters a stream is opened with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StreamParams {
+    pub format: SampleFormat,
+    /// The frame rate in Hz.
+    pub rate: u32,
+    pub channels: u8,
+    /// The size of the hardware buffer in bytes.
+    pub buffer_bytes: u32,
+    /// The size of a hardware period in bytes.
+    pub period_bytes: u32,
+}
+
+/// Opens a free output stream of `device` with the given parameters.
+///
+/// If no stream accepts `params.format`, a stream is opened with a format
+/// the frames can be converted to, and every write is converted. If no
+/// stream accepts `params.rate` either, an S16 stream is opened at the
+/// closest rate and the frames are resampled with a [`LinearResampler`].
+///
+/// The frames are expected in the [`default_layout`] of their channels. If the
+/// device reports a different channel map for them, every write is routed to
+/// it. Opening fails with [`SoundError::InvalidParam`] if the device reports
+/// channel maps but none for a surround stream of `params.channels` channels.
+///
+/// The stream is opened with [`StreamPriority::Normal`].
+pub fn open_output(
+    device: &Arc<dyn AudioOutput>,
+    params: StreamParams,
+) -> Result<OutputStream, SoundError> {
+    open_output_with_priority(device, params, StreamPriority::default())
+}
+
+/// Opens a free output stream of `device` like [`open_output`], for a client of `priority`.
+///
+/// If every output stream is claimed, a stream of lower priority may be
+/// preempted, as decided by the [`policy`](crate::policy).
+pub fn open_output_with_priority(
+    device: &Arc<dyn AudioOutput>,
+    params: StreamParams,
+    priority: StreamPriority,
+) -> Result<OutputStream, SoundError> {
+    let (stream_id, device_format, device_rate) =
+        policy::open_with_priority(&**device, StreamDirection::Output, priority, || {
+            match open_stream(&**device, StreamDirection::Output, &params) {
+                Ok((stream_id, device_format)) => Ok((stream_id, device_format, params.rate)),
+                Err(SoundError::InvalidParam) => open_resampled_output(&**device, &params),
+                Err(error) => Err(error),
+            }
+        })?;
+    let resampler = (device_rate != params.rate).then(|| {
+        Box::new(LinearResampler::new(
+            params.rate,
+            device_rate,
+            params.channels,
+        )) as Box<dyn Resampler>
+    });
+    let mut stream = OutputStream {
+        device: device.clone(),
+        claim: policy::claim(&**device, StreamDirection::Output, stream_id, priority),
+        stream_id,
+        params,
+        device_format,
+        device_rate,
+        resampler,
+        layout: default_layout(params.channels),
+        device_layout: default_layout(params.channels),
+        router: None,
+        position: 0,
+    };
+    // The stream is closed when dropped on error.
+    let capability = stream_capability(&**device, stream_id)?;
+    if !capability.supports_layout(params.channels) {
+        return Err(SoundError::InvalidParam);
+    }
+    if let Some(map) = capability.channel_map(params.channels) {
+        stream.device_layout = map.to_vec();
+    }
+    stream.update_router();
+    Ok(stream)
+}
+
+fn stream_capability(
+    device: &(impl AnySoundDevice + ?Sized),
+    stream_id: u32,
+) -> Result<StreamCapability, SoundError> {
+    device
+        .capabilities_snapshot()?
+        .stream(stream_id)
+        .cloned()
+        .ok_or(SoundError::InvalidParam)
+}
+
+/// Opens a free input stream of `device` with the given parameters.
+///
+/// If no stream accepts `params.format`, a stream is opened with a format
+/// that can be converted to it, and every read is converted.
+///
+/// The stream is opened with [`StreamPriority::Normal`].
+pub fn open_input(
+    device: &Arc<dyn AudioInput>,
+    params: StreamParams,
+) -> Result<InputStream, SoundError> {
+    open_input_with_priority(device, params, StreamPriority::default())
+}
+
+/// Opens a free input stream of `device` like [`open_input`], for a client of `priority`.
+///
+/// If every input stream is claimed, a stream of lower priority may be
+/// preempted, as decided by the [`policy`](crate::policy).
+pub fn open_input_with_priority(
+    device: &Arc<dyn AudioInput>,
+    params: StreamParams,
+    priority: StreamPriority,
+) -> Result<InputStream, SoundError> {
+    let (stream_id, device_format) =
+        policy::open_with_priority(&**device, StreamDirection::Input, priority, || {
+            open_stream(&**device, StreamDirection::Input, &params)
+        })?;
+    Ok(InputStream {
+        device: device.clone(),
+        claim: policy::claim(&**device, StreamDirection::Input, stream_id, priority),
+        stream_id,
+        params,
+        device_format,
+        position: 0,
+    })
+}
+
+/// Opens a stream with `params`, falling back to the convertible formats.
+///
+/// Returns the ID of the stream and the format it is opened with.
+fn open_stream(
+    device: &(impl AnySoundDevice + ?Sized),
+    direction: StreamDirection,
+    params: &StreamParams,
+) -> Result<(u32, SampleFormat), SoundError> {
+    let error = match device.open_stream(direction, params) {
+        Ok(stream_id) => return Ok((stream_id, params.format)),
+        Err(error) => error,
+    };
+    if error != SoundError::InvalidParam {
+        return Err(error);
+    }
+
+    let Some(client_bytes) = sample_bytes(params.format) else {
+        return Err(error);
+    };
+    for format in fallback_formats(params.format, direction) {
+        let device_bytes = sample_bytes(format).unwrap() as u32;
+        let device_params = StreamParams {
+            format,
+            buffer_bytes: params.buffer_bytes / client_bytes as u32 * device_bytes,
+            period_bytes: params.period_bytes / client_bytes as u32 * device_bytes,
+            ..*params
+        };
+        if let Ok(stream_id) = device.open_stream(direction, &device_params) {
+            return Ok((stream_id, format));
+        }
+    }
+    Err(error)
+}
+
+/// Opens an S16 output stream at the supported rate closest to `params.rate`.
+///
+/// Returns the ID of the stream, its format and its rate.
+fn open_resampled_output(
+    device: &(impl AnySoundDevice + ?Sized),
+    params: &StreamParams,
+) -> Result<(u32, SampleFormat, u32), SoundError> {
+    let format = SampleFormat::S16;
+    let Some(client_bytes) = sample_bytes(params.format) else {
+        return Err(SoundError::InvalidParam);
+    };
+    if !can_convert(params.format, format) {
+        return Err(SoundError::InvalidParam);
+    }
+
+    let mut rates: Vec<u32> = device
+        .capabilities_snapshot()?
+        .streams()
+        .iter()
+        .filter(|capability| {
+            capability.direction == StreamDirection::Output
+                && capability.formats.contains(&format)
+                && capability.channels.contains(&params.channels)
+        })
+        .flat_map(|capability| capability.rates.iter().copied())
+        .collect();
+    rates.sort_unstable_by_key(|rate| rate.abs_diff(params.rate));
+    rates.dedup();
+
+    let scale = |bytes: u32, rate: u32| {
+        let bytes = bytes as u64 / client_bytes as u64 * sample_bytes(format).unwrap() as u64;
+        (bytes * rate as u64 / params.rate as u64) as u32
+    };
+    for rate in rates {
+        let device_params = StreamParams {
+            format,
+            rate,
+            buffer_bytes: scale(params.buffer_bytes, rate),
+            period_bytes: scale(params.period_bytes, rate),
+            ..*params
+        };
+        if let Ok(stream_id) = device.open_stream(StreamDirection::Output, &device_params) {
+            return Ok((stream_id, format, rate));
+        }
+    }
+    Err(SoundError::InvalidParam)
+}
+
+/// Converts a length in bytes of samples of format `from` to the one of the same samples in `to`.
+fn convert_len(len: usize, from: SampleFormat, to: SampleFormat) -> usize {
+    match (sample_bytes(from), sample_bytes(to)) {
+        (Some(from_bytes), Some(to_bytes)) => len / from_bytes * to_bytes,
+        _ => len,
+    }
+}
+
+/// An opened output stream.
+///
+/// The stream is closed when the handle is dropped. Once the stream is
+/// preempted, the operations of the handle fail with [`SoundError::Preempted`].
+#[derive(Debug)]
+pub struct OutputStream {
+    device: Arc<dyn AudioOutput>,
+    /// Holds the stream until it is closed or preempted.
+    claim: Arc<Claim>,
+    stream_id: u32,
+    params: StreamParams,
+    /// The format the device stream is opened with.
+    device_format: SampleFormat,
+    /// The rate the device stream is opened with.
+    device_rate: u32,
+    /// Converts the frames to `device_rate`, if it differs from the rate of `params`.
+    resampler: Option<Box<dyn Resampler>>,
+    /// The position of each channel of the written frames.
+    layout: Vec<u8>,
+    /// The position of each channel of the frames reaching the device.
+    device_layout: Vec<u8>,
+    /// Reorders the channels from `layout` to `device_layout`, if they differ.
+    router: Option<ChannelRouter>,
+    /// The number of bytes written since the stream was opened.
+    position: u64,
+}
+
+impl OutputStream {
+    pub fn stream_id(&self) -> u32 {
+        self.stream_id
+    }
+
+    pub fn params(&self) -> &StreamParams {
+        &self.params
+    }
+
+    pub fn priority(&self) -> StreamPriority {
+        self.claim.priority()
+    }
+
+    /// Returns whether the stream has been preempted by a client of higher priority.
+    pub fn is_preempted(&self) -> bool {
+        self.claim.is_preempted()
+    }
+
+    /// Sets the callback invoked when the stream is preempted.
+    pub fn set_preempt_callback(&mut self, callback: Arc<PreemptCallback>) {
+        self.claim.set_callback(callback);
+    }
+
+    /// Returns the number of bytes written since the stream was opened.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Returns how many frames the device has played, for synchronizing with other media.
+    ///
+    /// The frames are counted at the rate of the device, which may differ from
+    /// the rate of the stream.
+    pub fn device_position(&self) -> Result<StreamPosition, SoundError> {
+        self.claim.check()?;
+        self.device.stream_position(self.stream_id)
+    }
+
+    pub fn start(&mut self) -> Result<(), SoundError> {
+        self.claim.check()?;
+        self.device.start_stream(self.stream_id)
+    }
+
+    pub fn stop(&mut self) -> Result<(), SoundError> {
+        self.claim.check()?;
+        self.device.stop_stream(self.stream_id)
+    }
+
+    /// Returns the format the frames are converted to before reaching the device.
+    pub fn device_format(&self) -> SampleFormat {
+        self.device_format
+    }
+
+    /// Returns the rate the frames are resampled to before reaching the device.
+    pub fn device_rate(&self) -> u32 {
+        self.device_rate
+    }
+
+    /// Replaces the resampler of the stream.
+    ///
+    /// The resampler must convert from the rate of [`Self::params`] to [`Self::device_rate`].
+    /// Only streams whose device format is S16 can be resampled.
+    pub fn set_resampler(&mut self, resampler: Box<dyn Resampler>) -> Result<(), SoundError> {
+        if self.device_format != SampleFormat::S16 {
+            return Err(SoundError::Unsupported);
+        }
+        self.resampler = Some(resampler);
+        Ok(())
+    }
+
+    /// Returns the latency added by the resampler, in microseconds.
+    pub fn resampler_latency_us(&self) -> u64 {
+        self.resampler.as_ref().map_or(0, |resampler| {
+            resampler.latency_frames() as u64 * 1_000_000 / self.device_rate as u64
+        })
+    }
+
+    /// Returns the position of each channel of the written frames.
+    pub fn channel_map(&self) -> &[u8] {
+        &self.layout
+    }
+
+    /// Plays the channels of the written frames at the positions of `map`,
+    /// e.g., `[FR, FL]` to swap the stereo channels.
+    ///
+    /// If the device can switch to `map` itself, it does. Otherwise the channels
+    /// are routed in software to the positions of the device.
+    pub fn set_channel_map(&mut self, map: &[u8]) -> Result<(), SoundError> {
+        if map.len() != self.params.channels as usize || sample_bytes(self.params.format).is_none()
+        {
+            return Err(SoundError::InvalidParam);
+        }
+        self.claim.check()?;
+        let capability = stream_capability(&*self.device, self.stream_id)?;
+        if capability.remappable && capability.channel_maps.iter().any(|other| other == map) {
+            self.device.set_channel_map(self.stream_id, map)?;
+            self.device_layout = map.to_vec();
+        }
+        self.layout = map.to_vec();
+        self.update_router();
+        Ok(())
+    }
+
+    fn update_router(&mut self) {
+        self.router = if sample_bytes(self.params.format).is_some() {
+            ChannelRouter::new(&self.layout, &self.device_layout)
+        } else {
+            None
+        };
+    }
+
+    /// Writes PCM frames to the stream, returning the number of bytes written.
+    ///
+    /// When the frames are resampled, either all of the whole frames are written or none of them.
+    pub fn write(&mut self, frames: &[u8]) -> Result<usize, SoundError> {
+        self.claim.check()?;
+        let routed;
+        let frames = match &self.router {
+            Some(router) => {
+                routed = router.route(frames, sample_bytes(self.params.format).unwrap());
+                &routed[..]
+            }
+            None => frames,
+        };
+        let len = if let Some(resampler) = self.resampler.as_mut() {
+            let converted = convert(self.params.format, SampleFormat::S16, frames)
+                .ok_or(SoundError::InvalidParam)?;
+            let samples: Vec<i16> = converted
+                .chunks_exact(2)
+                .map(|sample| i16::from_le_bytes([sample[0], sample[1]]))
+                .collect();
+            let frame_samples = self.params.channels as usize;
+            let whole_samples = samples.len() / frame_samples * frame_samples;
+            let mut resampled = Vec::new();
+            resampler.resample(&samples[..whole_samples], &mut resampled);
+            let bytes: Vec<u8> = resampled
+                .iter()
+                .flat_map(|sample| sample.to_le_bytes())
+                .collect();
+            self.device.write_stream(self.stream_id, &bytes)?;
+            convert_len(whole_samples * 2, SampleFormat::S16, self.params.format)
+        } else if self.device_format == self.params.format {
+            self.device.write_stream(self.stream_id, frames)?
+        } else {
+            let converted = convert(self.params.format, self.device_format, frames)
+                .ok_or(SoundError::InvalidParam)?;
+            let written = self.device.write_stream(self.stream_id, &converted)?;
+            convert_len(written, self.device_format, self.params.format)
+        };
+        self.position += len as u64;
+        Ok(len)
+    }
+
+    /// Waits until every written frame has been consumed by the device, then
+    /// stops the stream.
+    pub fn drain(&mut self) -> Result<(), SoundError> {
+        self.claim.check()?;
+        self.device.drain_stream(self.stream_id)
+    }
+}
+
+impl Drop for OutputStream {
+    fn drop(&mut self) {
+        if self.claim.release() {
+            let _ = self.device.close_stream(self.stream_id);
+        }
+    }
+}
+
+/// An opened input stream.
+///
+/// The stream is closed when the handle is dropped. Once the stream is
+/// preempted, the operations of the handle fail with [`SoundError::Preempted`].
+#[derive(Debug)]
+pub struct InputStream {
+    device: Arc<dyn AudioInput>,
+    /// Holds the stream until it is closed or preempted.
+    claim: Arc<Claim>,
+    stream_id: u32,
+    params: StreamParams,
+    /// The format the device stream is opened with.
+    device_format: SampleFormat,
+    /// The number of bytes read since the stream was opened.
+    position: u64,
+}
+
+impl InputStream {
+    pub fn stream_id(&self) -> u32 {
+        self.stream_id
+    }
+
+    pub fn params(&self) -> &StreamParams {
+        &self.params
+    }
+
+    pub fn priority(&self) -> StreamPriority {
+        self.claim.priority()
+    }
+
+    /// Returns whether the stream has been preempted by a client of higher priority.
+    pub fn is_preempted(&self) -> bool {
+        self.claim.is_preempted()
+    }
+
+    /// Sets the callback invoked when the stream is preempted.
+    pub fn set_preempt_callback(&mut self, callback: Arc<PreemptCallback>) {
+        self.claim.set_callback(callback);
+    }
+
+    /// Returns the number of bytes read since the stream was opened.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Returns how many frames the device has captured, for synchronizing with other media.
+    ///
+    /// The frames are counted at the rate of the device, which may differ from
+    /// the rate of the stream.
+    pub fn device_position(&self) -> Result<StreamPosition, SoundError> {
+        self.claim.check()?;
+        self.device.stream_position(self.stream_id)
+    }
+
+    pub fn start(&mut self) -> Result<(), SoundError> {
+        self.claim.check()?;
+        self.device.start_stream(self.stream_id)
+    }
+
+    pub fn stop(&mut self) -> Result<(), SoundError> {
+        self.claim.check()?;
+        self.device.stop_stream(self.stream_id)
+    }
+
+    /// Returns the format the device records the frames in before they are converted.
+    pub fn device_format(&self) -> SampleFormat {
+        self.device_format
+    }
+
+    /// Reads recorded PCM frames into `frames`, returning the number of bytes read.
+    pub fn read(&mut self, frames: &mut [u8]) -> Result<usize, SoundError> {
+        self.claim.check()?;
+        let len = if self.device_format == self.params.format {
+            self.device.read_stream(self.stream_id, frames)?
+        } else {
+            let mut recorded =
+                vec![0u8; convert_len(frames.len(), self.params.format, self.device_format)];
+            let read = self.device.read_stream(self.stream_id, &mut recorded)?;
+            let converted = convert(self.device_format, self.params.format, &recorded[..read])
+                .ok_or(SoundError::InvalidParam)?;
+            frames[..converted.len()].copy_from_slice(&converted);
+            converted.len()
+        };
+        self.position += len as u64;
+        Ok(len)
+    }
+
+    /// Waits until the device has completed every pending read, then stops
+    /// the stream.
+    pub fn drain(&mut self) -> Result<(), SoundError> {
+        self.claim.check()?;
+        self.device.drain_stream(self.stream_id)
+    }
+}
+
+impl Drop for InputStream {
+    fn drop(&mut self) {
+        if self.claim.release() {
+            let _ = self.device.close_stream(self.stream_id);
+        }
+    }
+}
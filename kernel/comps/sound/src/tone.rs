@@ -0,0 +1,486 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! Test tones and a sound device that captures them.
+//!
+//! [`ToneGenerator`] produces sine or square waves at any frequency and rate.
+//! [`ToneSoundDevice`] has a single input stream that records the tone of a
+//! generator, so that the capture side of the stack can be tested end to end
+//! with a known signal.
+
+use alloc::{collections::BTreeMap, string::ToString, sync::Arc, vec, vec::Vec};
+use core::{f32::consts::PI, time::Duration};
+
+use ostd::sync::SpinLock;
+
+use crate::{
+    convert::{convert, sample_bytes},
+    AnySoundDevice, AudioInput, CallbackHandle, CompletionMode, CompletionPriority, Frames,
+    LatencyHistogram, RecordToken, SampleFormat, SoundCallback, SoundError, StreamCapability,
+    StreamDirection, StreamParams, StreamPosition,
+};
+
+/// The name the tone device is registered with.
+pub const TONE_DEVICE_NAME: &str = "Tone";
+
+/// The stable ID the tone device is registered with.
+///
+/// It sorts after the IDs of the devices on a bus and of the other virtual
+/// devices, so the tone device is never the default one.
+pub const TONE_STABLE_ID: &str = "virtual-tone";
+
+/// The frequency of the tone captured by a new [`ToneSoundDevice`], in Hz.
+pub const DEFAULT_TONE_FREQUENCY: u32 = 440;
+/// The amplitude of the tone captured by a new [`ToneSoundDevice`].
+pub const DEFAULT_TONE_AMPLITUDE: i16 = 16384;
+
+const INPUT_STREAM: u32 = 0;
+
+/// Registers a tone device.
+///
+/// It is only meant for testing, so the kernel registers it only when asked to
+/// run the sound tests.
+pub fn register_tone_device() {
+    crate::register_input_device(
+        TONE_DEVICE_NAME.to_string(),
+        TONE_STABLE_ID.to_string(),
+        Arc::new(ToneSoundDevice::new()),
+    );
+}
+
+/// The shape of a test tone.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Waveform {
+    #[default]
+    Sine,
+    Square,
+}
+
+/// Generates a test tone, one frame after the other.
+///
+/// Each period of a sine wave starts at zero going up, and each period of a
+/// square wave with its positive half, so that a period contains exactly one
+/// rising zero crossing.
+#[derive(Debug, Clone)]
+pub struct ToneGenerator {
+    waveform: Waveform,
+    amplitude: i16,
+    /// The fraction of a period the phase advances by at each frame, in 1/2^32.
+    step: u64,
+    /// The fraction of a period elapsed, in 1/2^32.
+    phase: u64,
+}
+
+impl ToneGenerator {
+    pub fn new(waveform: Waveform, frequency: u32, rate: u32, amplitude: i16) -> Self {
+        Self {
+            waveform,
+            amplitude,
+            step: ((frequency as u64) << 32) / rate.max(1) as u64,
+            phase: 0,
+        }
+    }
+
+    /// Returns the next mono sample of the tone.
+    pub fn next_sample(&mut self) -> i16 {
+        let phase = self.phase & 0xffff_ffff;
+        self.phase = self.phase.wrapping_add(self.step);
+        match self.waveform {
+            Waveform::Sine => {
+                let turns = phase as f32 / (1u64 << 32) as f32;
+                (sine(turns) * self.amplitude as f32) as i16
+            }
+            Waveform::Square if phase < 1 << 31 => self.amplitude,
+            Waveform::Square => -self.amplitude,
+        }
+    }
+
+    /// Fills `samples` with the next mono samples of the tone.
+    pub fn fill(&mut self, samples: &mut [i16]) {
+        for sample in samples.iter_mut() {
+            *sample = self.next_sample();
+        }
+    }
+
+    /// Returns the next `frames` frames of the tone in `format`, with the same
+    /// sample in each of the `channels` channels.
+    ///
+    /// Returns `None` if S16 samples cannot be converted to `format`.
+    pub fn generate(
+        &mut self,
+        format: SampleFormat,
+        channels: u8,
+        frames: usize,
+    ) -> Option<Vec<u8>> {
+        let samples: Vec<u8> = (0..frames)
+            .flat_map(|_| {
+                let sample = self.next_sample().to_le_bytes();
+                (0..channels).flat_map(move |_| sample)
+            })
+            .collect();
+        convert(SampleFormat::S16, format, &samples)
+    }
+}
+
+/// Returns the sine of `turns` full turns, for `turns` in `[0, 1)`.
+fn sine(turns: f32) -> f32 {
+    let mut x = turns * 2.0 * PI;
+    if x > PI {
+        x -= 2.0 * PI;
+    }
+    // Fold into [-pi/2, pi/2], where the Taylor series converges quickly.
+    if x > PI / 2.0 {
+        x = PI - x;
+    } else if x < -PI / 2.0 {
+        x = -PI - x;
+    }
+    let x2 = x * x;
+    x * (1.0 - x2 / 6.0 * (1.0 - x2 / 20.0 * (1.0 - x2 / 42.0)))
+}
+
+#[derive(Debug)]
+struct ToneState {
+    waveform: Waveform,
+    frequency: u32,
+    amplitude: i16,
+    opened: bool,
+    disabled: bool,
+    running: bool,
+    /// Whether the stream was running when the device was suspended.
+    running_before_suspend: bool,
+    params: Option<StreamParams>,
+    /// Generates the tone of the opened stream.
+    generator: Option<ToneGenerator>,
+    /// The bytes of frames read since the stream was opened.
+    transferred_bytes: u64,
+    dma_quota: usize,
+    suspended: bool,
+    /// The length of each pending record request.
+    records: BTreeMap<RecordToken, usize>,
+    next_record_token: u32,
+}
+
+impl ToneState {
+    fn opened_stream(&mut self, stream_id: u32) -> Result<&mut Self, SoundError> {
+        if stream_id != INPUT_STREAM || !self.opened {
+            return Err(SoundError::InvalidParam);
+        }
+        Ok(self)
+    }
+
+    fn enabled_stream(&mut self, stream_id: u32) -> Result<&mut Self, SoundError> {
+        if self.suspended {
+            return Err(SoundError::NotReady);
+        }
+        let state = self.opened_stream(stream_id)?;
+        if state.disabled {
+            return Err(SoundError::NotReady);
+        }
+        Ok(state)
+    }
+
+    fn frame_bytes(&self) -> usize {
+        self.params.map_or(1, |params| {
+            sample_bytes(params.format).unwrap_or(1) * params.channels as usize
+        })
+    }
+
+    /// Records the next frames of the tone into `frames`, returning the number of bytes recorded.
+    ///
+    /// A stopped stream records nothing.
+    fn capture(&mut self, frames: &mut [u8]) -> usize {
+        if !self.running {
+            return 0;
+        }
+        let frame_bytes = self.frame_bytes();
+        let (Some(params), Some(generator)) = (self.params, self.generator.as_mut()) else {
+            return 0;
+        };
+        let tone = generator
+            .generate(params.format, params.channels, frames.len() / frame_bytes)
+            .unwrap_or_default();
+        frames[..tone.len()].copy_from_slice(&tone);
+        self.transferred_bytes += tone.len() as u64;
+        tone.len()
+    }
+}
+
+/// A sound device whose only stream captures a test tone.
+///
+/// The frames are generated as fast as they are read, rather than at the pace
+/// of the stream, so tests do not wait for them. The tone is a sine at
+/// [`DEFAULT_TONE_FREQUENCY`] unless changed with [`ToneSoundDevice::set_tone`].
+#[derive(Debug)]
+pub struct ToneSoundDevice {
+    state: SpinLock<ToneState>,
+}
+
+impl ToneSoundDevice {
+    /// The frame rates accepted by the stream, in Hz.
+    const RATES: [u32; 7] = [8000, 11025, 16000, 22050, 32000, 44100, 48000];
+
+    pub fn new() -> Self {
+        let state = ToneState {
+            waveform: Waveform::default(),
+            frequency: DEFAULT_TONE_FREQUENCY,
+            amplitude: DEFAULT_TONE_AMPLITUDE,
+            opened: false,
+            disabled: false,
+            running: false,
+            running_before_suspend: false,
+            params: None,
+            generator: None,
+            transferred_bytes: 0,
+            dma_quota: usize::MAX,
+            suspended: false,
+            records: BTreeMap::new(),
+            next_record_token: 0,
+        };
+        Self {
+            state: SpinLock::new(state),
+        }
+    }
+
+    /// Sets the tone captured by the stream from the next time it is opened.
+    pub fn set_tone(&self, waveform: Waveform, frequency: u32, amplitude: i16) {
+        let mut state = self.state.lock();
+        state.waveform = waveform;
+        state.frequency = frequency;
+        state.amplitude = amplitude;
+    }
+
+    fn capability() -> StreamCapability {
+        StreamCapability {
+            stream_id: INPUT_STREAM,
+            direction: StreamDirection::Input,
+            formats: vec![SampleFormat::U8, SampleFormat::S16],
+            rates: Self::RATES.to_vec(),
+            channels: 1..=2,
+            channel_maps: vec![],
+            remappable: false,
+            jacks: vec![],
+        }
+    }
+}
+
+impl Default for ToneSoundDevice {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AnySoundDevice for ToneSoundDevice {
+    fn capabilities(&self) -> Result<Vec<StreamCapability>, SoundError> {
+        Ok(vec![Self::capability()])
+    }
+
+    fn set_completion_mode(&self, stream_id: u32, _mode: CompletionMode) -> Result<(), SoundError> {
+        // Reads complete immediately, whatever the mode.
+        if stream_id != INPUT_STREAM {
+            return Err(SoundError::InvalidParam);
+        }
+        Ok(())
+    }
+
+    fn set_completion_priority(&self, _priority: CompletionPriority) {}
+
+    fn set_dma_quota(&self, bytes: usize) {
+        self.state.lock().dma_quota = bytes;
+    }
+
+    fn set_jack_auto_pause(&self, _enabled: bool) {
+        // The device has no jacks.
+    }
+
+    fn latency_histogram(&self, stream_id: u32) -> Option<LatencyHistogram> {
+        // Reads complete immediately, so there is nothing to record.
+        (stream_id == INPUT_STREAM).then(LatencyHistogram::new)
+    }
+
+    fn open_stream(
+        &self,
+        direction: StreamDirection,
+        params: &StreamParams,
+    ) -> Result<u32, SoundError> {
+        if direction != StreamDirection::Input
+            || !Self::capability().supports(params.format, params.rate, params.channels)
+        {
+            return Err(SoundError::InvalidParam);
+        }
+
+        let mut state = self.state.lock();
+        if params.buffer_bytes as usize > state.dma_quota {
+            return Err(SoundError::QuotaExceeded);
+        }
+        if state.opened || state.disabled {
+            return Err(SoundError::InvalidParam);
+        }
+        state.opened = true;
+        state.running = false;
+        state.params = Some(*params);
+        state.generator = Some(ToneGenerator::new(
+            state.waveform,
+            state.frequency,
+            params.rate,
+            state.amplitude,
+        ));
+        state.transferred_bytes = 0;
+        Ok(INPUT_STREAM)
+    }
+
+    fn start_stream(&self, stream_id: u32) -> Result<(), SoundError> {
+        self.state.lock().enabled_stream(stream_id)?.running = true;
+        Ok(())
+    }
+
+    fn stop_stream(&self, stream_id: u32) -> Result<(), SoundError> {
+        self.state.lock().opened_stream(stream_id)?.running = false;
+        Ok(())
+    }
+
+    fn stream_position(&self, stream_id: u32) -> Result<StreamPosition, SoundError> {
+        let mut state = self.state.lock();
+        let state = state.opened_stream(stream_id)?;
+        Ok(StreamPosition {
+            frames: state.transferred_bytes / state.frame_bytes() as Frames,
+            // The device has no clock.
+            timestamp: Duration::ZERO,
+        })
+    }
+
+    fn drain_stream(&self, stream_id: u32) -> Result<(), SoundError> {
+        // Reads complete immediately, so there is nothing to wait for.
+        self.state.lock().opened_stream(stream_id)?.running = false;
+        Ok(())
+    }
+
+    fn close_stream(&self, stream_id: u32) -> Result<(), SoundError> {
+        let mut state = self.state.lock();
+        let state = state.opened_stream(stream_id)?;
+        state.opened = false;
+        state.running = false;
+        state.generator = None;
+        state.records.clear();
+        Ok(())
+    }
+
+    fn disable_stream(&self, stream_id: u32) -> Result<(), SoundError> {
+        if stream_id != INPUT_STREAM {
+            return Err(SoundError::InvalidParam);
+        }
+        let mut state = self.state.lock();
+        state.disabled = true;
+        state.running = false;
+        Ok(())
+    }
+
+    fn enable_stream(&self, stream_id: u32) -> Result<(), SoundError> {
+        if stream_id != INPUT_STREAM {
+            return Err(SoundError::InvalidParam);
+        }
+        self.state.lock().disabled = false;
+        Ok(())
+    }
+
+    fn suspend(&self) -> Result<(), SoundError> {
+        let mut state = self.state.lock();
+        if !state.suspended {
+            state.running_before_suspend = state.running;
+            state.running = false;
+            state.suspended = true;
+        }
+        Ok(())
+    }
+
+    fn resume(&self) -> Result<(), SoundError> {
+        let mut state = self.state.lock();
+        if state.suspended {
+            state.running = state.opened && state.running_before_suspend;
+            state.suspended = false;
+        }
+        Ok(())
+    }
+}
+
+impl AudioInput for ToneSoundDevice {
+    fn register_callback(&self, _callback: Arc<SoundCallback>) -> CallbackHandle {
+        // The callbacks are never invoked, since the device records nothing by itself.
+        CallbackHandle::new(|| {})
+    }
+
+    fn capture_overrun_bytes(&self) -> u64 {
+        // The frames are generated when read, so none can be dropped.
+        0
+    }
+
+    fn read_stream(&self, stream_id: u32, frames: &mut [u8]) -> Result<usize, SoundError> {
+        let mut state = self.state.lock();
+        Ok(state.enabled_stream(stream_id)?.capture(frames))
+    }
+
+    fn record_nb(&self, stream_id: u32, len: usize) -> Result<RecordToken, SoundError> {
+        let mut state = self.state.lock();
+        state.enabled_stream(stream_id)?;
+        let token = RecordToken(state.next_record_token);
+        state.next_record_token = state.next_record_token.wrapping_add(1);
+        state.records.insert(token, len);
+        Ok(token)
+    }
+
+    fn record_poll(
+        &self,
+        token: RecordToken,
+        frames: &mut [u8],
+    ) -> Option<Result<usize, SoundError>> {
+        let mut state = self.state.lock();
+        // The frames are generated as soon as they are collected.
+        let len = state.records.remove(&token)?;
+        let len = len.min(frames.len());
+        Some(Ok(state.capture(&mut frames[..len])))
+    }
+}
+
+#[cfg(ktest)]
+mod test {
+    use ostd::prelude::*;
+
+    use super::*;
+    use crate::{
+        open_input,
+        verify::{analyze_tone, tone_matches},
+    };
+
+    #[ktest]
+    fn square_tone() {
+        let mut generator = ToneGenerator::new(Waveform::Square, 1000, 4000, 100);
+        let mut samples = [0; 6];
+        generator.fill(&mut samples);
+        assert_eq!(samples, [100, 100, -100, -100, 100, 100]);
+    }
+
+    #[ktest]
+    fn capture_sine_tone() {
+        let params = StreamParams {
+            format: SampleFormat::S16,
+            rate: 48000,
+            channels: 1,
+            buffer_bytes: 9600,
+            period_bytes: 960,
+        };
+        let device: Arc<dyn AudioInput> = Arc::new(ToneSoundDevice::new());
+        let mut input = open_input(&device, params).unwrap();
+        input.start().unwrap();
+
+        let mut recorded = vec![0u8; 96000];
+        assert_eq!(input.read(&mut recorded), Ok(96000));
+        let samples: Vec<i16> = recorded
+            .chunks_exact(2)
+            .map(|bytes| i16::from_le_bytes([bytes[0], bytes[1]]))
+            .collect();
+        let analysis = analyze_tone(&samples, params.rate);
+        assert!(tone_matches(
+            &analysis,
+            DEFAULT_TONE_FREQUENCY,
+            DEFAULT_TONE_AMPLITUDE
+        ));
+    }
+}
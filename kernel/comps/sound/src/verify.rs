@@ -0,0 +1,185 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! End-to-end verification of the capture path.
+//!
+//! A tone is played on an output stream of a device whose output is looped
+//! back into its input, then the recorded signal is checked to have the
+//! frequency and amplitude of the tone. This gives a pass/fail signal for the
+//! whole audio stack when running integration tests inside QEMU.
+
+use alloc::{sync::Arc, vec, vec::Vec};
+
+use crate::{
+    open_input, open_output, AudioInput, AudioOutput, SampleFormat, SoundError, StreamParams,
+};
+
+/// The frequency of the tone played by [`verify_capture_path`], in Hz.
+pub const TEST_TONE_FREQUENCY: u32 = 440;
+/// The amplitude of the tone played by [`verify_capture_path`].
+pub const TEST_TONE_AMPLITUDE: i16 = 16384;
+
+const TEST_PARAMS: StreamParams = StreamParams {
+    format: SampleFormat::S16,
+    rate: 48000,
+    channels: 1,
+    buffer_bytes: 9600,
+    period_bytes: 960,
+};
+
+/// The frequency and amplitude measured from a recorded signal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ToneAnalysis {
+    /// The frequency in Hz.
+    pub frequency: u32,
+    /// The largest absolute sample value.
+    pub amplitude: i16,
+}
+
+/// Generates `frames` mono samples of a triangle wave.
+///
+/// The wave starts at its negative peak so that each period contains exactly
+/// one rising zero crossing.
+pub fn triangle_tone(frequency: u32, rate: u32, amplitude: i16, frames: usize) -> Vec<i16> {
+    let step = ((frequency as u64) << 32) / rate as u64;
+    let mut phase = 0u64;
+    (0..frames)
+        .map(|_| {
+            let x = ((phase >> 16) & 0xffff) as i64;
+            let value = if x < 0x8000 {
+                2 * x - 0x8000
+            } else {
+                0x18000 - 2 * x
+            };
+            phase = phase.wrapping_add(step);
+            (value * amplitude as i64 / 0x8000) as i16
+        })
+        .collect()
+}
+
+/// Measures the frequency of a signal from its rising zero crossings, and its amplitude.
+pub fn analyze_tone(samples: &[i16], rate: u32) -> ToneAnalysis {
+    let crossings = samples
+        .windows(2)
+        .filter(|pair| pair[0] < 0 && pair[1] >= 0)
+        .count() as u64;
+    let frequency = if samples.is_empty() {
+        0
+    } else {
+        (crossings * rate as u64 / samples.len() as u64) as u32
+    };
+    let amplitude = samples
+        .iter()
+        .map(|sample| sample.saturating_abs())
+        .max()
+        .unwrap_or(0);
+    ToneAnalysis {
+        frequency,
+        amplitude,
+    }
+}
+
+/// Returns whether the measured tone matches the expected one.
+///
+/// The frequency may be off by 2% and the amplitude by 10%.
+pub fn tone_matches(analysis: &ToneAnalysis, frequency: u32, amplitude: i16) -> bool {
+    let frequency_error = analysis.frequency.abs_diff(frequency);
+    let amplitude_error = analysis.amplitude.abs_diff(amplitude);
+    frequency_error * 50 <= frequency && amplitude_error as u32 * 10 <= amplitude as u32
+}
+
+/// Plays a test tone on `output` and checks the signal recorded from `input`.
+///
+/// The output must be looped back into the input, e.g., both are the same
+/// loopback device. One second of the tone is played; the measured tone is
+/// returned if it matches the played one.
+pub fn verify_capture_path(
+    output: &Arc<dyn AudioOutput>,
+    input: &Arc<dyn AudioInput>,
+) -> Result<ToneAnalysis, SoundError> {
+    let frames = TEST_PARAMS.rate as usize;
+    let tone = triangle_tone(
+        TEST_TONE_FREQUENCY,
+        TEST_PARAMS.rate,
+        TEST_TONE_AMPLITUDE,
+        frames,
+    );
+    let bytes: Vec<u8> = tone
+        .iter()
+        .flat_map(|sample| sample.to_le_bytes())
+        .collect();
+
+    let mut input = open_input(input, TEST_PARAMS)?;
+    let mut output = open_output(output, TEST_PARAMS)?;
+    input.start()?;
+    output.start()?;
+    for period in bytes.chunks(TEST_PARAMS.period_bytes as usize) {
+        output.write(period)?;
+    }
+    output.drain()?;
+
+    let mut recorded = vec![0u8; bytes.len()];
+    let mut len = 0;
+    while len < recorded.len() {
+        let read = input.read(&mut recorded[len..])?;
+        if read == 0 {
+            break;
+        }
+        len += read;
+    }
+    input.stop()?;
+
+    let samples: Vec<i16> = recorded[..len]
+        .chunks_exact(2)
+        .map(|bytes| i16::from_le_bytes([bytes[0], bytes[1]]))
+        .collect();
+    let analysis = analyze_tone(&samples, TEST_PARAMS.rate);
+    if tone_matches(&analysis, TEST_TONE_FREQUENCY, TEST_TONE_AMPLITUDE) {
+        Ok(analysis)
+    } else {
+        Err(SoundError::IoError)
+    }
+}
+
+#[cfg(ktest)]
+mod test {
+    use ostd::prelude::*;
+
+    use super::*;
+    use crate::{fake::FakeSoundDevice, StreamDirection};
+
+    #[ktest]
+    fn analyze_generated_tone() {
+        let tone = triangle_tone(1000, 48000, 10000, 48000);
+        let analysis = analyze_tone(&tone, 48000);
+        assert!(tone_matches(&analysis, 1000, 10000));
+    }
+
+    #[ktest]
+    fn verify_fake_loopback() {
+        let fake = FakeSoundDevice::new(vec![
+            FakeSoundDevice::capability(0, StreamDirection::Output),
+            FakeSoundDevice::capability(1, StreamDirection::Input),
+        ]);
+        fake.set_loopback(true);
+        let fake = Arc::new(fake);
+        let (output, input): (Arc<dyn AudioOutput>, Arc<dyn AudioInput>) = (fake.clone(), fake);
+
+        let analysis = verify_capture_path(&output, &input).unwrap();
+        assert_eq!(analysis.frequency, TEST_TONE_FREQUENCY);
+    }
+
+    #[ktest]
+    fn detect_missing_loopback() {
+        let fake = FakeSoundDevice::new(vec![
+            FakeSoundDevice::capability(0, StreamDirection::Output),
+            FakeSoundDevice::capability(1, StreamDirection::Input),
+        ]);
+        let fake = Arc::new(fake);
+        let (output, input): (Arc<dyn AudioOutput>, Arc<dyn AudioInput>) = (fake.clone(), fake);
+
+        assert_eq!(
+            verify_capture_path(&output, &input),
+            Err(SoundError::IoError)
+        );
+    }
+}
@@ -56,6 +56,26 @@ pub enum VirtioDeviceError {
     /// Invalid parameter.
     InvalidParam,
     DmaError,
+    /// The request would exceed the DMA memory quota of the device.
+    QuotaExceeded,
+    /// The stream has been disabled.
+    StreamDisabled,
+    /// Capture is blocked by the kill-switch.
+    CaptureBlocked,
+    /// The device is suspended.
+    Suspended,
+    /// The stream is not in a state accepting the operation.
+    InvalidState,
+    /// The device found the request malformed or its parameters invalid.
+    BadMessage,
+    /// The device does not support the requested operation or parameters.
+    Unsupported,
+    /// The device did not answer in time.
+    Timeout,
+    /// The stream underran or overran, and must be recovered.
+    Xrun,
+    /// The transfer cannot be queued until the device completes an earlier one.
+    WouldBlock,
 }
 
 impl From<QueueError> for VirtioDeviceError {
@@ -1,10 +1,14 @@
-use core::mem::offset_of;
+use core::{
+    fmt::{self, Display, Formatter},
+    mem::offset_of,
+};
 
 use aster_util::safe_ptr::SafePtr;
 use ostd::Pod;
 
 use crate::transport::{ConfigManager, VirtioTransport};
 bitflags::bitflags! {
+    /// The features of the sound device.
     pub struct SoundFeatures: u64 {
         //Device supports control elements.
         const VIRTIO_SND_F_CTLS = 1 << 0;
@@ -20,6 +24,16 @@ pub struct VirtioSoundConfig {
     pub controls: u32, // (driver-read-only) indicates a total number of all available control elements if VIRTIO_SND_F_CTLS has been negotiated.
 }
 
+impl Display for VirtioSoundConfig {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} streams, {} jacks, {} chmaps, {} controls",
+            self.streams, self.jacks, self.chmaps, self.controls
+        )
+    }
+}
+
 impl VirtioSoundConfig {
     pub(super) fn new_manager(transport: &dyn VirtioTransport) -> ConfigManager<Self> {
         let safe_ptr = transport
@@ -31,7 +45,11 @@ impl VirtioSoundConfig {
 }
 
 impl ConfigManager<VirtioSoundConfig> {
-    pub(super) fn read_config(&self, ctls_negotiated: bool) -> VirtioSoundConfig {
+    /// Reads the configuration of the device.
+    ///
+    /// `controls` is only valid, and thus only read, if `VIRTIO_SND_F_CTLS` has
+    /// been negotiated in `features`; it is zero otherwise.
+    pub(super) fn read_config(&self, features: SoundFeatures) -> VirtioSoundConfig {
         let mut sound_config = VirtioSoundConfig::new_uninit();
         sound_config.jacks = self
             .read_once::<u32>(offset_of!(VirtioSoundConfig, jacks))
@@ -42,7 +60,7 @@ impl ConfigManager<VirtioSoundConfig> {
         sound_config.chmaps = self
             .read_once::<u32>(offset_of!(VirtioSoundConfig, chmaps))
             .unwrap_or(0);
-        if ctls_negotiated {
+        if features.contains(SoundFeatures::VIRTIO_SND_F_CTLS) {
             sound_config.controls = self
                 .read_once::<u32>(offset_of!(VirtioSoundConfig, controls))
                 .unwrap_or(0);
@@ -1,20 +1,43 @@
 use alloc::{
-    boxed::Box, collections::btree_map::BTreeMap, string::ToString, sync::Arc, vec, vec::Vec,
+    boxed::Box,
+    collections::{btree_map::BTreeMap, btree_set::BTreeSet, VecDeque},
+    format,
+    string::{String, ToString},
+    sync::Arc,
+    vec,
+    vec::Vec,
 };
 use core::{
-    array,
+    fmt::Write,
     hint::spin_loop,
-    ops::RangeInclusive,
+    ops::{Range, RangeInclusive},
+    sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+    task::Poll,
+    time::Duration,
 };
 
 // use core::slice;
-use aster_sound::{AnySoundDevice, SoundCallback};
+use aster_sound::{
+    convert::fill_silence,
+    tone::{ToneGenerator, Waveform, DEFAULT_TONE_FREQUENCY},
+    AnySoundDevice, AudioInput, AudioOutput, CallbackHandle, CapabilitiesSnapshot,
+    CaptureBlockMode, CaptureRing, CompletionMode, CompletionPriority, EventCallback, Frames,
+    JackState, LatencyHistogram, PlaybackCallback, RecordToken, SampleFormat, SoundCallback,
+    SoundError, SoundEvent, StreamCapability, StreamDirection, StreamParams, StreamPosition,
+    XrunEvent,
+};
+use aster_time::read_monotonic_time;
 use config::{SoundFeatures, VirtioSoundConfig};
 use log::{debug, error, info, warn};
 use ostd::{
-    early_println,
-    mm::{DmaDirection, DmaStream, DmaStreamSlice, FrameAllocOptions, VmIo, VmReader, VmWriter},
-    sync::{LocalIrqDisabled, RwLock, SpinLock},
+    arch::{read_tsc, tsc_freq},
+    mm::{
+        DmaDirection, DmaStream, DmaStreamSlice, FrameAllocOptions, VmIo, VmReader, VmWriter,
+        PAGE_SIZE,
+    },
+    sync::{LocalIrqDisabled, Mutex, MutexGuard, RwLock, SpinLock, WaitQueue},
+    task::Task,
+    timer,
     trap::TrapFrame,
     Pod,
 };
@@ -23,44 +46,302 @@ use super::{config, *};
 // use crate::queue::QueueError;
 use crate::{
     device::VirtioDeviceError,
+    pm::{register_pm_device, unregister_pm_device, PowerManaged},
     queue::VirtQueue,
-    transport::{ConfigManager, VirtioTransport},
+    self_test::{register_self_test, unregister_self_test},
+    transport::{ConfigManager, DeviceStatus, VirtioTransport},
 };
 
+/// A virtio-sound device.
+///
+/// The control state, the tx state and the rx state are locked independently,
+/// so that a control request, e.g., a capability query or a jack change, is
+/// not blocked by a transfer in progress. When both are needed, the control
+/// lock is taken before the tx lock.
+///
+/// The data paths only take the control lock briefly to look up the parameters
+/// of their stream, so an output stream and an input stream can run at the
+/// same time.
 pub struct SoundDevice {
     sound_inner: Arc<SoundDeviceInner>,
 
-    pcm_infos: Option<Vec<VirtioSndPcmInfo>>,
+    /// The state changed by the requests on the control queue.
+    control: Mutex<ControlState>,
+
+    /// The state of the transfers on the tx queue.
+    ///
+    /// The state of the transfers on the rx queue is kept by [`SoundDeviceInner`].
+    tx: Mutex<TxState>,
+
+    /// The submission-to-completion latencies of the periods of each stream.
+    latency_histograms: SpinLock<Vec<LatencyHistogram>>,
+
+    /// The positions of the streams, indexed by stream ID.
+    stream_clocks: SpinLock<Vec<StreamClock>>,
+
+    /// The output streams fed by pump threads, keyed by stream ID.
+    pumps: SpinLock<BTreeMap<u32, Arc<PumpStream>>>,
+}
+
+impl Debug for SoundDevice {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SoundDevice")
+            .field("sound_inner", &self.sound_inner)
+            .field("control", &self.control)
+            .field("tx", &self.tx)
+            .field("latency_histograms", &self.latency_histograms)
+            .field("stream_clocks", &self.stream_clocks)
+            .field("pumps", &self.pumps)
+            .finish()
+    }
+}
+
+struct ControlState {
+    sound_inner: Arc<SoundDeviceInner>,
 
+    /// The capabilities built from the infos, until they are invalidated.
+    capability_cache: Option<CapabilitiesSnapshot>,
 
-    chmap_infos: Option<Vec<VirtioSndChmapInfo>>,
+    /// The streams opened when the device was suspended, with whether they were running.
+    suspended: Option<BTreeMap<u32, bool>>,
 
     pcm_parameters: Vec<PcmParameters>,
 
     set_up: bool,
 
-    token_rsp: BTreeMap<u16, u16>,
-
     pcm_states: Vec<PCMState>,
 
-    token_buf: BTreeMap<u16, u16>,
+    completion_modes: Vec<CompletionMode>,
+
+    /// The DMA memory, in bytes, reserved by each stream.
+    dma_usage: Vec<usize>,
+
+    /// The hardware buffer of each configured output stream, holding
+    /// `buffer_bytes / period_bytes` periods.
+    frames_buffers: Vec<Option<DmaStream>>,
+
+    /// The upper bound of the DMA memory reserved by all streams.
+    dma_quota: usize,
+
+    /// How long to wait for the device to answer a request on the control queue.
+    request_timeout: Duration,
+
+    /// Whether each stream is claimed by an opened stream handle.
+    stream_opened: Vec<bool>,
+
+    /// Whether each stream has been disabled by the administrator.
+    stream_disabled: Vec<bool>,
+
+    jack_infos: Vec<VirtioSndJackInfo>,
+
+    /// The streams sharing a function group node with each jack.
+    jack_routes: BTreeMap<u32, Vec<u32>>,
+
+    /// Whether output streams are stopped while all their jacks are disconnected.
+    jack_auto_pause: bool,
+
+    /// The output streams stopped because their jacks got disconnected.
+    paused_by_jack: BTreeSet<u32>,
 }
 
-impl Debug for SoundDevice {
+impl Debug for ControlState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.debug_struct("SoundDevice")
-            .field("sound_inner", &self.sound_inner)
-            .field("pcm_infos", &self.pcm_infos)
-            .field("chmap_infos", &self.chmap_infos)
+        f.debug_struct("ControlState")
+            .field("capability_cache", &self.capability_cache)
+            .field("suspended", &self.suspended)
             .field("pcm_parameters", &self.pcm_parameters)
             .field("set_up", &self.set_up)
-            .field("token_rsp", &self.token_rsp)
             .field("pcm_states", &self.pcm_states)
-            .field("token_buf", &self.token_buf)
-            .finish()
+            .field("completion_modes", &self.completion_modes)
+            .field("dma_usage", &self.dma_usage)
+            .field("frames_buffers", &self.frames_buffers)
+            .field("dma_quota", &self.dma_quota)
+            .field("request_timeout", &self.request_timeout)
+            .field("stream_opened", &self.stream_opened)
+            .field("stream_disabled", &self.stream_disabled)
+            .field("jack_infos", &self.jack_infos)
+            .field("jack_routes", &self.jack_routes)
+            .field("jack_auto_pause", &self.jack_auto_pause)
+            .field("paused_by_jack", &self.paused_by_jack)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The control state is dropped along with the device, or when the device fails
+/// to be set up. The streams left active are then stopped and released, and the
+/// device is reset, so that the host does not keep playing or capturing them.
+impl Drop for ControlState {
+    fn drop(&mut self) {
+        // No stream can have been configured on a device that was not set up.
+        if self.set_up {
+            self.release_streams();
+        }
+        self.sound_inner.reset();
+    }
+}
+
+#[derive(Debug)]
+struct TxState {
+    /// The buffers of each pending non-blocking transfer, keyed by its token.
+    token_buf: BTreeMap<u16, XferBuffers>,
+
+    /// The period of its hardware buffer that the next non-blocking transfer of
+    /// each output stream is written to.
+    next_periods: BTreeMap<u32, usize>,
+
+    /// Holds the `virtio_snd_pcm_status` of the blocking transfers, in one slot per
+    /// transfer that a stream may have in flight.
+    status_buffer: DmaStream,
+
+    /// Holds the `virtio_snd_pcm_xfer` header of the blocking transfers, in one
+    /// slot per stream.
+    header_buffer: DmaStream,
+
+    /// The header buffers of the completed non-blocking transfers, which are
+    /// reused by the next ones instead of allocating new buffers.
+    header_pool: Vec<DmaStream>,
+}
+
+/// The buffers a non-blocking transfer owns until the device completes it.
+///
+/// They are kept alive while the transfer is in flight, so that the device
+/// never reads freed memory, and the period is not written to again before the
+/// device is done with it.
+#[derive(Debug)]
+struct XferBuffers {
+    stream_id: u32,
+    /// Holds the `virtio_snd_pcm_xfer` header, followed by the
+    /// `virtio_snd_pcm_status` the device answers the transfer with.
+    header: DmaStream,
+    /// The hardware buffer of the stream.
+    frames: DmaStream,
+    /// The period of `frames` that is transferred.
+    period: Range<usize>,
+    /// The TSC when the transfer was submitted.
+    submit_tsc: u64,
+}
+
+impl XferBuffers {
+    const STATUS_OFFSET: usize = size_of::<VirtioSndPcmXfer>();
+
+    /// Allocates a buffer for the header and the status of a transfer.
+    fn alloc_header() -> Result<DmaStream, VirtioDeviceError> {
+        let segment = FrameAllocOptions::new()
+            .zeroed(false)
+            .alloc_segment(1)
+            .map_err(|_| VirtioDeviceError::DmaError)?;
+        DmaStream::map(segment.into(), DmaDirection::Bidirectional, false)
+            .map_err(|_| VirtioDeviceError::DmaError)
+    }
+
+    fn status_slice(&self) -> DmaStreamSlice<&DmaStream> {
+        DmaStreamSlice::new(
+            &self.header,
+            Self::STATUS_OFFSET,
+            size_of::<VirtioSndPcmStatus>(),
+        )
+    }
+
+    /// Reads the status the device answered the transfer with.
+    fn read_status(&self) -> VirtioSndPcmStatus {
+        let status_range =
+            Self::STATUS_OFFSET..Self::STATUS_OFFSET + size_of::<VirtioSndPcmStatus>();
+        self.header.sync(status_range).unwrap();
+        self.header.read_val(Self::STATUS_OFFSET).unwrap()
+    }
+}
+
+/// An output stream opened by [`SoundDevice::play`], which is closed when dropped
+/// so that it is stopped and released even if playing fails.
+struct PlayingStream<'a> {
+    device: &'a SoundDevice,
+    stream_id: u32,
+}
+
+impl Drop for PlayingStream<'_> {
+    fn drop(&mut self) {
+        if let Err(err) = self.device.close_stream(self.stream_id) {
+            warn!(
+                "[sound device] failed to close stream {} after playing: {:?}",
+                self.stream_id, err
+            );
+        }
+    }
+}
+
+/// The hardware position of a stream, see [`SoundDevice::pcm_hw_position`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PcmHwPosition {
+    /// The frames the device has consumed from the buffer of an output stream, or
+    /// produced into the buffer of an input stream, since the stream was opened.
+    pub frames: Frames,
+    /// The frames the device holds on to besides the buffer, as last reported in
+    /// `latency_bytes`: those not played yet for an output stream, and those not
+    /// handed over yet for an input stream.
+    pub delay: Frames,
+}
+
+/// An output stream opened by [`SoundDevice::open_gapless`], on which buffers are
+/// played back to back.
+///
+/// Each buffer is queued while the previous one is still being played, so there
+/// is no gap between them. The stream is stopped and released when dropped.
+pub struct GaplessPlayback<'a> {
+    stream: PlayingStream<'a>,
+    period_bytes: usize,
+    format: SampleFormat,
+    /// The trailing frames that do not fill a period, played with the next buffer.
+    pending: Vec<u8>,
+}
+
+impl GaplessPlayback<'_> {
+    /// Queue `data` after the buffers played so far, returning once it is queued.
+    ///
+    /// The frames that do not fill a whole period are kept until the next buffer,
+    /// instead of being padded with silence.
+    pub fn play(&mut self, data: &[u8]) -> Result<(), VirtioDeviceError> {
+        self.pending.extend_from_slice(data);
+        let queued = self.pending.len() / self.period_bytes * self.period_bytes;
+        self.stream
+            .device
+            .pcm_xfer_queued(self.stream.stream_id, &self.pending[..queued])?;
+        self.pending.drain(..queued);
+        Ok(())
+    }
+
+    /// Play the frames kept from the last buffer, padded with silence, and return
+    /// once everything queued has been played.
+    pub fn finish(mut self) -> Result<(), VirtioDeviceError> {
+        if !self.pending.is_empty() {
+            let len = self.pending.len();
+            self.pending.resize(self.period_bytes, 0);
+            fill_silence(self.format, &mut self.pending[len..]);
+            self.stream
+                .device
+                .pcm_xfer_queued(self.stream.stream_id, &self.pending)?;
+        }
+        self.stream.device.drain_stream(self.stream.stream_id)
+    }
+
+    /// Get the ID of the stream played on.
+    pub fn stream_id(&self) -> u32 {
+        self.stream.stream_id
     }
 }
 
+/// The callback invoked with the token of each non-blocking transfer the device
+/// has completed.
+///
+/// It is invoked from the deferred work of the tx queue interrupt, so it must not
+/// sleep.
+pub type XferCallback = dyn Fn(u16) + Send + Sync;
+
+/// The name of the self-test of the sound device, suffixed with its index.
+const SELF_TEST_NAME: &str = "sound-tone";
+
+/// The index of the next sound device to be probed.
+static NEXT_DEVICE_INDEX: AtomicUsize = AtomicUsize::new(0);
+
 impl SoundDevice {
     pub fn negotiate_features(features: u64) -> u64 {
         let mut features = SoundFeatures::from_bits_truncate(features);
@@ -69,96 +350,212 @@ impl SoundDevice {
         features.bits()
     }
     const QUEUE_SIZE: u16 = 16;
+    const DEFAULT_DMA_QUOTA: usize = 256 * 1024;
+    const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(1);
     pub fn init(transport: Box<dyn VirtioTransport>) -> Result<(), VirtioDeviceError> {
         // set up sound inner configuration
         let sound_inner = SoundDeviceInner::set(transport).unwrap();
 
         // set parameters 
         let mut pcm_parameters = vec![]; 
-        for _ in 0..sound_inner.config_manager.read_config(false).streams {
+        for _ in 0..sound_inner.read_config().streams {
             pcm_parameters.push(PcmParameters::default());
         }
+        let completion_modes = vec![CompletionMode::default(); pcm_parameters.len()];
+        let dma_usage = vec![0; pcm_parameters.len()];
+        let frames_buffers = vec![None; pcm_parameters.len()];
+        let pcm_parameters_len = pcm_parameters.len();
+        let stream_opened = vec![false; pcm_parameters.len()];
+        let stream_disabled = vec![false; pcm_parameters.len()];
+        let latency_histograms = vec![LatencyHistogram::new(); pcm_parameters.len()];
+        let stream_clocks = vec![StreamClock::default(); pcm_parameters.len()];
 
         // initialize device
-        let mut device = SoundDevice {
-            sound_inner,
-            pcm_infos: None,
-            chmap_infos: None,
+        let mut control = ControlState {
+            sound_inner: sound_inner.clone(),
+            capability_cache: None,
+            suspended: None,
             pcm_parameters,
             set_up: false,
-            token_rsp: BTreeMap::new(),
             pcm_states: vec![],
+            completion_modes,
+            dma_usage,
+            frames_buffers,
+            dma_quota: Self::DEFAULT_DMA_QUOTA,
+            request_timeout: Self::DEFAULT_REQUEST_TIMEOUT,
+            stream_opened,
+            stream_disabled,
+            jack_infos: vec![],
+            jack_routes: BTreeMap::new(),
+            jack_auto_pause: false,
+            paused_by_jack: BTreeSet::new(),
+        };
+        // The infos are queried up front, so that the capabilities can be read
+        // without the control state.
+        control.set_up()?;
+        control.set_up = true;
+        let tx = TxState {
             token_buf: BTreeMap::new(),
+            next_periods: BTreeMap::new(),
+            status_buffer: alloc_status_buffer(pcm_parameters_len)?,
+            header_buffer: alloc_header_buffer(pcm_parameters_len)?,
+            header_pool: Vec::new(),
+        };
+        let device = SoundDevice {
+            sound_inner,
+            control: Mutex::new(control),
+            tx: Mutex::new(tx),
+            latency_histograms: SpinLock::new(latency_histograms),
+            stream_clocks: SpinLock::new(stream_clocks),
+            pumps: SpinLock::new(BTreeMap::new()),
         };
-        // let cloned_device = device;
-        // early_println!("Config is {:?}", soin.config_manager.read_config()); //Config is VirtioSoundConfig { jacks: 0, streams: 2, chmaps: 0, controls: 4294967295 }
-        device.test_device();
-        // device.test_device_input();
 
-        aster_sound::register_device(DEVICE_NAME.to_string(), Arc::new(SpinLock::new(device)));
+        let device = Arc::new(device);
+        {
+            let device = device.clone();
+            register_self_test(&device.self_test_name(), move || device.self_test());
+        }
+        let stable_id = device.sound_inner.stable_id.clone();
+        register_pm_device(&stable_id, device.clone());
+        aster_sound::register_device(device.name(), stable_id, device);
         Ok(())
     }
+}
 
-    fn request<Req: Pod>(&mut self, req: Req) -> Result<VirtioSndHdr, VirtioDeviceError> {
-        // 参数req表示一个request结构体，存放request信息，如VirtIOSndQueryInfo
-        // 这里的Pod trait可以保证可转换为一连串bytes，然后就可以用len的到长度了
-        let req_slice = {
-            let req_slice =
-                DmaStreamSlice::new(&self.sound_inner.send_buffer, 0, req.as_bytes().len());
-            req_slice.write_val(0, &req).unwrap();
-            req_slice.sync().unwrap();
-            req_slice
-        }; // 将req写入snd_req这个DmaStream
-
-        let resp_slice = {
-            let resp_slice =
-                DmaStreamSlice::new(&self.sound_inner.receive_buffer, 0, 20 * SND_HDR_SIZE);
-            resp_slice
-        }; // 希望写入snd_resp这个DmaStream的前面 （目前只预留 返回一个最基础的OK或者ERR 的长度）
-
-        let mut queue = self.sound_inner.control_queue.disable_irq().lock();
-        let token = queue
-            .add_dma_buf(&[&req_slice], &[&resp_slice])
-            .expect("add queue failed");
-        if queue.should_notify() {
-            queue.notify();
+impl ControlState {
+    /// Send a request on the control queue, wait for the device to answer it and
+    /// return the answer.
+    ///
+    /// The status of the answer is decoded, so that a request the device rejects
+    /// is reported as the matching error. An accepted request whose answer is
+    /// shorter than expected fails with [`VirtioDeviceError::IoError`]. If the
+    /// device does not answer in time, the request is given up on with
+    /// [`VirtioDeviceError::Timeout`].
+    fn request<Req: Pod>(&mut self, req: Req) -> Result<Vec<u8>, VirtioDeviceError> {
+        let answer_len = answer_len(req.as_bytes());
+        let token = self.sound_inner.submit_control_request(&req, answer_len)?;
+        let answer = self
+            .sound_inner
+            .wait_control_answer(token, answer_len, self.request_timeout)
+            .inspect_err(|_| {
+                let hdr = VirtioSndHdr::from_bytes(&req.as_bytes()[..SND_HDR_SIZE]);
+                warn!(
+                    "[sound device] request {:#x} timed out after {:?}",
+                    hdr.code.get(),
+                    self.request_timeout
+                );
+                self.sound_inner.note_control_timeout();
+            })?;
+        self.sound_inner
+            .control_timeouts
+            .store(0, Ordering::Relaxed);
+
+        if answer.len() < SND_HDR_SIZE {
+            warn!("[sound device] the answer has no status");
+            return Err(VirtioDeviceError::IoError);
         }
-        while !queue.can_pop() {
-            spin_loop();
+        let resp = VirtioSndHdr::from_bytes(&answer[..SND_HDR_SIZE]);
+        check_status(resp.code.get()).inspect_err(|err| {
+            let hdr = VirtioSndHdr::from_bytes(&req.as_bytes()[..SND_HDR_SIZE]);
+            match CommandCode::try_from(hdr.code.get()) {
+                Ok(code) => debug!("[sound device] request {:?} failed: {:?}", code, err),
+                Err(code) => debug!("[sound device] request {:#x} failed: {:?}", code, err),
+            }
+        })?;
+        if answer.len() < answer_len {
+            warn!(
+                "[sound device] the answer has {} bytes, {} expected",
+                answer.len(),
+                answer_len
+            );
+            return Err(VirtioDeviceError::IoError);
         }
-        queue.pop_used_with_token(token).expect("pop used failed");
+        Ok(answer)
+    }
+
+    fn set_up(&mut self) -> Result<(), VirtioDeviceError> {
+        self.query_infos()?;
 
-        resp_slice.sync().unwrap();
-        let resp: VirtioSndHdr = resp_slice.read_val(0).unwrap();
+        // set the state of new streams to default, keeping the state of the others
+        let streams = self.sound_inner.read_config().streams;
+        self.pcm_states
+            .resize(streams as usize, PCMState::default());
+        Ok(())
+    }
 
-        Ok(resp) //没有考虑报错
+    /// Resize the per-stream state to `streams` streams, the new streams being
+    /// unconfigured.
+    fn resize_streams(&mut self, streams: usize) {
+        self.pcm_parameters
+            .resize(streams, PcmParameters::default());
+        self.pcm_states.truncate(streams);
+        self.completion_modes
+            .resize(streams, CompletionMode::default());
+        self.dma_usage.resize(streams, 0);
+        self.frames_buffers.resize(streams, None);
+        self.stream_opened.resize(streams, false);
+        self.stream_disabled.resize(streams, false);
     }
 
-    fn set_up(&mut self) -> Result<(), VirtioDeviceError> {
+    /// Query the PCM, channel map and jack infos from the device.
+    fn query_infos(&mut self) -> Result<(), VirtioDeviceError> {
         // init pcm info
-        let pcm_infos = self.pcm_info(0, self.sound_inner.config_manager.read_config(false).streams)?;
+        let pcm_infos = self.pcm_info(0, self.sound_inner.read_config().streams)?;
         for pcm_info in &pcm_infos {
             info!("[sound device] pcm_info: {}", pcm_info);
         }
-        self.pcm_infos = Some(pcm_infos);
+        *self.sound_inner.pcm_infos.write() = Some(pcm_infos);
 
         // init chmap info
-        if let Ok(chmap_infos) =
-            self.chmap_info(0, self.sound_inner.config_manager.read_config(false).chmaps)
-        {
+        if let Ok(chmap_infos) = self.chmap_info(0, self.sound_inner.read_config().chmaps) {
             for chmap_info in &chmap_infos {
                 info!("[sound device] chmap_info: {}", chmap_info);
             }
-            self.chmap_infos = Some(chmap_infos);
+            *self.sound_inner.chmap_infos.write() = Some(chmap_infos);
         } else {
-            self.chmap_infos = Some(vec![]);
+            *self.sound_inner.chmap_infos.write() = Some(vec![]);
             warn!("[sound device] Error getting chmap infos");
         }
 
-        // set pcm state to default
-        for _ in 0..self.sound_inner.config_manager.read_config(false).streams {
-            self.pcm_states.push(PCMState::default());
+        // init jack info and the jack -> stream routes
+        let jacks = self.sound_inner.read_config().jacks;
+        if jacks > 0 {
+            match self.jack_info(0, jacks) {
+                Ok(jack_infos) => {
+                    for jack_info in &jack_infos {
+                        info!("[sound device] jack_info: {}", jack_info);
+                    }
+                    self.jack_infos = jack_infos;
+                }
+                Err(_) => warn!("[sound device] Error getting jack infos"),
+            }
         }
+        for (jack_connected, jack_info) in self
+            .sound_inner
+            .jack_connected
+            .read()
+            .iter()
+            .zip(self.jack_infos.iter())
+        {
+            jack_connected.store(jack_info.is_connected(), Ordering::Relaxed);
+        }
+        let pcm_infos = self.sound_inner.pcm_infos.read();
+        self.jack_routes = self
+            .jack_infos
+            .iter()
+            .enumerate()
+            .map(|(jack_id, jack_info)| {
+                let streams = pcm_infos
+                    .as_deref()
+                    .unwrap_or(&[])
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, pcm_info)| pcm_info.hdr == jack_info.hdr)
+                    .map(|(stream_id, _)| stream_id as u32)
+                    .collect();
+                (jack_id as u32, streams)
+            })
+            .collect();
         Ok(())
     }
 
@@ -168,23 +565,19 @@ impl SoundDevice {
         stream_count: u32, // The number of streams that need to be queried
     ) -> Result<Vec<VirtioSndPcmInfo>, VirtioDeviceError> {
         // Check if stream_dart_id+stream_comnt exceeds the number of streams supported by the device. If exceeded, return an error.
-        if stream_start_id + stream_count > self.sound_inner.config_manager.read_config(false).streams {
+        if stream_start_id + stream_count > self.sound_inner.read_config().streams {
             error!("stream_start_id + stream_count > streams! There are not enough streams to be queried!");
             return Err(VirtioDeviceError::IoError);
         }
 
         // Construct a request header
         let request_hdr = VirtioSndHdr::from(ItemInformationRequestType::RPcmInfo);
-        let hdr = self.request(VirtioSndQueryInfo {
+        let answer = self.request(VirtioSndQueryInfo {
             hdr: request_hdr,
-            start_id: stream_start_id,
-            count: stream_count,
-            size: size_of::<VirtioSndPcmInfo>() as u32,
+            start_id: stream_start_id.into(),
+            count: stream_count.into(),
+            size: (size_of::<VirtioSndPcmInfo>() as u32).into(),
         })?; // call self.request to send the request and get the response
-        if hdr != RequestStatusCode::Ok.into() {
-            // if failed(not OK) then Error
-            return Err(VirtioDeviceError::IoError);
-        }
         // read struct VirtIOSndPcmInfo
         let mut pcm_infos = vec![];
 
@@ -193,14 +586,10 @@ impl SoundDevice {
             const PCM_INFO_SIZE: usize = size_of::<VirtioSndPcmInfo>();
             let start_byte_idx = HDR_SIZE + i * PCM_INFO_SIZE; //
             let end_byte_idx = HDR_SIZE + (i + 1) * PCM_INFO_SIZE;
-            if end_byte_idx > self.sound_inner.receive_buffer.nbytes() {
+            if end_byte_idx > answer.len() {
                 return Err(VirtioDeviceError::BufferOverflow);
             }
-            let reader = self.sound_inner.receive_buffer.reader().unwrap();
-            let mut reader = reader.skip(start_byte_idx).limit(PCM_INFO_SIZE);
-            let mut buffer = [0u8; size_of::<VirtioSndPcmInfo>()];
-            reader.read(&mut buffer.as_mut_slice().into()); // 读取数据到缓冲区
-            let pcm_info = VirtioSndPcmInfo::from_bytes(&buffer); // 解析数据
+            let pcm_info = VirtioSndPcmInfo::from_bytes(&answer[start_byte_idx..end_byte_idx]); // 解析数据
             pcm_infos.push(pcm_info);
         }
 
@@ -218,96 +607,130 @@ impl SoundDevice {
         Ok(pcm_infos)
     }
 
+    /// Query information about the available jacks.
+    fn jack_info(
+        &mut self,
+        jack_start_id: u32,
+        jack_count: u32,
+    ) -> Result<Vec<VirtioSndJackInfo>, VirtioDeviceError> {
+        if jack_start_id + jack_count > self.sound_inner.read_config().jacks {
+            error!("jack_start_id + jack_count > jacks! There are not enough jacks to be queried!");
+            return Err(VirtioDeviceError::IoError);
+        }
+
+        let answer = self.request(VirtioSndQueryInfo {
+            hdr: ItemInformationRequestType::RJackInfo.into(),
+            start_id: jack_start_id.into(),
+            count: jack_count.into(),
+            size: (size_of::<VirtioSndJackInfo>() as u32).into(),
+        })?;
+        let mut jack_infos = vec![];
+        for i in 0..jack_count as usize {
+            const HDR_SIZE: usize = size_of::<VirtioSndHdr>();
+            const JACK_INFO_SIZE: usize = size_of::<VirtioSndJackInfo>();
+            let start_byte_idx = HDR_SIZE + i * JACK_INFO_SIZE;
+            let end_byte_idx = HDR_SIZE + (i + 1) * JACK_INFO_SIZE;
+            if end_byte_idx > answer.len() {
+                return Err(VirtioDeviceError::BufferOverflow);
+            }
+            let jack_info = VirtioSndJackInfo::from_bytes(&answer[start_byte_idx..end_byte_idx]);
+            jack_infos.push(jack_info);
+        }
+        Ok(jack_infos)
+    }
+
     /// Query information about the available chmaps.
     fn chmap_info(
         &mut self,
         chmaps_start_id: u32,
         chmaps_count: u32,
     ) -> Result<Vec<VirtioSndChmapInfo>, VirtioDeviceError> {
-        //
-        if chmaps_start_id + chmaps_count > self.sound_inner.config_manager.read_config(false).streams {
-            error!("chmaps_start_id + chmaps_count > self.chmaps");
+        if chmaps_start_id + chmaps_count > self.sound_inner.read_config().chmaps {
+            error!("chmaps_start_id + chmaps_count > chmaps! There are not enough chmaps to be queried!");
             return Err(VirtioDeviceError::IoError);
         }
 
-        // Construct a request header
-        let hdr = self.request(VirtioSndQueryInfo {
+        let answer = self.request(VirtioSndQueryInfo {
             hdr: ItemInformationRequestType::RChmapInfo.into(),
-            start_id: chmaps_start_id,
-            count: chmaps_count,
-            size: size_of::<VirtioSndQueryInfo>() as u32,
+            start_id: chmaps_start_id.into(),
+            count: chmaps_count.into(),
+            size: (size_of::<VirtioSndChmapInfo>() as u32).into(),
         })?;
-        if hdr != RequestStatusCode::Ok.into() {
-            return Err(VirtioDeviceError::IoError);
-        }
         let mut chmap_infos = vec![];
         for i in 0..chmaps_count as usize {
-            const OFFSET: usize = size_of::<VirtioSndHdr>();
-            const CHAMP_INFO_SIZE: usize = size_of::<VirtioSndQueryInfo>();
-            let start_byte = OFFSET + i * CHAMP_INFO_SIZE;
-            let end_byte = OFFSET + (i + 1) * CHAMP_INFO_SIZE;
-            if end_byte > self.sound_inner.receive_buffer.nbytes() {
+            const HDR_SIZE: usize = size_of::<VirtioSndHdr>();
+            const CHMAP_INFO_SIZE: usize = size_of::<VirtioSndChmapInfo>();
+            let start_byte_idx = HDR_SIZE + i * CHMAP_INFO_SIZE;
+            let end_byte_idx = HDR_SIZE + (i + 1) * CHMAP_INFO_SIZE;
+            if end_byte_idx > answer.len() {
                 return Err(VirtioDeviceError::BufferOverflow);
             }
-            let reader = self.sound_inner.receive_buffer.reader().unwrap();
-            let mut reader = reader.skip(start_byte).limit(CHAMP_INFO_SIZE);
-            // let chmap_info =
-            //     VirtioSndChmapInfo::read_from_bytes(&self.queue_buf_recv[start_byte..end_byte])
-            //         .unwrap();
-            let mut buffer = [0u8; size_of::<VirtioSndPcmInfo>()];
-            reader.read(&mut buffer.as_mut_slice().into()); // 读取数据到缓冲区
-            let chmap_info = VirtioSndChmapInfo::from_bytes(&buffer); // 解析数据
+            let chmap_info = VirtioSndChmapInfo::from_bytes(&answer[start_byte_idx..end_byte_idx]);
             chmap_infos.push(chmap_info);
         }
         Ok(chmap_infos)
     }
 
+    /// Set the parameters of a stream with specified stream ID.
+    ///
+    /// The parameters are validated against the capabilities of the stream by
+    /// [`PcmParamsBuilder::build`].
     pub fn pcm_set_params(
         &mut self,
         stream_id: u32,
-        buffer_bytes: u32,
-        period_bytes: u32,
-        features: PcmFeatures,
-        channels: u8,
-        format: PcmFormat,
-        rate: PcmRate,
+        params: PcmParamsBuilder,
     ) -> Result<(), VirtioDeviceError> {
         if !self.set_up {
             self.set_up()?;
             self.set_up = true;
         }
-        if period_bytes == 0 || period_bytes > buffer_bytes || buffer_bytes % period_bytes != 0 {
-            return Err(VirtioDeviceError::InvalidParam);
+        self.check_transition(stream_id, PCMState::SetParameters)?;
+        let pcm_info = self.sound_inner.pcm_info(stream_id)?;
+        let request = params.build(stream_id, &pcm_info)?;
+        let buffer_bytes = params.buffer_bytes;
+        // The hardware buffer is backed by whole pages of DMA memory.
+        let buffer_usage = (buffer_bytes as usize).div_ceil(PAGE_SIZE) * PAGE_SIZE;
+        let other_usage: usize = self
+            .dma_usage
+            .iter()
+            .enumerate()
+            .filter(|(id, _)| *id != stream_id as usize)
+            .map(|(_, usage)| usage)
+            .sum();
+        if other_usage + buffer_usage > self.dma_quota {
+            warn!(
+                "[sound device] stream {} requests {} bytes of DMA memory, exceeding the quota",
+                stream_id, buffer_usage
+            );
+            return Err(VirtioDeviceError::QuotaExceeded);
         }
-        let request_hdr = VirtioSndHdr::from(CommandCode::RPcmSetParams);
-        let rsp = self.request(VirtioSndPcmSetParams {
-            hdr: VirtioSndPcmHdr {
-                hdr: request_hdr,
-                stream_id,
-            },
-            buffer_bytes,
-            period_bytes,
-            features: features.bits(),
-            channels,
-            format: format.into(),
-            rate: rate.into(),
-            padding: 0,
-        })?;
-        // rsp is just a header, so it can be compared with VirtIOSndHdr
-        if rsp == VirtioSndHdr::from(RequestStatusCode::Ok) {
-            self.pcm_parameters[stream_id as usize] = PcmParameters {
-                setup: true,
-                buffer_bytes,
-                period_bytes,
-                features,
-                channels,
-                format,
-                rate,
-            };
-            Ok(())
+        // The periods of an output stream are copied into its hardware buffer.
+        let frames_buffer = if self.is_input_stream(stream_id) {
+            None
         } else {
-            Err(VirtioDeviceError::IoError)
+            Some(alloc_frames_buffer(buffer_bytes as usize)?)
+        };
+        let mut features = params.features;
+        let supported_features = pcm_info.features();
+        // Ask the device to report xruns, so that they reach the event callbacks.
+        if supported_features.contains(PcmFeatures::EVT_XRUNS) {
+            features.insert(PcmFeatures::EVT_XRUNS);
         }
+        // Let the device know that a polling stream will not rely on interrupts.
+        if self.completion_modes[stream_id as usize] == CompletionMode::Polling
+            && supported_features.contains(PcmFeatures::MSG_POLLING)
+        {
+            features.insert(PcmFeatures::MSG_POLLING);
+        }
+        self.request(VirtioSndPcmSetParams {
+            features: features.bits().into(),
+            ..request
+        })?;
+        self.pcm_parameters[stream_id as usize] = PcmParameters::from(&params.features(features));
+        self.dma_usage[stream_id as usize] = buffer_usage;
+        self.frames_buffers[stream_id as usize] = frames_buffer;
+        self.pcm_states[stream_id as usize] = PCMState::SetParameters;
+        Ok(())
     }
 
     /// Prepare a stream with specified stream ID.
@@ -316,17 +739,14 @@ impl SoundDevice {
             self.set_up()?;
             self.set_up = true;
         }
+        self.check_transition(stream_id, PCMState::Prepare)?;
         let request_hdr = VirtioSndHdr::from(CommandCode::RPcmPrepare);
-        let rsp = self.request(VirtioSndPcmHdr {
+        self.request(VirtioSndPcmHdr {
             hdr: request_hdr,
-            stream_id,
+            stream_id: stream_id.into(),
         })?;
-        // rsp is just a header, so it can be compared with VirtIOSndHdr
-        if rsp == VirtioSndHdr::from(RequestStatusCode::Ok) {
-            Ok(())
-        } else {
-            Err(VirtioDeviceError::IoError)
-        }
+        self.pcm_states[stream_id as usize] = PCMState::Prepare;
+        Ok(())
     }
 
     /// Release a stream with specified stream ID.
@@ -335,17 +755,20 @@ impl SoundDevice {
             self.set_up()?;
             self.set_up = true;
         }
+        self.check_transition(stream_id, PCMState::Release)?;
         let request_hdr = VirtioSndHdr::from(CommandCode::RPcmRelease);
-        let rsp = self.request(VirtioSndPcmHdr {
+        self.request(VirtioSndPcmHdr {
             hdr: request_hdr,
-            stream_id,
+            stream_id: stream_id.into(),
         })?;
-        // rsp is just a header, so it can be compared with VirtIOSndHdr
-        if rsp == VirtioSndHdr::from(RequestStatusCode::Ok) {
-            Ok(())
-        } else {
-            Err(VirtioDeviceError::IoError)
-        }
+        self.dma_usage[stream_id as usize] = 0;
+        self.pcm_states[stream_id as usize] = PCMState::Release;
+        self.sound_inner.paused.lock().remove(&stream_id);
+        self.sound_inner.remove_silence_fill(stream_id);
+        // The device has returned every period of the stream by now.
+        self.sound_inner.reclaim_retired_pull_streams();
+        self.scrub_buffers(stream_id);
+        Ok(())
     }
 
     /// Start a stream with specified stream ID.
@@ -354,17 +777,49 @@ impl SoundDevice {
             self.set_up()?;
             self.set_up = true;
         }
+        self.check_transition(stream_id, PCMState::Start)?;
+        if self.is_input_stream(stream_id) {
+            // The device fills the periods submitted before the stream is started.
+            let period_bytes = self.pcm_parameters[stream_id as usize].period_bytes as usize;
+            self.sound_inner
+                .add_capture_stream(stream_id, period_bytes)?;
+        }
         let request_hdr = VirtioSndHdr::from(CommandCode::RPcmStart);
-        let rsp = self.request(VirtioSndPcmHdr {
+        self.request(VirtioSndPcmHdr {
             hdr: request_hdr,
-            stream_id,
+            stream_id: stream_id.into(),
         })?;
-        // rsp is just a header, so it can be compared with VirtIOSndHdr
-        if rsp == VirtioSndHdr::from(RequestStatusCode::Ok) {
-            Ok(())
-        } else {
-            Err(VirtioDeviceError::IoError)
+        if self.is_input_stream(stream_id) {
+            aster_sound::capture_started();
+        }
+        self.pcm_states[stream_id as usize] = PCMState::Start;
+        if self.sound_inner.paused.lock().remove(&stream_id) {
+            // Let the device know of the periods queued while the stream was paused.
+            self.sound_inner.tx_queue.disable_irq().lock().notify();
+        }
+        Ok(())
+    }
+
+    /// Pause a running stream.
+    ///
+    /// The stream is stopped on the device, but keeps its parameters and buffers.
+    /// Periods written to a paused output stream are queued without the device
+    /// being notified, and played once the stream is resumed.
+    pub fn pcm_pause(&mut self, stream_id: u32) -> Result<(), VirtioDeviceError> {
+        if self.pcm_states.get(stream_id as usize) != Some(&PCMState::Start) {
+            return Err(VirtioDeviceError::InvalidState);
+        }
+        self.pcm_stop(stream_id)?;
+        self.sound_inner.paused.lock().insert(stream_id);
+        Ok(())
+    }
+
+    /// Resume a stream paused by [`Self::pcm_pause`].
+    pub fn pcm_resume(&mut self, stream_id: u32) -> Result<(), VirtioDeviceError> {
+        if !self.sound_inner.is_paused(stream_id) {
+            return Err(VirtioDeviceError::InvalidState);
         }
+        self.pcm_start(stream_id)
     }
 
     /// Stop a stream with specified stream ID.
@@ -373,705 +828,3879 @@ impl SoundDevice {
             self.set_up()?;
             self.set_up = true;
         }
+        self.check_transition(stream_id, PCMState::Stop)?;
         let request_hdr = VirtioSndHdr::from(CommandCode::RPcmStop);
-        let rsp = self.request(VirtioSndPcmHdr {
+        self.request(VirtioSndPcmHdr {
             hdr: request_hdr,
-            stream_id,
+            stream_id: stream_id.into(),
         })?;
-        // rsp is just a header, so it can be compared with VirtIOSndHdr
-        if rsp == VirtioSndHdr::from(RequestStatusCode::Ok) {
-            Ok(())
+        if self.is_input_stream(stream_id) {
+            aster_sound::capture_stopped();
         } else {
-            Err(VirtioDeviceError::IoError)
+            self.sound_inner.stop_silence_fill(stream_id);
         }
+        self.pcm_states[stream_id as usize] = PCMState::Stop;
+        Ok(())
     }
 
-    /// Get all output streams.
-    pub fn output_streams(&mut self) -> Result<Vec<u32>, VirtioDeviceError> {
-        if !self.set_up {
-            self.set_up()?;
-            self.set_up = true;
+    /// Stop and release every stream left active on the device, e.g., when the
+    /// device is torn down, and close them.
+    fn release_streams(&mut self) {
+        for stream_id in 0..self.pcm_states.len() as u32 {
+            if self.pcm_states[stream_id as usize] == PCMState::Start
+                && self.pcm_stop(stream_id).is_err()
+            {
+                warn!("[sound device] failed to stop stream {}", stream_id);
+            }
+            if matches!(
+                self.pcm_states[stream_id as usize],
+                PCMState::Prepare | PCMState::Stop
+            ) && self.pcm_release(stream_id).is_err()
+            {
+                warn!("[sound device] failed to release stream {}", stream_id);
+            }
+            self.stream_opened[stream_id as usize] = false;
         }
-        Ok(self
-            .pcm_infos
-            .as_ref()
-            .unwrap()
-            .iter()
-            .enumerate()
-            .filter(|(_, info)| info.direction == VIRTIO_SND_D_OUTPUT)
-            .map(|(idx, _)| idx as u32)
-            .collect())
+        // The buffers of the streams that failed to be released are freed as well.
+        self.frames_buffers.fill(None);
+        self.dma_usage.fill(0);
     }
 
-    /// Get all input streams.
-    pub fn input_streams(&mut self) -> Result<Vec<u32>, VirtioDeviceError> {
-        if !self.set_up {
-            self.set_up()?;
-            self.set_up = true;
+    /// Check that a stream may move from its current state to `next`.
+    fn check_transition(&self, stream_id: u32, next: PCMState) -> Result<(), VirtioDeviceError> {
+        self.check_stream_id(stream_id)?;
+        let state = self.pcm_states[stream_id as usize];
+        if state.can_transition_to(next) {
+            Ok(())
+        } else {
+            warn!(
+                "[sound device] stream {} cannot go from {:?} to {:?}",
+                stream_id, state, next
+            );
+            Err(VirtioDeviceError::InvalidState)
         }
-        Ok(self
-            .pcm_infos
-            .as_ref()
-            .unwrap()
-            .iter()
-            .enumerate()
-            .filter(|(_, info)| info.direction == VIRTIO_SND_D_INPUT)
-            .map(|(idx, _)| idx as u32)
-            .collect())
     }
 
-    /// Get the rates that a stream supports.
-    pub fn rates_supported(&mut self, stream_id: u32) -> Result<PcmRates, VirtioDeviceError> {
-        if !self.set_up {
-            self.set_up()?;
-            self.set_up = true;
-        }
-        if stream_id >= self.pcm_infos.as_ref().unwrap().len() as u32 {
-            return Err(VirtioDeviceError::InvalidParam);
+    /// Check that a stream ID names a stream of the device, before the per-stream
+    /// state is indexed with it.
+    ///
+    /// The streams are only known once the device has been set up and their infos
+    /// have been queried.
+    fn check_stream_id(&self, stream_id: u32) -> Result<(), VirtioDeviceError> {
+        let index = stream_id as usize;
+        if index < self.pcm_parameters.len()
+            && index < self.pcm_states.len()
+            && self.sound_inner.pcm_info(stream_id).is_ok()
+        {
+            Ok(())
+        } else {
+            warn!("[sound device] no stream {}", stream_id);
+            Err(VirtioDeviceError::InvalidParam)
         }
-        Ok(
-            PcmRates::from_bits(self.pcm_infos.as_ref().unwrap()[stream_id as usize].rates)
-                .unwrap(),
-        )
     }
 
-    /// Get the formats that a stream supports.
-    pub fn formats_supported(&mut self, stream_id: u32) -> Result<PcmFormats, VirtioDeviceError> {
-        debug!("formats_supported debug");
-        if !self.set_up {
-            self.set_up()?;
-            self.set_up = true;
-        }
-        if stream_id >= self.pcm_infos.as_ref().unwrap().len() as u32 {
-            return Err(VirtioDeviceError::InvalidParam);
+    /// Check that a stream has been opened with [`Self::open_stream`].
+    fn check_opened(&self, stream_id: u32) -> Result<(), VirtioDeviceError> {
+        if self
+            .stream_opened
+            .get(stream_id as usize)
+            .is_some_and(|opened| *opened)
+        {
+            Ok(())
+        } else {
+            Err(VirtioDeviceError::InvalidParam)
         }
-        debug!("formats_supported pass");
-        Ok(
-            PcmFormats::from_bits(self.pcm_infos.as_ref().unwrap()[stream_id as usize].formats)
-                .unwrap(),
-        )
     }
 
-    /// Get channel range that a stream supports.
-    pub fn channel_range_supported(
-        &mut self,
-        stream_id: u32,
-    ) -> Result<RangeInclusive<u8>, VirtioDeviceError> {
-        debug!("channel_range_supported debug");
-        if !self.set_up {
-            self.set_up()?;
-            self.set_up = true;
+    /// Check that frames can be transferred on a stream.
+    fn check_transfer(&self, stream_id: u32) -> Result<(), VirtioDeviceError> {
+        if self.sound_inner.xruns.lock().contains(&stream_id) {
+            return Err(VirtioDeviceError::Xrun);
         }
-        if stream_id >= self.pcm_infos.as_ref().unwrap().len() as u32 {
-            return Err(VirtioDeviceError::InvalidParam);
+        let state = *self
+            .pcm_states
+            .get(stream_id as usize)
+            .ok_or(VirtioDeviceError::InvalidParam)?;
+        // Periods are queued on a paused stream, to be played once it is resumed.
+        if state.can_transfer() || self.sound_inner.is_paused(stream_id) {
+            Ok(())
+        } else {
+            warn!(
+                "[sound device] stream {} cannot transfer frames in {:?}",
+                stream_id, state
+            );
+            Err(VirtioDeviceError::InvalidState)
         }
-        let pcm_info = &self.pcm_infos.as_ref().unwrap()[stream_id as usize];
-        debug!("channel_range_supported pass");
-        Ok(pcm_info.channels_min..=pcm_info.channels_max)
     }
 
-    pub fn features_supported(&mut self, stream_id: u32) -> Result<PcmFeatures, VirtioDeviceError> {
-        debug!("features_supported debug");
-        if !self.set_up {
-            self.set_up()?;
-            self.set_up = true;
-        }
-        if stream_id >= self.pcm_infos.as_ref().unwrap().len() as u32 {
-            return Err(VirtioDeviceError::InvalidParam);
+    /// Drop the buffers of a released stream, zeroing them first, so that its
+    /// residual audio cannot leak to the next user of the memory.
+    ///
+    /// The device has completed every period of the stream when releasing it.
+    fn scrub_buffers(&mut self, stream_id: u32) {
+        let sound_inner = &self.sound_inner;
+        if self.is_input_stream(stream_id) {
+            sound_inner.remove_capture_stream(stream_id);
+        } else if let Some(frames_buffer) = self.frames_buffers[stream_id as usize].take() {
+            frames_buffer.writer().unwrap().fill(0u8);
+            frames_buffer.sync(0..frames_buffer.nbytes()).unwrap();
         }
-        let pcm_info = &self.pcm_infos.as_ref().unwrap()[stream_id as usize];
-        debug!("features_supported pass");
-        Ok(PcmFeatures::from_bits(pcm_info.features).unwrap())
     }
 
-    /// Transfer PCM frame to device, based on the stream type(OUTPUT/INPUT).
-    ///
-    /// Currently supports only output stream.
+    /// Get the hardware buffer of a configured output stream, checking that it
+    /// holds every period set by [`Self::pcm_set_params`].
     ///
-    /// This is a blocking method that will not return until the audio playback is complete.
-    pub fn pcm_xfer(&mut self, stream_id: u32, frames: &[u8]) -> Result<(), VirtioDeviceError> {
-        const U32_SIZE: usize = size_of::<u32>();
-        if !self.set_up {
-            self.set_up()?;
-            self.set_up = true;
+    /// The frames of a period are copied into, and handed to the device from,
+    /// a single range of the buffer, so a period that would not fit is rejected
+    /// rather than truncated.
+    fn frames_buffer(&self, stream_id: u32) -> Result<DmaStream, VirtioDeviceError> {
+        let frames_buffer = self.frames_buffers[stream_id as usize]
+            .clone()
+            .ok_or(VirtioDeviceError::InvalidParam)?;
+        let buffer_bytes = self.pcm_parameters[stream_id as usize].buffer_bytes as usize;
+        if frames_buffer.nbytes() < buffer_bytes {
+            warn!(
+                "[sound device] the hardware buffer of stream {} holds {} bytes, less than its {} bytes",
+                stream_id,
+                frames_buffer.nbytes(),
+                buffer_bytes
+            );
+            return Err(VirtioDeviceError::BufferOverflow);
         }
-        if !self.pcm_parameters[stream_id as usize].setup {
-            warn!("Please set parameters for a stream before using it!");
-            return Err(VirtioDeviceError::IoError);
+        Ok(frames_buffer)
+    }
+
+    /// Get how the completions on the tx queue are noticed.
+    ///
+    /// The tx queue raises interrupts as long as one output stream is interrupt-driven.
+    fn tx_completion_mode(&self) -> CompletionMode {
+        let pcm_infos = self.sound_inner.pcm_infos.read();
+        let Some(pcm_infos) = pcm_infos.as_ref() else {
+            return CompletionMode::default();
+        };
+        let interrupt_driven =
+            pcm_infos
+                .iter()
+                .zip(self.completion_modes.iter())
+                .any(|(info, mode)| {
+                    info.direction() == StreamDirection::Output
+                        && *mode == CompletionMode::Interrupt
+                });
+        if interrupt_driven {
+            CompletionMode::Interrupt
+        } else {
+            CompletionMode::Polling
         }
-        let stream_id_bytes = stream_id.to_le_bytes();
-        let period_size = self.pcm_parameters[stream_id as usize].period_bytes as usize;
+    }
 
-        // 将 frames 字节数组按照 period_size 分割成多个小块
-        let mut remaining_buffers = frames.chunks(period_size);
-        // 初始化一个 Option 类型的缓冲区数组，存储当前可用的缓冲区
+    fn is_input_stream(&self, stream_id: u32) -> bool {
+        self.sound_inner
+            .pcm_info(stream_id)
+            .is_ok_and(|pcm_info| pcm_info.direction() == StreamDirection::Input)
+    }
+
+    /// Get the DMA memory, in bytes, reserved by a stream.
+    pub fn dma_usage(&self, stream_id: u32) -> usize {
+        self.dma_usage.get(stream_id as usize).copied().unwrap_or(0)
+    }
+
+    /// Set the upper bound of the DMA memory reserved by all streams.
+    ///
+    /// Streams that are already configured keep their reservation.
+    pub fn set_dma_quota(&mut self, bytes: usize) {
+        self.dma_quota = bytes;
+    }
+
+    /// Set how long to wait for the device to answer a request on the control queue.
+    pub fn set_request_timeout(&mut self, timeout: Duration) {
+        self.request_timeout = timeout;
+    }
+
+    /// Get whether each jack is connected.
+    ///
+    /// The states are queried once from the device, then kept up to date by the
+    /// jack events.
+    pub fn jack_states(&mut self) -> Result<Vec<JackState>, VirtioDeviceError> {
+        if !self.set_up {
+            self.set_up()?;
+            self.set_up = true;
+        }
+        Ok(self
+            .sound_inner
+            .jack_connected
+            .read()
+            .iter()
+            .take(self.jack_infos.len())
+            .enumerate()
+            .map(|(jack_id, connected)| JackState {
+                jack_id: jack_id as u32,
+                connected: connected.load(Ordering::Relaxed),
+            })
+            .collect())
+    }
+
+    /// Set whether output streams are stopped while all their jacks are disconnected.
+    pub fn set_jack_auto_pause(&mut self, enabled: bool) {
+        self.jack_auto_pause = enabled;
+    }
+
+    /// Record a jack being connected or disconnected.
+    ///
+    /// With auto-pause enabled, running output streams routed only to disconnected
+    /// jacks are stopped, and started again once one of their jacks is reconnected.
+    pub fn handle_jack_change(
+        &mut self,
+        jack_id: u32,
+        connected: bool,
+    ) -> Result<(), VirtioDeviceError> {
+        let Some(jack_info) = self.jack_infos.get_mut(jack_id as usize) else {
+            return Err(VirtioDeviceError::InvalidParam);
+        };
+        jack_info.connected = connected as u8;
+        self.sound_inner.jack_connected.read()[jack_id as usize]
+            .store(connected, Ordering::Relaxed);
+        if !self.jack_auto_pause {
+            return Ok(());
+        }
+
+        let streams = self.jack_routes.get(&jack_id).cloned().unwrap_or_default();
+        for stream_id in streams {
+            if self.is_input_stream(stream_id) {
+                continue;
+            }
+            let any_connected = self.jack_routes.iter().any(|(jack_id, streams)| {
+                streams.contains(&stream_id) && self.jack_infos[*jack_id as usize].is_connected()
+            });
+            if !any_connected && self.pcm_states[stream_id as usize] == PCMState::Start {
+                self.pcm_stop(stream_id)?;
+                self.paused_by_jack.insert(stream_id);
+            } else if any_connected && self.paused_by_jack.remove(&stream_id) {
+                self.pcm_start(stream_id)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Switch between interrupt-driven and polled completion for a stream.
+    ///
+    /// Streams of the same direction share a virtqueue, so the queue interrupts
+    /// stay enabled as long as one stream of that direction is interrupt-driven.
+    pub fn set_completion_mode(
+        &mut self,
+        stream_id: u32,
+        mode: CompletionMode,
+    ) -> Result<(), VirtioDeviceError> {
+        if !self.set_up {
+            self.set_up()?;
+            self.set_up = true;
+        }
+        self.check_stream_id(stream_id)?;
+        self.completion_modes[stream_id as usize] = mode;
+
+        let pcm_infos = self.sound_inner.pcm_infos.read();
+        let pcm_infos = pcm_infos.as_deref().unwrap_or(&[]);
+        let direction = pcm_infos
+            .get(stream_id as usize)
+            .ok_or(VirtioDeviceError::InvalidParam)?
+            .direction();
+        let interrupt_driven =
+            pcm_infos
+                .iter()
+                .zip(self.completion_modes.iter())
+                .any(|(info, mode)| {
+                    info.direction() == direction && *mode == CompletionMode::Interrupt
+                });
+        let queue = if direction == StreamDirection::Input {
+            &self.sound_inner.rx_queue
+        } else {
+            &self.sound_inner.tx_queue
+        };
+        let mut queue = queue.disable_irq().lock();
+        if interrupt_driven {
+            queue.enable_callback();
+        } else {
+            queue.disable_callback();
+        }
+        Ok(())
+    }
+
+    /// Get the capabilities of all streams, as reported by the PCM and channel map infos.
+    pub fn capabilities(&mut self) -> Result<Vec<StreamCapability>, VirtioDeviceError> {
+        Ok(self.capabilities_snapshot()?.streams().to_vec())
+    }
+
+    /// Get the capabilities of all streams in one snapshot.
+    ///
+    /// The snapshot is built once and cached, with one entry per stream, until
+    /// [`Self::invalidate_capabilities`] is called.
+    pub fn capabilities_snapshot(&mut self) -> Result<CapabilitiesSnapshot, VirtioDeviceError> {
+        if !self.set_up {
+            self.set_up()?;
+            self.set_up = true;
+        }
+        if let Some(snapshot) = &self.capability_cache {
+            return Ok(snapshot.clone());
+        }
+        let snapshot = CapabilitiesSnapshot::new(self.build_capabilities());
+        self.capability_cache = Some(snapshot.clone());
+        Ok(snapshot)
+    }
+
+    /// Drop the cached capabilities and query the infos from the device again.
+    ///
+    /// The states and parameters of the streams are kept.
+    pub fn invalidate_capabilities(&mut self) -> Result<(), VirtioDeviceError> {
+        self.capability_cache = None;
+        if self.set_up {
+            self.query_infos()?;
+        }
+        Ok(())
+    }
+
+    fn build_capabilities(&self) -> Vec<StreamCapability> {
+        let chmap_infos = self.sound_inner.chmap_infos.read();
+        let chmap_infos = chmap_infos.as_deref().unwrap_or(&[]);
+        self.sound_inner
+            .pcm_infos
+            .read()
+            .as_deref()
+            .unwrap_or(&[])
+            .iter()
+            .enumerate()
+            .map(|(stream_id, pcm_info)| {
+                let direction = pcm_info.direction();
+                let formats = (0..u64::BITS)
+                    .filter(|bit| pcm_info.formats.get() & (1 << bit) != 0)
+                    .filter_map(|bit| SampleFormat::try_from(bit as u8).ok())
+                    .collect();
+                let rates = PCM_RATES_HZ
+                    .iter()
+                    .enumerate()
+                    .filter(|(bit, _)| pcm_info.rates.get() & (1 << bit) != 0)
+                    .map(|(_, hz)| *hz)
+                    .collect();
+                // A channel map belongs to the stream sharing its function group node.
+                let channel_maps = chmap_infos
+                    .iter()
+                    .filter(|chmap_info| {
+                        chmap_info.hdr == pcm_info.hdr && chmap_info.direction == pcm_info.direction
+                    })
+                    .map(|chmap_info| {
+                        let channels =
+                            usize::from(chmap_info.channels).min(VIRTIO_SND_CHMAP_MAX_SIZE);
+                        chmap_info.positions[..channels].to_vec()
+                    })
+                    .collect();
+                let jacks = self
+                    .jack_routes
+                    .iter()
+                    .filter(|(_, streams)| streams.contains(&(stream_id as u32)))
+                    .map(|(jack_id, _)| *jack_id)
+                    .collect();
+                StreamCapability {
+                    stream_id: stream_id as u32,
+                    direction,
+                    formats,
+                    rates,
+                    channels: pcm_info.channel_range(),
+                    channel_maps,
+                    // virtio-sound has no request to select a channel map.
+                    remappable: false,
+                    jacks,
+                }
+            })
+            .collect()
+    }
+
+    /// Claim a free stream of the given direction that accepts `params`, then
+    /// set its parameters and prepare it.
+    pub fn open_stream(
+        &mut self,
+        direction: StreamDirection,
+        params: &StreamParams,
+    ) -> Result<u32, VirtioDeviceError> {
+        let rate = PcmRate::from_hz(params.rate).ok_or(VirtioDeviceError::InvalidParam)?;
+        let stream_id = self
+            .capabilities()?
+            .iter()
+            .find(|capability| {
+                capability.direction == direction
+                    && !self.stream_opened[capability.stream_id as usize]
+                    && !self.stream_disabled[capability.stream_id as usize]
+                    && capability.supports(params.format, params.rate, params.channels)
+            })
+            .map(|capability| capability.stream_id)
+            .ok_or(VirtioDeviceError::InvalidParam)?;
+
+        self.pcm_set_params(
+            stream_id,
+            PcmParamsBuilder::new(params.format.into(), rate, params.channels)
+                .buffer_bytes(params.buffer_bytes)
+                .period_bytes(params.period_bytes),
+        )?;
+        self.pcm_prepare(stream_id)?;
+        self.stream_opened[stream_id as usize] = true;
+        Ok(stream_id)
+    }
+
+    /// Stop a stream if it is running, release it and give it back for other users.
+    pub fn close_stream(&mut self, stream_id: u32) -> Result<(), VirtioDeviceError> {
+        self.check_opened(stream_id)?;
+        if self.pcm_states[stream_id as usize] == PCMState::Start {
+            self.pcm_stop(stream_id)?;
+        }
+        self.stream_opened[stream_id as usize] = false;
+        self.paused_by_jack.remove(&stream_id);
+        self.sound_inner.remove_pull_stream(stream_id);
+        self.pcm_release(stream_id)
+    }
+
+    /// Enable a stream disabled by [`SoundDevice::disable_stream`].
+    ///
+    /// A stream that was opened while being disabled stays stopped until its user starts it again.
+    pub fn enable_stream(&mut self, stream_id: u32) -> Result<(), VirtioDeviceError> {
+        if stream_id as usize >= self.stream_disabled.len() {
+            return Err(VirtioDeviceError::InvalidParam);
+        }
+        self.stream_disabled[stream_id as usize] = false;
+        Ok(())
+    }
+
+    pub fn is_stream_enabled(&self, stream_id: u32) -> bool {
+        !self
+            .stream_disabled
+            .get(stream_id as usize)
+            .copied()
+            .unwrap_or(true)
+    }
+
+    fn check_stream_enabled(&self, stream_id: u32) -> Result<(), VirtioDeviceError> {
+        if self.suspended.is_some() {
+            return Err(VirtioDeviceError::Suspended);
+        }
+        if self.is_stream_enabled(stream_id) {
+            Ok(())
+        } else {
+            Err(VirtioDeviceError::StreamDisabled)
+        }
+    }
+
+    /// Set the saved parameters of the streams released by [`SoundDevice::suspend`] again,
+    /// prepare them and start those that were running.
+    pub fn resume(&mut self) -> Result<(), VirtioDeviceError> {
+        let Some(suspended) = self.suspended.take() else {
+            return Ok(());
+        };
+        self.restore(suspended)
+    }
+
+    /// Set the parameters of the streams the device forgot when it was reset again,
+    /// prepare the streams that were prepared, and start those that were running.
+    fn restore_streams(&mut self) -> Result<(), VirtioDeviceError> {
+        let mut streams = BTreeMap::new();
+        for stream_id in 0..self.pcm_states.len() as u32 {
+            let state = self.pcm_states[stream_id as usize];
+            if !self.pcm_parameters[stream_id as usize].setup {
+                continue;
+            }
+            match state {
+                PCMState::Prepare | PCMState::Start | PCMState::Stop => {
+                    if state == PCMState::Start && self.is_input_stream(stream_id) {
+                        // The capture is counted again when the stream is restarted.
+                        aster_sound::capture_stopped();
+                    }
+                    streams.insert(stream_id, state == PCMState::Start);
+                    self.pcm_states[stream_id as usize] = PCMState::Release;
+                }
+                PCMState::SetParameters => {
+                    let params = PcmParamsBuilder::from(&self.pcm_parameters[stream_id as usize]);
+                    self.pcm_set_params(stream_id, params)?;
+                }
+                PCMState::Release => {}
+            }
+        }
+        self.restore(streams)
+    }
+
+    /// Set the saved parameters of `streams` again and prepare them, starting the
+    /// streams mapped to `true`.
+    fn restore(&mut self, streams: BTreeMap<u32, bool>) -> Result<(), VirtioDeviceError> {
+        for (stream_id, running) in streams {
+            let params = PcmParamsBuilder::from(&self.pcm_parameters[stream_id as usize]);
+            self.pcm_set_params(stream_id, params)?;
+            self.pcm_prepare(stream_id)?;
+            if running {
+                self.pcm_start(stream_id)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Recover a stream from an underrun or overrun, like `snd_pcm_recover`.
+    ///
+    /// The stream is released, then set up with the same parameters and prepared
+    /// again, dropping the periods it had queued. A stream that was running is
+    /// restarted. Nothing is done if the stream has not underrun or overrun.
+    pub fn pcm_recover(&mut self, stream_id: u32) -> Result<(), VirtioDeviceError> {
+        self.check_stream_id(stream_id)?;
+        if !self.sound_inner.xruns.lock().contains(&stream_id) {
+            return Ok(());
+        }
+        let running = self.pcm_states[stream_id as usize] == PCMState::Start;
+        if running {
+            self.pcm_stop(stream_id)?;
+        }
+        if self.pcm_states[stream_id as usize] != PCMState::Release {
+            self.pcm_release(stream_id)?;
+        }
+        let params = PcmParamsBuilder::from(&self.pcm_parameters[stream_id as usize]);
+        self.pcm_set_params(stream_id, params)?;
+        self.pcm_prepare(stream_id)?;
+        self.sound_inner.xruns.lock().remove(&stream_id);
+        if running {
+            self.pcm_start(stream_id)?;
+        }
+        Ok(())
+    }
+
+    /// Change the parameters of an opened stream.
+    ///
+    /// The stream is released, set up with `params` and prepared again, dropping
+    /// the periods it had queued; its hardware buffer is allocated again to fit
+    /// the new `buffer_bytes`. A stream that was running is restarted.
+    ///
+    /// If the device rejects `params`, the stream is set up with its previous
+    /// parameters again, so that it is left as it was.
+    pub fn pcm_reconfigure(
+        &mut self,
+        stream_id: u32,
+        params: &StreamParams,
+    ) -> Result<(), VirtioDeviceError> {
+        self.check_opened(stream_id)?;
+        let rate = PcmRate::from_hz(params.rate).ok_or(VirtioDeviceError::InvalidParam)?;
+        let old_params = self.pcm_parameters[stream_id as usize].clone();
+        let running = self.pcm_states[stream_id as usize] == PCMState::Start;
+        if running {
+            self.pcm_stop(stream_id)?;
+        }
+        if self.pcm_states[stream_id as usize] != PCMState::Release {
+            self.pcm_release(stream_id)?;
+        }
+        let result = self.pcm_set_params(
+            stream_id,
+            PcmParamsBuilder::new(params.format.into(), rate, params.channels)
+                .buffer_bytes(params.buffer_bytes)
+                .period_bytes(params.period_bytes)
+                .features(old_params.features),
+        );
+        if let Err(err) = result {
+            warn!(
+                "[sound device] failed to reconfigure stream {}: {:?}",
+                stream_id, err
+            );
+            self.pcm_set_params(stream_id, PcmParamsBuilder::from(&old_params))?;
+        }
+        self.pcm_prepare(stream_id)?;
+        self.sound_inner.xruns.lock().remove(&stream_id);
+        if running {
+            self.pcm_start(stream_id)?;
+        }
+        result
+    }
+}
+
+impl SoundDevice {
+    /// Lock the control state, first acting on a change of the configuration of
+    /// the device.
+    fn lock_control(&self) -> MutexGuard<'_, ControlState> {
+        let mut control = self.control.lock();
+        if self.sound_inner.needs_reset.swap(false, Ordering::Acquire) {
+            if let Err(err) = self.recover(&mut control) {
+                warn!("[sound device] failed to recover: {:?}", err);
+            }
+        }
+        if self
+            .sound_inner
+            .config_changed
+            .swap(false, Ordering::Acquire)
+        {
+            if let Err(err) = self.reconfigure(&mut control) {
+                warn!("[sound device] failed to reconfigure: {:?}", err);
+            }
+        }
+        control
+    }
+
+    /// Reset the device and set it up again, then restore its configured streams,
+    /// rather than leaving the driver wedged.
+    ///
+    /// This is done by the next control operation once the device asks to be reset
+    /// or stops answering control requests.
+    pub fn recover_device(&self) -> Result<(), VirtioDeviceError> {
+        let mut control = self.control.lock();
+        self.recover(&mut control)
+    }
+
+    /// Reset the device, negotiate the features and create the virtqueues again,
+    /// then set the configured streams up again.
+    ///
+    /// The periods queued before the reset are dropped. The streams are prepared
+    /// again, and those that were running are restarted.
+    fn recover(&self, control: &mut ControlState) -> Result<(), VirtioDeviceError> {
+        warn!("[sound device] resetting the device");
+        self.sound_inner.reinit(true)?;
+        {
+            let mut tx = self.tx.lock();
+            tx.token_buf.clear();
+            tx.next_periods.clear();
+        }
+        // A suspended device has its streams restored when it is resumed.
+        if control.suspended.is_some() {
+            return Ok(());
+        }
+        control.restore_streams()
+    }
+
+    /// Resize the per-stream and per-jack state to the configuration of the device,
+    /// then set the device up again.
+    ///
+    /// The streams that are still present keep their parameters and state.
+    fn reconfigure(&self, control: &mut ControlState) -> Result<(), VirtioDeviceError> {
+        let config = self.sound_inner.read_config();
+        let streams = config.streams as usize;
+        for stream_id in streams..control.pcm_parameters.len() {
+            if control.stream_opened[stream_id] {
+                warn!("[sound device] opened stream {} was removed", stream_id);
+            }
+        }
+        control.resize_streams(streams);
+        self.latency_histograms
+            .lock()
+            .resize(streams, LatencyHistogram::new());
+        self.stream_clocks
+            .lock()
+            .resize(streams, StreamClock::default());
+        {
+            let mut tx = self.tx.lock();
+            let status_bytes =
+                streams * Self::QUEUE_SIZE as usize * size_of::<VirtioSndPcmStatus>();
+            if status_bytes > tx.status_buffer.nbytes() {
+                let segment = FrameAllocOptions::new()
+                    .alloc_segment(status_bytes.div_ceil(PAGE_SIZE))
+                    .map_err(|_| VirtioDeviceError::DmaError)?;
+                tx.status_buffer = DmaStream::map(segment.into(), DmaDirection::FromDevice, false)
+                    .map_err(|_| VirtioDeviceError::DmaError)?;
+            }
+            if streams * size_of::<VirtioSndPcmXfer>() > tx.header_buffer.nbytes() {
+                tx.header_buffer = alloc_header_buffer(streams)?;
+            }
+        }
+        *self.sound_inner.jack_connected.write() =
+            (0..config.jacks).map(|_| AtomicBool::new(false)).collect();
+
+        *self.sound_inner.pcm_infos.write() = None;
+        *self.sound_inner.chmap_infos.write() = None;
+        control.jack_infos = vec![];
+        control.capability_cache = None;
+        control.set_up = false;
+        control.set_up()?;
+        control.set_up = true;
+        Ok(())
+    }
+
+    /// Get the submission-to-completion latencies of the periods of a stream.
+    pub fn latency_histogram(&self, stream_id: u32) -> Option<LatencyHistogram> {
+        self.latency_histograms
+            .lock()
+            .get(stream_id as usize)
+            .cloned()
+    }
+
+    /// Get the position of an opened stream as of the last completed transfer.
+    ///
+    /// The frames still buffered by the device count as not yet played for an
+    /// output stream, and as already captured for an input stream.
+    pub fn stream_position(&self, stream_id: u32) -> Result<StreamPosition, VirtioDeviceError> {
+        let control = self.lock_control();
+        control.check_opened(stream_id)?;
+        let params = &control.pcm_parameters[stream_id as usize];
+        let frame_bytes =
+            frame_bytes(params.format, params.channels).ok_or(VirtioDeviceError::InvalidParam)?;
+        let clock = self.stream_clocks.lock()[stream_id as usize];
+        let bytes = if control.is_input_stream(stream_id) {
+            clock.transferred_bytes + clock.latency_bytes as u64
+        } else {
+            clock
+                .transferred_bytes
+                .saturating_sub(clock.latency_bytes as u64)
+        };
+        Ok(StreamPosition {
+            frames: bytes / frame_bytes.max(1) as u64,
+            timestamp: clock.timestamp,
+        })
+    }
+
+    /// Get the hardware position of an opened stream, like the hardware pointer of
+    /// an ALSA driver.
+    ///
+    /// The position advances by whole periods as the device completes them. The
+    /// periods of the buffer past the position are still owned by the device, so a
+    /// player writing into the buffer, e.g., through a mapping, must stay within one
+    /// buffer ahead of the position.
+    pub fn pcm_hw_position(&self, stream_id: u32) -> Result<PcmHwPosition, VirtioDeviceError> {
+        let control = self.lock_control();
+        control.check_opened(stream_id)?;
+        let params = &control.pcm_parameters[stream_id as usize];
+        let frame_bytes = frame_bytes(params.format, params.channels)
+            .ok_or(VirtioDeviceError::InvalidParam)?
+            .max(1) as u64;
+        let clock = self.stream_clocks.lock()[stream_id as usize];
+        Ok(PcmHwPosition {
+            frames: clock.transferred_bytes / frame_bytes,
+            delay: clock.latency_bytes as u64 / frame_bytes,
+        })
+    }
+
+    /// Get the number of silent frames the device played on an output stream,
+    /// because none of its non-blocking transfers was in flight.
+    ///
+    /// It is counted from the first transfer after the stream was set up.
+    pub fn silence_frames(&self, stream_id: u32) -> Frames {
+        self.sound_inner
+            .silence_fills
+            .lock()
+            .get(&stream_id)
+            .map_or(0, |fill| {
+                fill.inserted_bytes / fill.frame_bytes.max(1) as u64
+            })
+    }
+
+    /// Get the latency of an opened stream, as reported by the device in the
+    /// status of the last completed transfer.
+    ///
+    /// It is the time it takes the device to play, or to hand over, the frames
+    /// it still has buffered.
+    pub fn stream_latency(&self, stream_id: u32) -> Result<Duration, VirtioDeviceError> {
+        let control = self.lock_control();
+        control.check_opened(stream_id)?;
+        let params = &control.pcm_parameters[stream_id as usize];
+        let frame_bytes =
+            frame_bytes(params.format, params.channels).ok_or(VirtioDeviceError::InvalidParam)?;
+        let latency_bytes = self.stream_clocks.lock()[stream_id as usize].latency_bytes;
+        let latency_frames = latency_bytes as u64 / frame_bytes.max(1) as u64;
+        Ok(Duration::from_micros(
+            latency_frames * 1_000_000 / params.rate.hz() as u64,
+        ))
+    }
+
+    /// Get all output streams.
+    pub fn output_streams(&self) -> Vec<u32> {
+        self.sound_inner.streams_of(StreamDirection::Output)
+    }
+
+    /// Get all input streams.
+    pub fn input_streams(&self) -> Vec<u32> {
+        self.sound_inner.streams_of(StreamDirection::Input)
+    }
+
+    /// Get the direction and the capabilities of a stream.
+    pub fn stream_info(&self, stream_id: u32) -> Result<StreamInfo, VirtioDeviceError> {
+        let pcm_info = self.sound_inner.pcm_info(stream_id)?;
+        Ok(StreamInfo::from(&pcm_info))
+    }
+
+    /// Summarize the configuration and the streams of the device, e.g., to back a
+    /// `/proc/asound/cards`-like file.
+    ///
+    /// Each stream is listed in a table row with its direction, its numbers of
+    /// channels, its formats, its rates and the channel maps it shares a function
+    /// group node with.
+    pub fn summary(&self) -> String {
+        let mut summary = format!("{}: {}\n", self.name(), self.sound_inner.read_config());
+        let _ = writeln!(
+            summary,
+            "{:>6}  {:<9}  {:<8}  {:<24}  {:<24}  chmaps",
+            "stream", "direction", "channels", "formats", "rates"
+        );
+        let chmap_infos = self.sound_inner.chmap_infos.read();
+        let chmap_infos = chmap_infos.as_deref().unwrap_or(&[]);
+        let pcm_infos = self.sound_inner.pcm_infos.read();
+        for (stream_id, pcm_info) in pcm_infos.as_deref().unwrap_or(&[]).iter().enumerate() {
+            let direction = match pcm_info.direction() {
+                StreamDirection::Input => "INPUT",
+                StreamDirection::Output => "OUTPUT",
+            };
+            let channels = format!("{}-{}", pcm_info.channels_min, pcm_info.channels_max);
+            let formats = format!("{:?}", pcm_info.formats());
+            let rates = PCM_RATES_HZ
+                .iter()
+                .enumerate()
+                .filter(|(bit, _)| pcm_info.rates.get() & (1 << bit) != 0)
+                .map(|(_, hz)| hz.to_string())
+                .collect::<Vec<_>>()
+                .join(",");
+            let chmaps = chmap_infos
+                .iter()
+                .filter(|chmap_info| {
+                    chmap_info.hdr == pcm_info.hdr && chmap_info.direction == pcm_info.direction
+                })
+                .map(|chmap_info| {
+                    let channels = usize::from(chmap_info.channels).min(VIRTIO_SND_CHMAP_MAX_SIZE);
+                    let positions = chmap_info.positions[..channels]
+                        .iter()
+                        .map(|&position| match ChannelPosition::try_from(position) {
+                            Ok(position) => format!("{:?}", position),
+                            Err(_) => position.to_string(),
+                        })
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                    format!("[{}]", positions)
+                })
+                .collect::<Vec<_>>()
+                .join(" ");
+            let _ = writeln!(
+                summary,
+                "{:>6}  {:<9}  {:<8}  {:<24}  {:<24}  {}",
+                stream_id, direction, channels, formats, rates, chmaps
+            );
+        }
+        summary
+    }
+
+    /// Get the rates that a stream supports.
+    pub fn rates_supported(&self, stream_id: u32) -> Result<PcmRates, VirtioDeviceError> {
+        let pcm_info = self.sound_inner.pcm_info(stream_id)?;
+        Ok(pcm_info.rates())
+    }
+
+    /// Get the formats that a stream supports.
+    pub fn formats_supported(&self, stream_id: u32) -> Result<PcmFormats, VirtioDeviceError> {
+        let pcm_info = self.sound_inner.pcm_info(stream_id)?;
+        Ok(pcm_info.formats())
+    }
+
+    /// Get channel range that a stream supports.
+    pub fn channel_range_supported(
+        &self,
+        stream_id: u32,
+    ) -> Result<RangeInclusive<u8>, VirtioDeviceError> {
+        let pcm_info = self.sound_inner.pcm_info(stream_id)?;
+        Ok(pcm_info.channel_range())
+    }
+
+    /// Get the features that a stream supports.
+    pub fn features_supported(&self, stream_id: u32) -> Result<PcmFeatures, VirtioDeviceError> {
+        self.sound_inner.features_supported(stream_id)
+    }
+
+    /// Return the position of each channel of an opened stream.
+    ///
+    /// The positions are taken from the channel map the device reports for the
+    /// stream with as many channels as it was opened with.
+    pub fn channel_map(&self, stream_id: u32) -> Result<Vec<u8>, VirtioDeviceError> {
+        let control = self.lock_control();
+        control.check_opened(stream_id)?;
+        let pcm_info = self.sound_inner.pcm_info(stream_id)?;
+        let channels = control.pcm_parameters[stream_id as usize].channels;
+        // A channel map belongs to the stream sharing its function group node.
+        self.sound_inner
+            .chmap_infos
+            .read()
+            .as_deref()
+            .unwrap_or(&[])
+            .iter()
+            .filter(|chmap_info| {
+                chmap_info.hdr == pcm_info.hdr && chmap_info.direction == pcm_info.direction
+            })
+            .find(|chmap_info| chmap_info.channels == channels)
+            .map(|chmap_info| {
+                let channels = usize::from(channels).min(VIRTIO_SND_CHMAP_MAX_SIZE);
+                chmap_info.positions[..channels].to_vec()
+            })
+            .ok_or(VirtioDeviceError::Unsupported)
+    }
+
+    /// Set the priority of the deferred work that processes the transfer completions.
+    ///
+    /// Boosted completions are run by urgent taskless jobs, ahead of the other deferred work.
+    pub fn set_completion_priority(&self, priority: CompletionPriority) {
+        self.sound_inner
+            .boost_completions
+            .store(priority == CompletionPriority::Boosted, Ordering::Relaxed);
+    }
+
+    /// Set how long to wait for the device to answer a request on the control queue.
+    ///
+    /// A request left unanswered fails with [`VirtioDeviceError::Timeout`].
+    pub fn set_request_timeout(&self, timeout: Duration) {
+        self.lock_control().set_request_timeout(timeout);
+    }
+
+    /// Claim a free stream of the given direction that accepts `params`, then
+    /// set its parameters and prepare it.
+    pub fn open_stream(
+        &self,
+        direction: StreamDirection,
+        params: &StreamParams,
+    ) -> Result<u32, VirtioDeviceError> {
+        let stream_id = self.lock_control().open_stream(direction, params)?;
+        self.stream_clocks.lock()[stream_id as usize] = StreamClock::default();
+        Ok(stream_id)
+    }
+
+    /// Stop a stream if it is running, release it and give it back for other users.
+    pub fn close_stream(&self, stream_id: u32) -> Result<(), VirtioDeviceError> {
+        let completion_mode = {
+            let control = self.lock_control();
+            control.check_opened(stream_id)?;
+            control.completion_modes[stream_id as usize]
+        };
+        self.stop_pump(stream_id);
+        // The hardware buffer is freed on release, so the device must be done with it.
+        self.wait_stream_transfers(stream_id, completion_mode);
+        self.tx.lock().next_periods.remove(&stream_id);
+        self.lock_control().close_stream(stream_id)
+    }
+
+    /// Disable a stream so that it can no longer be opened.
+    ///
+    /// If the stream is opened, its pending transfers are drained and it is stopped;
+    /// its user then gets errors until it closes the stream.
+    pub fn disable_stream(&self, stream_id: u32) -> Result<(), VirtioDeviceError> {
+        let completion_mode = {
+            let mut control = self.lock_control();
+            if stream_id as usize >= control.stream_disabled.len() {
+                return Err(VirtioDeviceError::InvalidParam);
+            }
+            control.stream_disabled[stream_id as usize] = true;
+            control.paused_by_jack.remove(&stream_id);
+            if !control.stream_opened[stream_id as usize] {
+                return Ok(());
+            }
+            control.completion_modes[stream_id as usize]
+        };
+        // Drain without the control lock, so that the other streams keep running.
+        self.wait_stream_transfers(stream_id, completion_mode);
+        let mut control = self.lock_control();
+        if control.pcm_states[stream_id as usize] == PCMState::Start {
+            control.pcm_stop(stream_id)?;
+        }
+        Ok(())
+    }
+
+    /// Quiesce the device before it loses power.
+    ///
+    /// The pending transfers are drained, then every opened stream is stopped and
+    /// released. Their parameters and positions are kept so that [`Self::resume`]
+    /// can restore them.
+    pub fn suspend(&self) -> Result<(), VirtioDeviceError> {
+        let completion_mode = {
+            let mut control = self.lock_control();
+            if control.suspended.is_some() {
+                return Ok(());
+            }
+            // No transfer can be submitted from now on.
+            control.suspended = Some(BTreeMap::new());
+            control.tx_completion_mode()
+        };
+        // Drain without the control lock, so that other control requests are not held up.
+        self.drain(completion_mode)?;
+        let mut control = self.lock_control();
+        let mut suspended = BTreeMap::new();
+        for stream_id in 0..control.stream_opened.len() as u32 {
+            if !control.stream_opened[stream_id as usize] {
+                continue;
+            }
+            let running = control.pcm_states[stream_id as usize] == PCMState::Start;
+            if running {
+                control.pcm_stop(stream_id)?;
+            }
+            control.pcm_release(stream_id)?;
+            suspended.insert(stream_id, running);
+        }
+        control.suspended = Some(suspended);
+        drop(control);
+        self.tx.lock().next_periods.clear();
+        Ok(())
+    }
+
+    /// The name the device is registered to the component with, e.g.,
+    /// `Virtio-Sound-0`.
+    ///
+    /// The devices are numbered in the order they are probed, so that several
+    /// virtio-sound devices can be registered side by side.
+    pub fn name(&self) -> String {
+        format!("{}-{}", DEVICE_NAME, self.sound_inner.index)
+    }
+
+    fn self_test_name(&self) -> String {
+        format!("{}-{}", SELF_TEST_NAME, self.sound_inner.index)
+    }
+
+    /// Tear the device down, e.g., when it is unplugged.
+    ///
+    /// The device is first unregistered from the component, its self-test and
+    /// its power management are dropped, so that it can no longer be reached. Every stream is then stopped
+    /// and released on the device, which frees its DMA buffers, and the device is
+    /// reset so that it no longer uses the queues.
+    pub fn remove(&self) {
+        aster_sound::unregister_device(&self.sound_inner.stable_id);
+        unregister_self_test(&self.self_test_name());
+        unregister_pm_device(&self.sound_inner.stable_id);
+        for pump in core::mem::take(&mut *self.pumps.lock()).into_values() {
+            pump.stop();
+        }
+
+        self.lock_control().release_streams();
+        self.sound_inner.reset();
+        let mut tx = self.tx.lock();
+        tx.token_buf.clear();
+        tx.next_periods.clear();
+    }
+
+    /// Restore the streams released by [`Self::suspend`].
+    ///
+    /// A device that was reset while suspended, e.g., because it lost power, is
+    /// set up again first.
+    pub fn resume(&self) -> Result<(), VirtioDeviceError> {
+        if self.sound_inner.reinit(false)? {
+            // The periods and transfers queued before the reset are gone.
+            let mut tx = self.tx.lock();
+            tx.token_buf.clear();
+            tx.next_periods.clear();
+        }
+        self.lock_control().resume()
+    }
+
+    /// Recover a stream from an underrun or overrun.
+    ///
+    /// Until then, the transfers on the stream fail with [`VirtioDeviceError::Xrun`].
+    pub fn pcm_recover(&self, stream_id: u32) -> Result<(), VirtioDeviceError> {
+        let completion_mode = {
+            let control = self.lock_control();
+            *control
+                .completion_modes
+                .get(stream_id as usize)
+                .ok_or(VirtioDeviceError::InvalidParam)?
+        };
+        // The hardware buffer is freed on release, so the device must be done with it.
+        self.wait_stream_transfers(stream_id, completion_mode);
+        self.tx.lock().next_periods.remove(&stream_id);
+        self.lock_control().pcm_recover(stream_id)
+    }
+
+    /// Change the parameters of an opened stream, e.g., its rate, format or channels.
+    ///
+    /// The periods queued on the stream are played first. The stream is left with
+    /// its previous parameters if the device rejects `params`. A stream played in
+    /// pull mode keeps the size of its periods, so it cannot be reconfigured.
+    pub fn pcm_reconfigure(
+        &self,
+        stream_id: u32,
+        params: &StreamParams,
+    ) -> Result<(), VirtioDeviceError> {
+        if self
+            .sound_inner
+            .pull_streams
+            .disable_irq()
+            .lock()
+            .contains_key(&stream_id)
+        {
+            return Err(VirtioDeviceError::InvalidState);
+        }
+        let completion_mode = {
+            let control = self.lock_control();
+            *control
+                .completion_modes
+                .get(stream_id as usize)
+                .ok_or(VirtioDeviceError::InvalidParam)?
+        };
+        // The hardware buffer is freed on release, so the device must be done with it.
+        self.wait_stream_transfers(stream_id, completion_mode);
+        self.tx.lock().next_periods.remove(&stream_id);
+        self.lock_control().pcm_reconfigure(stream_id, params)?;
+        self.stream_clocks.lock()[stream_id as usize] = StreamClock::default();
+        Ok(())
+    }
+
+    /// Pause a running stream, keeping its parameters and buffers.
+    pub fn pcm_pause(&self, stream_id: u32) -> Result<(), VirtioDeviceError> {
+        self.lock_control().pcm_pause(stream_id)
+    }
+
+    /// Resume a stream paused by [`Self::pcm_pause`].
+    pub fn pcm_resume(&self, stream_id: u32) -> Result<(), VirtioDeviceError> {
+        self.lock_control().pcm_resume(stream_id)
+    }
+
+    /// Play an opened output stream in pull mode.
+    ///
+    /// `callback` is invoked with a writer of one period whenever the device reports
+    /// that a period has elapsed; whatever it leaves unwritten is played as silence.
+    pub fn register_playback_callback(
+        &self,
+        stream_id: u32,
+        callback: Arc<PlaybackCallback>,
+    ) -> Result<CallbackHandle, VirtioDeviceError> {
+        let period_bytes = {
+            let control = self.lock_control();
+            control.check_opened(stream_id)?;
+            if control.is_input_stream(stream_id) {
+                return Err(VirtioDeviceError::InvalidParam);
+            }
+            control.check_stream_enabled(stream_id)?;
+            control.check_transfer(stream_id)?;
+            control.pcm_parameters[stream_id as usize].period_bytes as usize
+        };
+        self.sound_inner
+            .add_pull_stream(stream_id, period_bytes, callback)?;
+
+        let sound_inner = Arc::downgrade(&self.sound_inner);
+        Ok(CallbackHandle::new(move || {
+            if let Some(sound_inner) = sound_inner.upgrade() {
+                sound_inner.remove_pull_stream(stream_id);
+            }
+        }))
+    }
+
+    /// Read the frames captured on a started input stream into `buffer`, returning
+    /// the number of bytes read.
+    ///
+    /// The frames are captured in periods of the parameters the stream was set up
+    /// with. If none is buffered, the caller sleeps until the device fills the next
+    /// period, or yields if the stream is polled, since it raises no interrupt.
+    pub fn record(&self, stream_id: u32, buffer: &mut [u8]) -> Result<usize, VirtioDeviceError> {
+        let completion_mode = {
+            let control = self.lock_control();
+            control.check_opened(stream_id)?;
+            if !control.is_input_stream(stream_id) {
+                return Err(VirtioDeviceError::InvalidParam);
+            }
+            control.check_stream_enabled(stream_id)?;
+            if control.pcm_states[stream_id as usize] != PCMState::Start {
+                return Err(VirtioDeviceError::InvalidState);
+            }
+            control.completion_modes[stream_id as usize]
+        };
+        let privacy = aster_sound::capture_privacy();
+        if privacy.blocked && privacy.mode == CaptureBlockMode::Error {
+            return Err(VirtioDeviceError::CaptureBlocked);
+        }
+
+        let sound_inner = &self.sound_inner;
+        // Each input stream captures into a ring of its own, which is kept while
+        // the frames are read even if the stream is released meanwhile.
+        let Some(ring) = sound_inner.capture_ring(stream_id) else {
+            return Ok(0);
+        };
+        let len = loop {
+            // A polled stream raises no interrupt, so its periods are collected here.
+            sound_inner.process_rx_completions();
+            let len = ring.pop(buffer);
+            if len > 0 || buffer.is_empty() || !sound_inner.is_capturing(stream_id) {
+                break len;
+            }
+            match completion_mode {
+                CompletionMode::Interrupt => sound_inner.rx_wait_queue.wait_until(|| {
+                    (!ring.is_empty() || !sound_inner.is_capturing(stream_id)).then_some(())
+                }),
+                CompletionMode::Polling => Task::yield_now(),
+            }
+        };
+        // The frames left in the ring have been captured but not read yet.
+        let buffered = ring.len();
+        self.stream_clocks.lock()[stream_id as usize].complete(len, buffered as u32);
+        Ok(len)
+    }
+
+    /// Submit a request to record `len` bytes of an input stream, without waiting for it.
+    ///
+    /// `len` must not be zero nor exceed the buffer size of the stream.
+    pub fn record_nb(&self, stream_id: u32, len: usize) -> Result<u16, VirtioDeviceError> {
+        {
+            let control = self.lock_control();
+            control.check_opened(stream_id)?;
+            if !control.is_input_stream(stream_id) {
+                return Err(VirtioDeviceError::InvalidParam);
+            }
+            let buffer_bytes = control.pcm_parameters[stream_id as usize].buffer_bytes as usize;
+            if len == 0 || len > buffer_bytes {
+                return Err(VirtioDeviceError::InvalidParam);
+            }
+            control.check_stream_enabled(stream_id)?;
+            control.check_transfer(stream_id)?;
+        }
+        self.submit_record(stream_id, len)
+    }
+
+    /// Submit a request to capture `len` bytes of frames of an input stream to the
+    /// rx queue, without waiting for it, and return its token.
+    ///
+    /// This is the input counterpart of [`Self::pcm_xfer_nb`]: the stream only needs
+    /// its parameters set, rather than being opened. The device fills the request
+    /// once the stream is started, and [`Self::pcm_capture_ok`] collects the frames.
+    pub fn pcm_capture_nb(&self, stream_id: u32, len: usize) -> Result<u16, VirtioDeviceError> {
+        {
+            let control = self.lock_control();
+            control.check_stream_id(stream_id)?;
+            if !control.pcm_parameters[stream_id as usize].setup {
+                warn!("Please set parameters for a stream before using it!");
+                return Err(VirtioDeviceError::IoError);
+            }
+            if !control.is_input_stream(stream_id) || len == 0 {
+                return Err(VirtioDeviceError::InvalidParam);
+            }
+            control.check_transfer(stream_id)?;
+        }
+        self.submit_record(stream_id, len)
+    }
+
+    /// Collect the frames of a request submitted by [`Self::pcm_capture_nb`] into
+    /// `buffer`, returning how many bytes of frames the device actually wrote.
+    ///
+    /// Return [`VirtioDeviceError::WouldBlock`] if the device has not completed the
+    /// request yet, and [`VirtioDeviceError::InvalidParam`] if no request has the token.
+    pub fn pcm_capture_ok(
+        &self,
+        token: u16,
+        buffer: &mut [u8],
+    ) -> Result<usize, VirtioDeviceError> {
+        match self.record_poll(token, buffer) {
+            Some(result) => result,
+            None if self
+                .sound_inner
+                .records
+                .disable_irq()
+                .lock()
+                .contains_key(&token) =>
+            {
+                Err(VirtioDeviceError::WouldBlock)
+            }
+            None => Err(VirtioDeviceError::InvalidParam),
+        }
+    }
+
+    /// Submit a request to the rx queue for `len` bytes of frames of a stream, with
+    /// the `virtio_snd_pcm_xfer` header read by the device and the frames followed by
+    /// the `virtio_snd_pcm_status` written by it.
+    fn submit_record(&self, stream_id: u32, len: usize) -> Result<u16, VirtioDeviceError> {
+        const STATUS_SIZE: usize = size_of::<VirtioSndPcmStatus>();
+
+        let header = {
+            let segment = FrameAllocOptions::new()
+                .alloc_segment(1)
+                .map_err(|_| VirtioDeviceError::DmaError)?;
+            DmaStream::map(segment.into(), DmaDirection::ToDevice, false)
+                .map_err(|_| VirtioDeviceError::DmaError)?
+        };
+        header
+            .write_val(0, &VirtioSndPcmXfer::new(stream_id))
+            .unwrap();
+        header.sync(0..size_of::<VirtioSndPcmXfer>()).unwrap();
+        let frames = {
+            let nframes = (len + STATUS_SIZE).div_ceil(PAGE_SIZE);
+            let segment = FrameAllocOptions::new()
+                .alloc_segment(nframes)
+                .map_err(|_| VirtioDeviceError::DmaError)?;
+            DmaStream::map(segment.into(), DmaDirection::FromDevice, false)
+                .map_err(|_| VirtioDeviceError::DmaError)?
+        };
+
+        let header_slice = DmaStreamSlice::new(&header, 0, size_of::<VirtioSndPcmXfer>());
+        let frames_slice = DmaStreamSlice::new(&frames, 0, len);
+        let status_slice = DmaStreamSlice::new(&frames, len, STATUS_SIZE);
+        let mut queue = self.sound_inner.rx_queue.disable_irq().lock();
+        // Insert the request before the device may complete it.
+        let mut records = self.sound_inner.records.disable_irq().lock();
+        let token = queue.add_dma_buf(&[&header_slice], &[&frames_slice, &status_slice])?;
+        records.insert(
+            token,
+            PendingRecord {
+                stream_id,
+                header,
+                frames,
+                len,
+                used_len: None,
+            },
+        );
+        drop(records);
+        if queue.should_notify() {
+            queue.notify();
+        }
+        Ok(token)
+    }
+
+    /// Collect the frames of a completed record request into `buffer`.
+    ///
+    /// Return `None` if the request is still pending.
+    pub fn record_poll(
+        &self,
+        token: u16,
+        buffer: &mut [u8],
+    ) -> Option<Result<usize, VirtioDeviceError>> {
+        let mut queue = self.sound_inner.rx_queue.disable_irq().lock();
+        let mut records = self.sound_inner.records.disable_irq().lock();
+        let record = records.get_mut(&token)?;
+        if record.used_len.is_none() {
+            // The completion may not have been handled by the interrupt handler yet.
+            record.used_len = queue.pop_used_with_token(token).ok();
+        }
+        let used_len = record.used_len?;
+        let record = records.remove(&token).unwrap();
+        drop(records);
+        drop(queue);
+
+        let status_size = size_of::<VirtioSndPcmStatus>();
+        record.frames.sync(0..record.len + status_size).unwrap();
+        let status: VirtioSndPcmStatus = record.frames.read_val(record.len).unwrap();
+        if let Err(err) = check_status(status.status.get()) {
+            return Some(Err(err));
+        }
+
+        let len = (used_len as usize)
+            .saturating_sub(status_size)
+            .min(record.len)
+            .min(buffer.len());
+        let privacy = aster_sound::capture_privacy();
+        if privacy.blocked {
+            if privacy.mode == CaptureBlockMode::Error {
+                return Some(Err(VirtioDeviceError::CaptureBlocked));
+            }
+            buffer[..len].fill(0);
+        } else {
+            record.frames.read_bytes(0, &mut buffer[..len]).unwrap();
+        }
+        self.stream_clocks.lock()[record.stream_id as usize]
+            .complete(len, status.latency_bytes.get());
+        Some(Ok(len))
+    }
+
+    /// Wait until every non-blocking transfer has been completed by the device.
+    ///
+    /// `completion_mode` tells whether the tx queue raises interrupts to be woken up by.
+    pub fn drain(&self, completion_mode: CompletionMode) -> Result<(), VirtioDeviceError> {
+        let mut tx = self.tx.lock();
+        loop {
+            self.collect_nb_transfers(&mut tx);
+            if tx.token_buf.is_empty() {
+                return Ok(());
+            }
+            let tokens: Vec<u16> = tx.token_buf.keys().copied().collect();
+            self.sound_inner.wait_tx_used(completion_mode, |queue| {
+                self.sound_inner.any_nb_completed(queue, &tokens)
+            });
+        }
+    }
+
+    /// Wait until the device has consumed every period queued on a stream, then stop it.
+    ///
+    /// The caller sleeps on the tx wait queue between completions. A polled stream
+    /// raises no interrupt to be woken up by, so the caller yields instead.
+    pub fn drain_stream(&self, stream_id: u32) -> Result<(), VirtioDeviceError> {
+        let completion_mode = {
+            let control = self.lock_control();
+            control.check_opened(stream_id)?;
+            control.completion_modes[stream_id as usize]
+        };
+
+        self.wait_pump_drained(stream_id);
+        self.wait_stream_transfers(stream_id, completion_mode);
+
+        let mut control = self.lock_control();
+        if control.pcm_states[stream_id as usize] == PCMState::Start {
+            control.pcm_stop(stream_id)?;
+        }
+        Ok(())
+    }
+
+    /// Start feeding an opened output stream from a pump thread, which is woken up
+    /// as the device completes periods and feeds it the frames written with
+    /// [`Self::pump_write`].
+    ///
+    /// The periods then reach the device in time however bursty the writer is.
+    /// The pump runs until the stream is closed.
+    pub fn start_pump(self: &Arc<Self>, stream_id: u32) -> Result<(), VirtioDeviceError> {
+        let (period_bytes, buffer_bytes) = {
+            let control = self.lock_control();
+            control.check_opened(stream_id)?;
+            if control.is_input_stream(stream_id) {
+                return Err(VirtioDeviceError::InvalidParam);
+            }
+            let params = &control.pcm_parameters[stream_id as usize];
+            (params.period_bytes as usize, params.buffer_bytes as usize)
+        };
+        let pump = Arc::new(PumpStream {
+            period_bytes,
+            ring: CaptureRing::new(buffer_bytes),
+            writer: Mutex::new(()),
+            kicked: AtomicBool::new(false),
+            stopped: AtomicBool::new(false),
+            pump_wait_queue: WaitQueue::new(),
+            space_wait_queue: WaitQueue::new(),
+        });
+        {
+            let mut pumps = self.pumps.lock();
+            if pumps.contains_key(&stream_id) {
+                return Err(VirtioDeviceError::InvalidState);
+            }
+            pumps.insert(stream_id, pump.clone());
+        }
+
+        let device = self.clone();
+        let thread_pump = pump.clone();
+        if aster_sound::spawn_thread(move || device.run_pump(stream_id, thread_pump)).is_err() {
+            self.pumps.lock().remove(&stream_id);
+            return Err(VirtioDeviceError::Unsupported);
+        }
+        Ok(())
+    }
+
+    /// Write frames to an output stream fed by its pump thread, returning once they
+    /// have all been written to its ring.
+    ///
+    /// Return [`VirtioDeviceError::InvalidState`] if the stream has no pump, or
+    /// if the pump stops before the frames are written.
+    pub fn pump_write(&self, stream_id: u32, frames: &[u8]) -> Result<usize, VirtioDeviceError> {
+        let pump = self
+            .pumps
+            .lock()
+            .get(&stream_id)
+            .cloned()
+            .ok_or(VirtioDeviceError::InvalidState)?;
+        let _writer = pump.writer.lock();
+        let mut written = 0;
+        while written < frames.len() {
+            // Only what fits is pushed, since the ring drops what overflows.
+            let room = pump.ring.capacity() - pump.ring.len();
+            let len = room.min(frames.len() - written);
+            written += pump.ring.push(&frames[written..written + len]);
+            pump.kick();
+            if written < frames.len() {
+                pump.space_wait_queue.wait_until(|| {
+                    (pump.is_stopped() || pump.ring.len() < pump.ring.capacity()).then_some(())
+                });
+            }
+            if pump.is_stopped() {
+                return Err(VirtioDeviceError::InvalidState);
+            }
+        }
+        Ok(written)
+    }
+
+    /// Stop the pump thread of a stream, if it has one.
+    ///
+    /// The frames it has not fed to the device yet are dropped.
+    fn stop_pump(&self, stream_id: u32) {
+        if let Some(pump) = self.pumps.lock().remove(&stream_id) {
+            pump.stop();
+        }
+    }
+
+    /// Wait until the pump of a stream, if it has one, has fed every whole period
+    /// written to it to the device.
+    fn wait_pump_drained(&self, stream_id: u32) {
+        let Some(pump) = self.pumps.lock().get(&stream_id).cloned() else {
+            return;
+        };
+        pump.space_wait_queue.wait_until(|| {
+            (pump.is_stopped() || pump.ring.len() < pump.period_bytes).then_some(())
+        });
+    }
+
+    /// Feed the periods written to a stream to the device, until the pump is stopped.
+    ///
+    /// The pump sleeps until the device completes a transfer or reports that a
+    /// period has elapsed, or until frames are written.
+    fn run_pump(&self, stream_id: u32, pump: Arc<PumpStream>) {
+        let _xfer_callback = self.register_xfer_callback({
+            let pump = pump.clone();
+            Arc::new(move |_| pump.kick())
+        });
+        let _event_callback = self.register_event_callback({
+            let pump = pump.clone();
+            Arc::new(move |event| {
+                if event == (SoundEvent::PeriodElapsed { stream_id }) {
+                    pump.kick();
+                }
+            })
+        });
+
+        let mut period = vec![0u8; pump.period_bytes];
+        let mut filled = false;
+        while !pump.is_stopped() {
+            if !filled && pump.ring.len() >= pump.period_bytes {
+                pump.ring.pop(&mut period);
+                filled = true;
+                pump.space_wait_queue.wake_all();
+            }
+            if filled {
+                match self.pcm_xfer_nb(stream_id, &period) {
+                    Ok(_) => {
+                        filled = false;
+                        continue;
+                    }
+                    Err(VirtioDeviceError::WouldBlock) => {
+                        // Retry right away if a completed transfer freed its period.
+                        let mut tx = self.tx.lock();
+                        let in_flight = tx.token_buf.len();
+                        self.collect_nb_transfers(&mut tx);
+                        if tx.token_buf.len() < in_flight {
+                            continue;
+                        }
+                    }
+                    Err(err) => {
+                        warn!(
+                            "[sound device] the pump of stream {} failed: {:?}",
+                            stream_id, err
+                        );
+                        break;
+                    }
+                }
+            }
+            pump.pump_wait_queue
+                .wait_until(|| pump.kicked.swap(false, Ordering::AcqRel).then_some(()));
+        }
+
+        // Let the writers know, and forget the pump unless it was replaced.
+        pump.stop();
+        let mut pumps = self.pumps.lock();
+        if pumps
+            .get(&stream_id)
+            .is_some_and(|current| Arc::ptr_eq(current, &pump))
+        {
+            pumps.remove(&stream_id);
+        }
+    }
+
+    /// Wait until the device has completed every non-blocking transfer of a stream.
+    fn wait_stream_transfers(&self, stream_id: u32, completion_mode: CompletionMode) {
+        loop {
+            let mut tx = self.tx.lock();
+            self.collect_nb_transfers(&mut tx);
+            let pending: Vec<u16> = tx
+                .token_buf
+                .iter()
+                .filter(|(_, xfer)| xfer.stream_id == stream_id)
+                .map(|(token, _)| *token)
+                .collect();
+            drop(tx);
+            if pending.is_empty() {
+                return;
+            }
+            self.sound_inner.wait_tx_used(completion_mode, |queue| {
+                self.sound_inner.any_nb_completed(queue, &pending)
+            });
+        }
+    }
+
+    /// Pop the non-blocking transfers the device has completed, of any stream.
+    ///
+    /// Only the tokens of non-blocking transfers are popped, so that the
+    /// transfers of pull-mode streams are left to `pull_period`.
+    fn collect_nb_transfers(&self, tx: &mut TxState) {
+        while let Some(token) = tx
+            .token_buf
+            .keys()
+            .copied()
+            .find(|token| self.sound_inner.is_xfer_completed(*token))
+        {
+            self.finish_nb_transfer(tx, token);
+        }
+    }
+
+    /// Forget a completed non-blocking transfer and account for its period.
+    fn finish_nb_transfer(&self, tx: &mut TxState, token: u16) {
+        self.sound_inner.nb_xfers.lock().remove(&token);
+        if let Some(xfer) = tx.token_buf.remove(&token) {
+            let stream_id = xfer.stream_id as usize;
+            self.latency_histograms.lock()[stream_id].record(us_since(xfer.submit_tsc));
+            let status = xfer.read_status();
+            self.stream_clocks.lock()[stream_id]
+                .complete(xfer.period.len(), status.latency_bytes.get());
+            tx.header_pool.push(xfer.header);
+        }
+    }
+
+    /// Transfer the PCM frames of an output stream to the device.
+    ///
+    /// The frames of input streams are captured in the periods submitted when
+    /// they are started instead.
+    ///
+    /// This is a blocking method that will not return until the audio playback is complete.
+    pub fn pcm_xfer(&self, stream_id: u32, frames: &[u8]) -> Result<(), VirtioDeviceError> {
+        // Only the parameters are taken from the control state, so that control
+        // requests can be made during the transfer.
+        let (period_size, nr_periods, completion_mode, frames_buffer) = {
+            let mut control = self.lock_control();
+            if !control.set_up {
+                control.set_up()?;
+                control.set_up = true;
+            }
+            control.check_stream_id(stream_id)?;
+            if !control.pcm_parameters[stream_id as usize].setup {
+                warn!("Please set parameters for a stream before using it!");
+                return Err(VirtioDeviceError::IoError);
+            }
+            if control.is_input_stream(stream_id) {
+                return Err(VirtioDeviceError::InvalidParam);
+            }
+            control.check_transfer(stream_id)?;
+            let params = &control.pcm_parameters[stream_id as usize];
+            (
+                params.period_bytes as usize,
+                (params.buffer_bytes / params.period_bytes) as usize,
+                control.completion_modes[stream_id as usize],
+                control.frames_buffer(stream_id)?,
+            )
+        };
+        let tx = self.tx.lock();
+
+        // 将 frames 字节数组按照 period_size 分割成多个小块
+        let mut remaining_buffers = frames.chunks(period_size).peekable();
+        // 初始化一个 Option 类型的缓冲区数组，存储当前可用的缓冲区
         let mut buffers: [Option<&[u8]>; Self::QUEUE_SIZE as usize] =
             [None; Self::QUEUE_SIZE as usize];
-        // 初始化 VirtIOSndPcmStatus 数组，用于存储每个缓冲区的状态
-        let mut statuses: [VirtioSndPcmStatus; Self::QUEUE_SIZE as usize] =
-            array::from_fn(|_| Default::default());
         // 每个缓冲区的标识符（token），用于标识和管理缓冲区
         let mut tokens = [0; Self::QUEUE_SIZE as usize];
+        // 每个缓冲区提交时的 TSC，用于统计延迟
+        let mut submit_tscs = [0u64; Self::QUEUE_SIZE as usize];
         // 缓冲区的头部与尾部
         let mut head = 0;
         let mut tail = 0;
+        // 正在传输的周期数，以及下一个周期在硬件缓冲区中的位置
+        // 一个周期只有在设备用完之后才会被覆盖
+        let mut in_flight = 0;
+        let mut next_period = 0;
+
+        loop {
+            let mut queue = self.sound_inner.tx_queue.disable_irq().lock();
+            // Queue as many periods as the queue and the hardware buffer have room
+            // for, then notify the device once for the whole batch.
+            let mut batched = false;
+            while queue.available_desc() >= 3 && in_flight < nr_periods {
+                let Some(buffer) = remaining_buffers.next() else {
+                    break;
+                };
+                let resp_slice = status_slice(&tx.status_buffer, stream_id, head);
+                let offset = next_period * period_size;
+                let mut reader = VmReader::from(buffer);
+                let mut writer = frames_buffer
+                    .writer()
+                    .unwrap()
+                    .skip(offset)
+                    .limit(period_size);
+                let len = writer.write(&mut reader);
+                frames_buffer.sync(offset..offset + len).unwrap();
+
+                let pcm_data_slice: DmaStreamSlice<&DmaStream> =
+                    DmaStreamSlice::new(&frames_buffer, offset, len);
+                let header_slice = header_slice(&tx.header_buffer, stream_id);
+                let inputs = [&header_slice, &pcm_data_slice];
+                tokens[head] = queue
+                    .add_dma_buf(inputs.as_slice(), &[&resp_slice])
+                    .unwrap();
+                buffers[head] = Some(buffer);
+                submit_tscs[head] = read_tsc();
+                in_flight += 1;
+                next_period = (next_period + 1) % nr_periods;
+                head = (head + 1) % usize::from(Self::QUEUE_SIZE);
+                batched = true;
+            }
+            if batched && queue.should_notify() && !self.sound_inner.is_paused(stream_id) {
+                queue.notify();
+            }
+            if remaining_buffers.peek().is_none() && head == tail {
+                break;
+            }
+
+            // Harvest every period the device has completed since the last batch.
+            let mut harvested = false;
+            while in_flight > 0 && queue.pop_used_with_token(tokens[tail]).is_ok() {
+                let status = read_xfer_status(&tx.status_buffer, stream_id, tail);
+                if let Err(err) = check_status(status.status.get()) {
+                    // The other periods in flight are claimed, so that their descriptors
+                    // are given back before the error is returned.
+                    drop(queue);
+                    for i in 1..in_flight {
+                        let slot = (tail + i) % usize::from(Self::QUEUE_SIZE);
+                        self.sound_inner
+                            .claim_tx_token(tokens[slot], completion_mode);
+                    }
+                    return Err(err);
+                }
+                self.latency_histograms.lock()[stream_id as usize]
+                    .record(us_since(submit_tscs[tail]));
+                self.stream_clocks.lock()[stream_id as usize].complete(
+                    buffers[tail].map_or(0, <[u8]>::len),
+                    status.latency_bytes.get(),
+                );
+                in_flight -= 1;
+                tail = (tail + 1) % usize::from(Self::QUEUE_SIZE);
+                harvested = true;
+            }
+            if !harvested {
+                // Nothing can be submitted until the device completes a period, or
+                // until the other streams free descriptors if none is in flight.
+                drop(queue);
+                let tail_token = tokens[tail];
+                self.sound_inner.wait_tx_used(completion_mode, |queue| {
+                    if in_flight > 0 {
+                        queue.is_completed(tail_token)
+                    } else {
+                        queue.available_desc() >= 3
+                    }
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Transfer periods of an output stream to the device straight from DMA memory
+    /// owned by the caller, without copying them into the hardware buffer.
+    ///
+    /// Each period is given as one or more segments, e.g., two segments for a
+    /// period that wraps around the end of a ring, which are chained into the
+    /// descriptors of one transfer. The segments must hold at most a period of
+    /// frames in total, and must have been synced to the device.
+    ///
+    /// This is a blocking method that returns once the device has consumed
+    /// every period.
+    pub fn pcm_xfer_sg(
+        &self,
+        stream_id: u32,
+        periods: &[&[DmaStreamSlice<&DmaStream>]],
+    ) -> Result<(), VirtioDeviceError> {
+        let (period_size, nr_periods, completion_mode) = {
+            let mut control = self.lock_control();
+            if !control.set_up {
+                control.set_up()?;
+                control.set_up = true;
+            }
+            control.check_stream_id(stream_id)?;
+            if !control.pcm_parameters[stream_id as usize].setup {
+                warn!("Please set parameters for a stream before using it!");
+                return Err(VirtioDeviceError::IoError);
+            }
+            if control.is_input_stream(stream_id) {
+                return Err(VirtioDeviceError::InvalidParam);
+            }
+            control.check_transfer(stream_id)?;
+            let params = &control.pcm_parameters[stream_id as usize];
+            (
+                params.period_bytes as usize,
+                (params.buffer_bytes / params.period_bytes) as usize,
+                control.completion_modes[stream_id as usize],
+            )
+        };
+        // Besides its segments, a transfer takes a descriptor for the header and
+        // one for the status.
+        for segments in periods {
+            let len: usize = segments.iter().map(|segment| segment.nbytes()).sum();
+            if len == 0 || len > period_size || segments.len() + 2 > Self::QUEUE_SIZE as usize {
+                return Err(VirtioDeviceError::InvalidParam);
+            }
+        }
+        let tx = self.tx.lock();
+
+        let header_slice = header_slice(&tx.header_buffer, stream_id);
+
+        let mut remaining_periods = periods.iter().peekable();
+        // The token, the status slot, the submission TSC and the bytes of each period
+        // in flight, in order.
+        let mut in_flight = VecDeque::new();
+        let mut next_slot = 0;
+        loop {
+            let mut queue = self.sound_inner.tx_queue.disable_irq().lock();
+            let submittable = remaining_periods.peek().filter(|segments| {
+                queue.available_desc() >= segments.len() + 2 && in_flight.len() < nr_periods
+            });
+            if let Some(segments) = submittable {
+                let mut inputs = vec![&header_slice];
+                inputs.extend(segments.iter());
+                let resp_slice = status_slice(&tx.status_buffer, stream_id, next_slot);
+                let token = queue.add_dma_buf(inputs.as_slice(), &[&resp_slice])?;
+                if queue.should_notify() && !self.sound_inner.is_paused(stream_id) {
+                    queue.notify();
+                }
+                let len: usize = segments.iter().map(|segment| segment.nbytes()).sum();
+                in_flight.push_back((token, next_slot, read_tsc(), len));
+                next_slot = (next_slot + 1) % usize::from(Self::QUEUE_SIZE);
+                remaining_periods.next();
+                continue;
+            }
+            let Some(&(token, slot, submit_tsc, len)) = in_flight.front() else {
+                let Some(segments) = remaining_periods.peek() else {
+                    break;
+                };
+                // The descriptors are taken by the transfers of other streams.
+                drop(queue);
+                let nr_descs = segments.len() + 2;
+                self.sound_inner
+                    .wait_tx_used(completion_mode, |queue| queue.available_desc() >= nr_descs);
+                continue;
+            };
+            // A buffer of another stream completing does not mean the front period has.
+            if queue.pop_used_with_token(token).is_err() {
+                // Nothing can be submitted until the device completes a period.
+                drop(queue);
+                self.sound_inner
+                    .wait_tx_used(completion_mode, |queue| queue.is_completed(token));
+                continue;
+            }
+            drop(queue);
+            in_flight.pop_front();
+            let status = read_xfer_status(&tx.status_buffer, stream_id, slot);
+            if let Err(err) = check_status(status.status.get()) {
+                // The segments are the caller's, so they are not given back while the
+                // device may still read the periods in flight.
+                for &(token, ..) in &in_flight {
+                    self.sound_inner.claim_tx_token(token, completion_mode);
+                }
+                return Err(err);
+            }
+            self.latency_histograms.lock()[stream_id as usize].record(us_since(submit_tsc));
+            self.stream_clocks.lock()[stream_id as usize].complete(len, status.latency_bytes.get());
+        }
+
+        Ok(())
+    }
+
+    /// Transfer the PCM frames of an output stream to the device.
+    ///
+    /// The frames of input streams are captured in the periods submitted when
+    /// they are started instead.
+    ///
+    /// This is a non-blocking method that returns a token.
+    ///
+    /// The length of `frames` must be equal to the period size of the stream, or
+    /// [`VirtioDeviceError::InvalidParam`] is returned.
+    ///
+    /// Return [`VirtioDeviceError::WouldBlock`] if the tx queue is full or the next
+    /// period of the stream is still in flight; the caller may retry once a transfer
+    /// has completed, and pace itself with [`Self::tx_space`].
+    pub fn pcm_xfer_nb(&self, stream_id: u32, frames: &[u8]) -> Result<u16, VirtioDeviceError> {
+        let (period_size, nr_periods, frames_buffer, format, channels) = {
+            let mut control = self.lock_control();
+            if !control.set_up {
+                control.set_up()?;
+                control.set_up = true;
+            }
+            control.check_stream_id(stream_id)?;
+            if !control.pcm_parameters[stream_id as usize].setup {
+                warn!("Please set parameters for a stream before using it!");
+                return Err(VirtioDeviceError::IoError);
+            }
+            if control.is_input_stream(stream_id) {
+                return Err(VirtioDeviceError::InvalidParam);
+            }
+            control.check_transfer(stream_id)?;
+            let params = &control.pcm_parameters[stream_id as usize];
+            (
+                params.period_bytes as usize,
+                (params.buffer_bytes / params.period_bytes) as usize,
+                control.frames_buffer(stream_id)?,
+                params.format,
+                params.channels,
+            )
+        };
+        if frames.len() != period_size {
+            return Err(VirtioDeviceError::InvalidParam);
+        }
+        // The silent period is allocated before anything is queued, so that failing
+        // to allocate it loses no transfer.
+        self.sound_inner
+            .prepare_silence_fill(stream_id, period_size, format, channels)?;
+
+        let mut tx = self.tx.lock();
+        let next_period = tx.next_periods.get(&stream_id).copied().unwrap_or(0);
+        let offset = next_period * period_size;
+        // The period is still owned by a transfer the device has not completed.
+        if tx
+            .token_buf
+            .values()
+            .any(|xfer| xfer.stream_id == stream_id && xfer.period.start == offset)
+        {
+            return Err(VirtioDeviceError::WouldBlock);
+        }
+        let mut reader = VmReader::from(frames);
+        let mut writer = frames_buffer
+            .writer()
+            .unwrap()
+            .skip(offset)
+            .limit(period_size);
+        let len = writer.write(&mut reader);
+        frames_buffer.sync(offset..offset + len).unwrap();
+
+        let header = match tx.header_pool.pop() {
+            Some(header) => header,
+            None => XferBuffers::alloc_header()?,
+        };
+        header
+            .write_val(0, &VirtioSndPcmXfer::new(stream_id))
+            .unwrap();
+        header.sync(0..size_of::<VirtioSndPcmXfer>()).unwrap();
+        let xfer = XferBuffers {
+            stream_id,
+            header,
+            frames: frames_buffer,
+            period: offset..offset + period_size,
+            submit_tsc: read_tsc(),
+        };
+        let header_slice = DmaStreamSlice::new(&xfer.header, 0, size_of::<VirtioSndPcmXfer>());
+        let frame_slice = DmaStreamSlice::new(&xfer.frames, offset, period_size);
+        let inputs = vec![&header_slice, &frame_slice];
+        let rsp_slice = xfer.status_slice();
+        let mut queue = self.sound_inner.tx_queue.disable_irq().lock();
+        if queue.available_desc() < inputs.len() + 1 {
+            drop(queue);
+            tx.header_pool.push(xfer.header);
+            return Err(VirtioDeviceError::WouldBlock);
+        }
+        let token = queue.add_dma_buf(inputs.as_slice(), &[&rsp_slice])?;
+        // The token is tracked before the queue is unlocked, so that its completion
+        // cannot be missed by the interrupt handler.
+        self.sound_inner
+            .nb_xfers
+            .lock()
+            .insert(token, (stream_id, false));
+        if queue.should_notify() && !self.sound_inner.is_paused(stream_id) {
+            queue.notify();
+        }
+        drop(queue);
+        // The period is only advanced once it is queued, so that a transfer that
+        // would block is retried on the same period.
+        tx.next_periods
+            .insert(stream_id, (next_period + 1) % nr_periods);
+        tx.token_buf.insert(token, xfer);
+        drop(tx);
+        self.sound_inner.enable_silence_fill(stream_id);
+        Ok(token)
+    }
+
+    /// Transfer the PCM frames of an output stream to the device, returning once
+    /// the last period is queued rather than played.
+    ///
+    /// The length of `frames` must be a multiple of the period size of the stream.
+    /// The periods are queued behind those of the previous call, so a caller can
+    /// prepare its next buffer while the current one is still being played, and
+    /// wait for the playback to end with [`Self::drain_stream`].
+    pub fn pcm_xfer_queued(&self, stream_id: u32, frames: &[u8]) -> Result<(), VirtioDeviceError> {
+        let (period_size, completion_mode) = {
+            let control = self.lock_control();
+            control.check_stream_id(stream_id)?;
+            let params = &control.pcm_parameters[stream_id as usize];
+            (
+                params.period_bytes as usize,
+                control.completion_modes[stream_id as usize],
+            )
+        };
+        if period_size == 0 || frames.len() % period_size != 0 {
+            return Err(VirtioDeviceError::InvalidParam);
+        }
+
+        for period in frames.chunks(period_size) {
+            loop {
+                match self.pcm_xfer_nb(stream_id, period) {
+                    Err(VirtioDeviceError::WouldBlock) => {}
+                    result => {
+                        result?;
+                        break;
+                    }
+                }
+                // The queue is full, or the period is still being played.
+                let pending = {
+                    let mut tx = self.tx.lock();
+                    let in_flight = tx.token_buf.len();
+                    self.collect_nb_transfers(&mut tx);
+                    (tx.token_buf.len() == in_flight)
+                        .then(|| tx.token_buf.keys().copied().collect::<Vec<_>>())
+                };
+                if let Some(pending) = pending {
+                    self.sound_inner.wait_tx_used(completion_mode, |queue| {
+                        self.sound_inner.any_nb_completed(queue, &pending)
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The number of non-blocking transfers the tx queue has room for.
+    ///
+    /// The pull-mode streams and the blocking transfers share the tx queue, so the
+    /// room may be taken by them before a transfer is submitted.
+    pub fn tx_space(&self) -> usize {
+        // A transfer takes a descriptor for its header, its frames and its status.
+        const DESCS_PER_XFER: usize = 3;
+        let available_desc = self
+            .sound_inner
+            .tx_queue
+            .disable_irq()
+            .lock()
+            .available_desc();
+        available_desc / DESCS_PER_XFER
+    }
+
+    /// Check whether the non-blocking transfer of the given token has been completed.
+    ///
+    /// Once it is, the transfer is forgotten and the latency reported by the device
+    /// is returned, in bytes.
+    pub fn pcm_xfer_poll(&self, token: u16) -> Poll<Result<u32, VirtioDeviceError>> {
+        let mut tx = self.tx.lock();
+        let Some(xfer) = tx.token_buf.get(&token) else {
+            return Poll::Ready(Err(VirtioDeviceError::InvalidParam));
+        };
+        if !self.sound_inner.is_xfer_completed(token) {
+            return Poll::Pending;
+        }
+
+        // Each transfer is answered into its own status, which is not overwritten
+        // by the later transfers of the stream.
+        let status = xfer.read_status();
+        self.finish_nb_transfer(&mut tx, token);
+        Poll::Ready(check_status(status.status.get()).map(|_| status.latency_bytes.get()))
+    }
+
+    /// The PCM frame transmission corresponding to the given token has been completed.
+    ///
+    /// Return [`VirtioDeviceError::InvalidState`] if the device has not completed
+    /// it yet.
+    pub fn pcm_xfer_ok(&self, token: u16) -> Result<(), VirtioDeviceError> {
+        match self.pcm_xfer_poll(token) {
+            Poll::Ready(result) => result.map(|_| ()),
+            Poll::Pending => Err(VirtioDeviceError::InvalidState),
+        }
+    }
+
+    /// Register a callback invoked whenever the device completes a non-blocking
+    /// transfer, which can then be collected by [`Self::pcm_xfer_poll`].
+    pub fn register_xfer_callback(&self, callback: Arc<XferCallback>) -> CallbackHandle {
+        let id = self
+            .sound_inner
+            .next_callback_id
+            .fetch_add(1, Ordering::Relaxed);
+        self.sound_inner.xfer_callbacks.write().insert(id, callback);
+
+        let sound_inner = Arc::downgrade(&self.sound_inner);
+        CallbackHandle::new(move || {
+            if let Some(sound_inner) = sound_inner.upgrade() {
+                sound_inner.xfer_callbacks.write().remove(&id);
+            }
+        })
+    }
+
+    /// Play `data` on a free output stream set up with `params`, going through the
+    /// whole PCM command lifecycle, and return once it has been played.
+    ///
+    /// The data is transferred in periods of `params.period_bytes`, the last of
+    /// which is padded with silence. The stream is stopped and released once the
+    /// data has been played, or as soon as playing it fails.
+    pub fn play(&self, data: &[u8], params: &StreamParams) -> Result<(), VirtioDeviceError> {
+        let stream_id = self.open_stream(StreamDirection::Output, params)?;
+        let _stream = PlayingStream {
+            device: self,
+            stream_id,
+        };
+        self.lock_control().pcm_start(stream_id)?;
+
+        let period_bytes = params.period_bytes as usize;
+        if data.len() % period_bytes == 0 {
+            self.pcm_xfer(stream_id, data)?;
+        } else {
+            let mut frames = Vec::with_capacity(data.len().next_multiple_of(period_bytes));
+            frames.extend_from_slice(data);
+            frames.resize(frames.capacity(), 0);
+            fill_silence(params.format, &mut frames[data.len()..]);
+            self.pcm_xfer(stream_id, &frames)?;
+        }
+        self.drain_stream(stream_id)
+    }
+
+    /// Start a free output stream set up with `params`, on which buffers are played
+    /// back to back with [`GaplessPlayback::play`].
+    ///
+    /// Unlike consecutive calls to [`Self::play`], the stream is not stopped and
+    /// started again between the buffers.
+    pub fn open_gapless(
+        &self,
+        params: &StreamParams,
+    ) -> Result<GaplessPlayback<'_>, VirtioDeviceError> {
+        let stream_id = self.open_stream(StreamDirection::Output, params)?;
+        let stream = PlayingStream {
+            device: self,
+            stream_id,
+        };
+        self.lock_control().pcm_start(stream_id)?;
+        Ok(GaplessPlayback {
+            stream,
+            period_bytes: params.period_bytes as usize,
+            format: params.format,
+            pending: Vec::new(),
+        })
+    }
+
+    /// Play one second of a tone on a free output stream, going through the whole
+    /// PCM command lifecycle.
+    ///
+    /// The stream is claimed like any other, so the test fails with
+    /// [`VirtioDeviceError::InvalidParam`] rather than disturb a stream in use.
+    pub fn self_test(&self) -> Result<(), VirtioDeviceError> {
+        const RATE: u32 = 8000;
+        const CHANNELS: u8 = 1;
+        let params = StreamParams {
+            format: SampleFormat::U8,
+            rate: RATE,
+            channels: CHANNELS,
+            buffer_bytes: RATE,
+            period_bytes: RATE / 10,
+        };
+        let stream_id = self.open_stream(StreamDirection::Output, &params)?;
+        let result = self.play_tone(stream_id, &params);
+        self.close_stream(stream_id)?;
+        result
+    }
+
+    fn play_tone(&self, stream_id: u32, params: &StreamParams) -> Result<(), VirtioDeviceError> {
+        let frames = ToneGenerator::new(
+            Waveform::Sine,
+            DEFAULT_TONE_FREQUENCY,
+            params.rate,
+            i16::MAX,
+        )
+        .generate(params.format, params.channels, params.buffer_bytes as usize)
+        .ok_or(VirtioDeviceError::InvalidParam)?;
+        self.lock_control().pcm_start(stream_id)?;
+        self.pcm_xfer(stream_id, &frames)?;
+        self.drain_stream(stream_id)
+    }
+}
+
+pub struct SoundDeviceInner {
+    config_manager: ConfigManager<VirtioSoundConfig>,
+    /// The device-specific features negotiated with the device.
+    features: SoundFeatures,
+    transport: SpinLock<Box<dyn VirtioTransport>>,
+
+    /// 0: The control queue is used for sending control messages from the driver to the device.
+    /// 1: The event queue is used for sending notifications from the device to the driver.
+    /// 2: The tx queue is used to send PCM frames for output streams.
+    /// 3: The rx queue is used to receive PCM frames from input streams.
+    control_queue: SpinLock<VirtQueue>,
+    event_queue: SpinLock<VirtQueue>,
+    tx_queue: SpinLock<VirtQueue>,
+    rx_queue: SpinLock<VirtQueue>,
+    /// Holds the control requests, one slot per request in flight.
+    send_buffer: DmaStream,
+    /// Holds the answers to the control requests, one slot per request in flight.
+    receive_buffer: DmaStream,
+    /// The control requests submitted to the device.
+    control_requests: SpinLock<ControlRequests>,
+    /// The record callbacks, keyed by the ID given at registration.
+    callbacks: RwLock<BTreeMap<usize, Arc<SoundCallback>>, LocalIrqDisabled>,
+    /// The event callbacks, keyed by the ID given at registration.
+    event_callbacks: RwLock<BTreeMap<usize, Arc<EventCallback>>, LocalIrqDisabled>,
+    /// The callbacks of completed non-blocking transfers, keyed by the ID given at
+    /// registration.
+    xfer_callbacks: RwLock<BTreeMap<usize, Arc<XferCallback>>, LocalIrqDisabled>,
+    /// The non-blocking transfers in flight, keyed by their tokens, with their
+    /// streams and whether the device has completed each.
+    nb_xfers: SpinLock<BTreeMap<u16, (u32, bool)>, LocalIrqDisabled>,
+    /// The silence of the output streams fed by non-blocking transfers.
+    silence_fills: SpinLock<BTreeMap<u32, SilenceFill>, LocalIrqDisabled>,
+    next_callback_id: AtomicUsize,
+    /// Whether each jack is connected, updated by the jack events.
+    jack_connected: RwLock<Vec<AtomicBool>, LocalIrqDisabled>,
+    /// The infos of the streams, queried from the device when it is set up.
+    ///
+    /// They are kept out of the control state, so that they can be read while a
+    /// stream is being played.
+    pcm_infos: RwLock<Option<Vec<VirtioSndPcmInfo>>>,
+    /// The infos of the channel maps, queried from the device when it is set up.
+    chmap_infos: RwLock<Option<Vec<VirtioSndChmapInfo>>>,
+    /// The stable ID the device is registered to the component with.
+    stable_id: String,
+    /// The index of the device among the sound devices probed, which names it.
+    index: usize,
+    /// The configuration of the device the driver was last set up for.
+    config: SpinLock<VirtioSoundConfig, LocalIrqDisabled>,
+    /// Whether the configuration changed since the driver was last set up.
+    config_changed: AtomicBool,
+    /// Whether the device is to be reset and set up again by the next control
+    /// operation, because it asked to be or stopped answering control requests.
+    needs_reset: AtomicBool,
+    /// The number of control requests in a row the device has not answered in time.
+    control_timeouts: AtomicUsize,
+    /// The streams paused by `pcm_pause`, whose queued periods the device is not
+    /// notified of.
+    paused: SpinLock<BTreeSet<u32>, LocalIrqDisabled>,
+    /// Woken up when the device returns used buffers on the tx queue.
+    tx_wait_queue: WaitQueue,
+    /// Woken up when the device fills a period on the rx queue, or an input stream
+    /// stops being captured.
+    rx_wait_queue: WaitQueue,
+    /// Woken up when the device answers a request on the control queue.
+    control_wait_queue: WaitQueue,
+    /// The number of requests waiting for their answers, to be woken up by the timer.
+    control_waiters: AtomicUsize,
+    /// The number of captured bytes dropped because the ring of their stream was full.
+    capture_overrun_bytes: AtomicU64,
+    /// Whether the completions are processed by urgent taskless jobs.
+    boost_completions: AtomicBool,
+    /// The record requests submitted by `record_nb` and `pcm_capture_nb`, keyed by
+    /// their tokens.
+    records: SpinLock<BTreeMap<u16, PendingRecord>>,
+    /// Holds the events written by the device, one slot per event queue entry.
+    event_buffer: DmaStream,
+    /// The slot of each event buffer made available to the device, keyed by its token.
+    event_slots: SpinLock<BTreeMap<u16, usize>>,
+    /// The output streams played in pull mode.
+    pull_streams: SpinLock<BTreeMap<u32, PullStream>>,
+    /// The pull-mode streams no longer played, whose buffers are kept until the
+    /// device returns the periods still in flight.
+    retired_pull_streams: SpinLock<Vec<PullStream>>,
+    /// The started input streams, whose periods are captured into their rings.
+    capture_streams: SpinLock<BTreeMap<u32, CaptureStream>>,
+    /// The streams that underran or overran, whose transfers fail until they are
+    /// recovered.
+    xruns: SpinLock<BTreeSet<u32>, LocalIrqDisabled>,
+}
+
+/// The control requests submitted to the device, matched with their answers by token.
+///
+/// Each request in flight owns a slot of the send buffer and a slot of the
+/// receive buffer, until its answer is collected. The slots of a request given
+/// up on stay owned until the device answers it late.
+#[derive(Debug, Default)]
+struct ControlRequests {
+    /// The slot of each request in flight, keyed by its token.
+    slots: BTreeMap<u16, usize>,
+    /// The bytes written by the device for the requests it has answered, whose
+    /// answers have not been collected yet.
+    answered: BTreeMap<u16, usize>,
+    /// The requests given up on before the device answered them.
+    abandoned: BTreeSet<u16>,
+}
+
+impl ControlRequests {
+    fn free_slot(&self) -> Option<usize> {
+        (0..SoundDeviceInner::NR_CONTROL_SLOTS).find(|slot| !self.slots.values().any(|s| s == slot))
+    }
+}
+
+/// An output stream whose periods are filled by a playback callback.
+struct PullStream {
+    callback: Arc<PlaybackCallback>,
+    period_bytes: usize,
+    /// The period buffers, each holding the `virtio_snd_pcm_xfer` header, the frames
+    /// of a period and the `virtio_snd_pcm_status`.
+    buffers: Vec<DmaStream>,
+    /// The tokens and buffer indexes of the periods submitted to the tx queue, in order.
+    in_flight: VecDeque<(u16, usize)>,
+}
+
+impl PullStream {
+    const NR_BUFFERS: usize = 2;
+    const FRAMES_OFFSET: usize = size_of::<VirtioSndPcmXfer>();
+}
+
+impl Debug for PullStream {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PullStream")
+            .field("period_bytes", &self.period_bytes)
+            .field("in_flight", &self.in_flight)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The silence played on an output stream fed by non-blocking transfers while
+/// none of its periods is queued, so that the device does not starve.
+struct SilenceFill {
+    period_bytes: usize,
+    frame_bytes: usize,
+    /// Holds the `virtio_snd_pcm_xfer` header, a period of silence and the
+    /// `virtio_snd_pcm_status`.
+    buffer: DmaStream,
+    /// The token of the silent period submitted to the tx queue, if any.
+    in_flight: Option<u16>,
+    /// Whether the stream is started, so that silence is played when it runs dry.
+    active: bool,
+    /// The number of silent bytes the device has played.
+    inserted_bytes: u64,
+}
+
+impl SilenceFill {
+    const FRAMES_OFFSET: usize = size_of::<VirtioSndPcmXfer>();
+
+    /// Submit the period of silence to the tx queue.
+    fn submit(&mut self, queue: &mut VirtQueue) {
+        let header = DmaStreamSlice::new(&self.buffer, 0, Self::FRAMES_OFFSET);
+        let frames = DmaStreamSlice::new(&self.buffer, Self::FRAMES_OFFSET, self.period_bytes);
+        let status = DmaStreamSlice::new(
+            &self.buffer,
+            Self::FRAMES_OFFSET + self.period_bytes,
+            size_of::<VirtioSndPcmStatus>(),
+        );
+        // A full queue holds periods that keep the device busy anyway.
+        if let Ok(token) = queue.add_dma_buf(&[&header, &frames], &[&status]) {
+            if queue.should_notify() {
+                queue.notify();
+            }
+            self.in_flight = Some(token);
+        }
+    }
+}
+
+impl Debug for SilenceFill {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SilenceFill")
+            .field("period_bytes", &self.period_bytes)
+            .field("in_flight", &self.in_flight)
+            .field("active", &self.active)
+            .field("inserted_bytes", &self.inserted_bytes)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An output stream whose periods are fed to the device by a pump thread, from
+/// the frames written to its ring.
+struct PumpStream {
+    period_bytes: usize,
+    /// The frames written to the stream and not fed to the device yet.
+    ring: CaptureRing,
+    /// Serializes the writers, since the ring has a single producer.
+    writer: Mutex<()>,
+    /// Whether the pump has something to do, set before it is woken up.
+    kicked: AtomicBool,
+    /// Set to make the pump thread exit.
+    stopped: AtomicBool,
+    /// Woken up to run the pump.
+    pump_wait_queue: WaitQueue,
+    /// Woken up when the pump frees room in the ring, or stops.
+    space_wait_queue: WaitQueue,
+}
+
+impl PumpStream {
+    fn kick(&self) {
+        self.kicked.store(true, Ordering::Release);
+        self.pump_wait_queue.wake_all();
+    }
+
+    fn stop(&self) {
+        self.stopped.store(true, Ordering::Release);
+        self.kick();
+        self.space_wait_queue.wake_all();
+    }
+
+    fn is_stopped(&self) -> bool {
+        self.stopped.load(Ordering::Acquire)
+    }
+}
+
+impl Debug for PumpStream {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PumpStream")
+            .field("period_bytes", &self.period_bytes)
+            .field("buffered", &self.ring.len())
+            .field("stopped", &self.stopped)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An input stream whose periods are submitted to the rx queue again as soon
+/// as the device has filled them.
+struct CaptureStream {
+    period_bytes: usize,
+    /// The period buffers, each holding the `virtio_snd_pcm_xfer` header, the frames
+    /// of a period and the `virtio_snd_pcm_status`.
+    buffers: Vec<DmaStream>,
+    /// The frames captured on the stream that have not been read yet.
+    ring: Arc<CaptureRing>,
+    /// The buffer index of each period submitted to the rx queue, keyed by its token.
+    in_flight: BTreeMap<u16, usize>,
+}
+
+impl CaptureStream {
+    const NR_BUFFERS: usize = 4;
+    const FRAMES_OFFSET: usize = size_of::<VirtioSndPcmXfer>();
+}
+
+impl Debug for CaptureStream {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CaptureStream")
+            .field("period_bytes", &self.period_bytes)
+            .field("in_flight", &self.in_flight)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Tracks the position of a stream from the transfers completed by the device.
+#[derive(Debug, Default, Clone, Copy)]
+struct StreamClock {
+    /// The bytes of frames of the completed transfers.
+    transferred_bytes: u64,
+    /// The bytes the device still had buffered at the last completion.
+    latency_bytes: u32,
+    /// The monotonic time of the last completion.
+    timestamp: Duration,
+}
+
+impl StreamClock {
+    fn complete(&mut self, bytes: usize, latency_bytes: u32) {
+        self.transferred_bytes += bytes as u64;
+        self.latency_bytes = latency_bytes;
+        self.timestamp = read_monotonic_time();
+    }
+}
+
+/// A record request submitted to the rx queue.
+#[derive(Debug)]
+struct PendingRecord {
+    stream_id: u32,
+    /// Holds the `virtio_snd_pcm_xfer` header read by the device.
+    ///
+    /// It is only kept alive until the device completes the request.
+    #[allow(unused)]
+    header: DmaStream,
+    /// Holds the recorded frames followed by the `virtio_snd_pcm_status`.
+    frames: DmaStream,
+    /// The number of bytes of frames requested.
+    len: usize,
+    /// The number of bytes written by the device, once the request is completed.
+    used_len: Option<u32>,
+}
+
+impl PowerManaged for SoundDevice {
+    fn suspend(&self) -> Result<(), VirtioDeviceError> {
+        SoundDevice::suspend(self)
+    }
+
+    fn resume(&self) -> Result<(), VirtioDeviceError> {
+        SoundDevice::resume(self)
+    }
+}
+
+impl AnySoundDevice for SoundDevice {
+    fn self_test(&self) -> Result<(), SoundError> {
+        Ok(SoundDevice::self_test(self)?)
+    }
+
+    fn register_event_callback(&self, callback: Arc<EventCallback>) -> CallbackHandle {
+        let id = self
+            .sound_inner
+            .next_callback_id
+            .fetch_add(1, Ordering::Relaxed);
+        self.sound_inner
+            .event_callbacks
+            .write()
+            .insert(id, callback);
+
+        let sound_inner = Arc::downgrade(&self.sound_inner);
+        CallbackHandle::new(move || {
+            if let Some(sound_inner) = sound_inner.upgrade() {
+                sound_inner.event_callbacks.write().remove(&id);
+            }
+        })
+    }
+
+    fn capabilities(&self) -> Result<Vec<StreamCapability>, SoundError> {
+        Ok(self.lock_control().capabilities()?)
+    }
+
+    fn capabilities_snapshot(&self) -> Result<CapabilitiesSnapshot, SoundError> {
+        Ok(self.lock_control().capabilities_snapshot()?)
+    }
+
+    fn invalidate_capabilities(&self) -> Result<(), SoundError> {
+        Ok(self.lock_control().invalidate_capabilities()?)
+    }
+
+    fn set_completion_mode(&self, stream_id: u32, mode: CompletionMode) -> Result<(), SoundError> {
+        Ok(self.lock_control().set_completion_mode(stream_id, mode)?)
+    }
+
+    fn set_completion_priority(&self, priority: CompletionPriority) {
+        SoundDevice::set_completion_priority(self, priority);
+    }
+
+    fn set_dma_quota(&self, bytes: usize) {
+        self.lock_control().set_dma_quota(bytes);
+    }
+
+    fn jack_states(&self) -> Result<Vec<JackState>, SoundError> {
+        Ok(self.lock_control().jack_states()?)
+    }
+
+    fn set_jack_auto_pause(&self, enabled: bool) {
+        self.lock_control().set_jack_auto_pause(enabled);
+    }
+
+    fn latency_histogram(&self, stream_id: u32) -> Option<LatencyHistogram> {
+        SoundDevice::latency_histogram(self, stream_id)
+    }
+
+    fn open_stream(
+        &self,
+        direction: StreamDirection,
+        params: &StreamParams,
+    ) -> Result<u32, SoundError> {
+        Ok(SoundDevice::open_stream(self, direction, params)?)
+    }
+
+    fn start_stream(&self, stream_id: u32) -> Result<(), SoundError> {
+        let mut control = self.lock_control();
+        control.check_stream_enabled(stream_id)?;
+        Ok(control.pcm_start(stream_id)?)
+    }
+
+    fn stop_stream(&self, stream_id: u32) -> Result<(), SoundError> {
+        Ok(self.lock_control().pcm_stop(stream_id)?)
+    }
+
+    fn stream_latency(&self, stream_id: u32) -> Result<Duration, SoundError> {
+        Ok(SoundDevice::stream_latency(self, stream_id)?)
+    }
+
+    fn channel_map(&self, stream_id: u32) -> Result<Vec<u8>, SoundError> {
+        Ok(SoundDevice::channel_map(self, stream_id)?)
+    }
+
+    fn stream_position(&self, stream_id: u32) -> Result<StreamPosition, SoundError> {
+        Ok(SoundDevice::stream_position(self, stream_id)?)
+    }
+
+    fn drain_stream(&self, stream_id: u32) -> Result<(), SoundError> {
+        Ok(SoundDevice::drain_stream(self, stream_id)?)
+    }
+
+    fn close_stream(&self, stream_id: u32) -> Result<(), SoundError> {
+        Ok(SoundDevice::close_stream(self, stream_id)?)
+    }
+
+    fn disable_stream(&self, stream_id: u32) -> Result<(), SoundError> {
+        Ok(SoundDevice::disable_stream(self, stream_id)?)
+    }
+
+    fn enable_stream(&self, stream_id: u32) -> Result<(), SoundError> {
+        Ok(self.lock_control().enable_stream(stream_id)?)
+    }
+
+    fn suspend(&self) -> Result<(), SoundError> {
+        Ok(SoundDevice::suspend(self)?)
+    }
+
+    fn resume(&self) -> Result<(), SoundError> {
+        Ok(SoundDevice::resume(self)?)
+    }
+}
+
+impl AudioOutput for SoundDevice {
+    fn register_playback_callback(
+        &self,
+        stream_id: u32,
+        callback: Arc<PlaybackCallback>,
+    ) -> Result<CallbackHandle, SoundError> {
+        Ok(SoundDevice::register_playback_callback(
+            self, stream_id, callback,
+        )?)
+    }
+
+    fn write_stream(&self, stream_id: u32, frames: &[u8]) -> Result<usize, SoundError> {
+        self.lock_control().check_stream_enabled(stream_id)?;
+        if self.pumps.lock().contains_key(&stream_id) {
+            return Ok(self.pump_write(stream_id, frames)?);
+        }
+        self.pcm_xfer(stream_id, frames)?;
+        Ok(frames.len())
+    }
+
+    fn play(&self, data: &[u8], params: &StreamParams) -> Result<(), SoundError> {
+        Ok(SoundDevice::play(self, data, params)?)
+    }
+
+    fn silence_frames(&self, stream_id: u32) -> Frames {
+        SoundDevice::silence_frames(self, stream_id)
+    }
+}
+
+impl AudioInput for SoundDevice {
+    fn register_callback(&self, callback: Arc<SoundCallback>) -> CallbackHandle {
+        let id = self
+            .sound_inner
+            .next_callback_id
+            .fetch_add(1, Ordering::Relaxed);
+        self.sound_inner.callbacks.write().insert(id, callback);
+
+        let sound_inner = Arc::downgrade(&self.sound_inner);
+        CallbackHandle::new(move || {
+            if let Some(sound_inner) = sound_inner.upgrade() {
+                sound_inner.callbacks.write().remove(&id);
+            }
+        })
+    }
+
+    fn capture_overrun_bytes(&self) -> u64 {
+        self.sound_inner
+            .capture_overrun_bytes
+            .load(Ordering::Relaxed)
+    }
+
+    fn read_stream(&self, stream_id: u32, frames: &mut [u8]) -> Result<usize, SoundError> {
+        Ok(SoundDevice::record(self, stream_id, frames)?)
+    }
+
+    fn record_nb(&self, stream_id: u32, len: usize) -> Result<RecordToken, SoundError> {
+        let token = SoundDevice::record_nb(self, stream_id, len)?;
+        Ok(RecordToken(token as u32))
+    }
+
+    fn record_poll(
+        &self,
+        token: RecordToken,
+        frames: &mut [u8],
+    ) -> Option<Result<usize, SoundError>> {
+        let result = SoundDevice::record_poll(self, token.0 as u16, frames)?;
+        Some(result.map_err(SoundError::from))
+    }
+}
+
+/// Allocates a DMA buffer of at least `nbytes` bytes for the frames sent to the device.
+///
+/// The buffer is a single contiguous segment of as many pages as needed, so a
+/// period larger than a page is still handed to the device in one descriptor.
+///
+/// Fails with [`VirtioDeviceError::InvalidParam`] if the memory cannot be allocated.
+fn alloc_frames_buffer(nbytes: usize) -> Result<DmaStream, VirtioDeviceError> {
+    let segment = FrameAllocOptions::new()
+        .alloc_segment(nbytes.div_ceil(PAGE_SIZE))
+        .map_err(|_| VirtioDeviceError::InvalidParam)?;
+    DmaStream::map(segment.into(), DmaDirection::ToDevice, false)
+        .map_err(|_| VirtioDeviceError::InvalidParam)
+}
+
+/// Computes the bytes the device answers a control request with: the status,
+/// followed by the items of an item information request.
+fn answer_len(req: &[u8]) -> usize {
+    const QUERY_INFO_SIZE: usize = size_of::<VirtioSndQueryInfo>();
+    let hdr = VirtioSndHdr::from_bytes(&req[..SND_HDR_SIZE]);
+    let is_query = matches!(
+        CommandCode::try_from(hdr.code.get()),
+        Ok(CommandCode::RJackInfo | CommandCode::RPcmInfo | CommandCode::RChmapInfo)
+    );
+    if !is_query || req.len() < QUERY_INFO_SIZE {
+        return SND_HDR_SIZE;
+    }
+    let query = VirtioSndQueryInfo::from_bytes(&req[..QUERY_INFO_SIZE]);
+    SND_HDR_SIZE + query.count.get() as usize * query.size.get() as usize
+}
+
+/// Maps the status code of an answer or a transfer to its result.
+fn check_status(code: u32) -> Result<(), VirtioDeviceError> {
+    match RequestStatusCode::try_from(code) {
+        Ok(RequestStatusCode::Ok) => Ok(()),
+        Ok(RequestStatusCode::BadMsg) => Err(VirtioDeviceError::BadMessage),
+        Ok(RequestStatusCode::NotSupp) => Err(VirtioDeviceError::Unsupported),
+        Ok(RequestStatusCode::IoErr) => Err(VirtioDeviceError::IoError),
+        Err(code) => {
+            warn!("[sound device] unknown status code {:#x}", code);
+            Err(VirtioDeviceError::IoError)
+        }
+    }
+}
+
+/// Allocates a buffer with the `virtio_snd_pcm_xfer` header of each of `streams`
+/// streams, in one slot per stream.
+///
+/// The headers never change, so they are written once and shared by all the
+/// blocking transfers of a stream.
+fn alloc_header_buffer(streams: usize) -> Result<DmaStream, VirtioDeviceError> {
+    let header_size = size_of::<VirtioSndPcmXfer>();
+    let nbytes = streams * header_size;
+    let segment = FrameAllocOptions::new()
+        .alloc_segment(nbytes.div_ceil(PAGE_SIZE).max(1))
+        .map_err(|_| VirtioDeviceError::DmaError)?;
+    let header_buffer = DmaStream::map(segment.into(), DmaDirection::ToDevice, false)
+        .map_err(|_| VirtioDeviceError::DmaError)?;
+    for stream_id in 0..streams {
+        header_buffer
+            .write_val(
+                stream_id * header_size,
+                &VirtioSndPcmXfer::new(stream_id as u32),
+            )
+            .unwrap();
+    }
+    header_buffer.sync(0..nbytes).unwrap();
+    Ok(header_buffer)
+}
+
+/// Allocates a buffer with [`SoundDevice::QUEUE_SIZE`] `virtio_snd_pcm_status`
+/// slots for each of `streams` streams, written by the device.
+fn alloc_status_buffer(streams: usize) -> Result<DmaStream, VirtioDeviceError> {
+    let nbytes = streams * SoundDevice::QUEUE_SIZE as usize * size_of::<VirtioSndPcmStatus>();
+    let segment = FrameAllocOptions::new()
+        .alloc_segment(nbytes.div_ceil(PAGE_SIZE).max(1))
+        .map_err(|_| VirtioDeviceError::DmaError)?;
+    DmaStream::map(segment.into(), DmaDirection::FromDevice, false)
+        .map_err(|_| VirtioDeviceError::DmaError)
+}
+
+/// Allocates a DMA buffer of at least `size` bytes, e.g., for the periods of a stream.
+fn alloc_dma_stream(size: usize, direction: DmaDirection) -> Result<DmaStream, VirtioDeviceError> {
+    let segment = FrameAllocOptions::new()
+        .alloc_segment(size.div_ceil(PAGE_SIZE).max(1))
+        .map_err(|_| VirtioDeviceError::DmaError)?;
+    DmaStream::map(segment.into(), direction, false).map_err(|_| VirtioDeviceError::DmaError)
+}
+
+/// Returns the slot of `header_buffer` holding the header of a stream.
+fn header_slice(header_buffer: &DmaStream, stream_id: u32) -> DmaStreamSlice<&DmaStream> {
+    let header_size = size_of::<VirtioSndPcmXfer>();
+    DmaStreamSlice::new(header_buffer, stream_id as usize * header_size, header_size)
+}
+
+/// Returns the slot of `status_buffer` a blocking transfer of a stream gets its status in.
+///
+/// The device may complete the transfers of a stream out of order, so each transfer
+/// in flight gets a `slot` of its own, below the queue size.
+fn status_slice(
+    status_buffer: &DmaStream,
+    stream_id: u32,
+    slot: usize,
+) -> DmaStreamSlice<&DmaStream> {
+    let status_size = size_of::<VirtioSndPcmStatus>();
+    DmaStreamSlice::new(status_buffer, status_offset(stream_id, slot), status_size)
+}
+
+/// Reads the status the device wrote for the transfer of a stream in `slot`.
+fn read_xfer_status(status_buffer: &DmaStream, stream_id: u32, slot: usize) -> VirtioSndPcmStatus {
+    let status_size = size_of::<VirtioSndPcmStatus>();
+    let offset = status_offset(stream_id, slot);
+    status_buffer.sync(offset..offset + status_size).unwrap();
+    status_buffer.read_val(offset).unwrap()
+}
+
+/// Returns the offset of a status slot of a stream in the status buffer.
+fn status_offset(stream_id: u32, slot: usize) -> usize {
+    debug_assert!(slot < SoundDevice::QUEUE_SIZE as usize);
+    (stream_id as usize * SoundDevice::QUEUE_SIZE as usize + slot) * size_of::<VirtioSndPcmStatus>()
+}
+
+/// Returns the microseconds elapsed since the TSC read `tsc`.
+fn us_since(tsc: u64) -> u64 {
+    read_tsc().saturating_sub(tsc) * 1_000_000 / tsc_freq().max(1)
+}
+
+impl From<VirtioDeviceError> for SoundError {
+    fn from(error: VirtioDeviceError) -> Self {
+        match error {
+            VirtioDeviceError::InvalidParam => SoundError::InvalidParam,
+            VirtioDeviceError::QuotaExceeded => SoundError::QuotaExceeded,
+            VirtioDeviceError::StreamDisabled => SoundError::NotReady,
+            VirtioDeviceError::CaptureBlocked => SoundError::CaptureBlocked,
+            VirtioDeviceError::Suspended => SoundError::NotReady,
+            VirtioDeviceError::InvalidState => SoundError::NotReady,
+            VirtioDeviceError::BadMessage => SoundError::InvalidParam,
+            VirtioDeviceError::Unsupported => SoundError::Unsupported,
+            VirtioDeviceError::Xrun => SoundError::Xrun,
+            VirtioDeviceError::WouldBlock => SoundError::WouldBlock,
+            _ => SoundError::IoError,
+        }
+    }
+}
+
+impl Debug for SoundDeviceInner {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SoundDeviceInner")
+            .field("config", &self.read_config())
+            .field("features", &self.features)
+            .field("transport", &self.transport)
+            .field("control_queue", &self.control_queue)
+            .field("event_queue", &self.event_queue)
+            .field("tx_queue", &self.tx_queue)
+            .field("rx_queue", &self.rx_queue)
+            .field("send_buffer", &self.send_buffer)
+            .field("receive_buffer", &self.receive_buffer)
+            .field("control_requests", &self.control_requests)
+            .field("capture_overrun_bytes", &self.capture_overrun_bytes)
+            .field("boost_completions", &self.boost_completions)
+            .field("jack_connected", &self.jack_connected)
+            .field("records", &self.records)
+            .field("event_buffer", &self.event_buffer)
+            .field("pull_streams", &self.pull_streams)
+            .field("retired_pull_streams", &self.retired_pull_streams)
+            .field("capture_streams", &self.capture_streams)
+            .finish()
+    }
+}
+impl SoundDeviceInner {
+    const CONTROLQ_INDEX: u16 = 0;
+    const EVENTQ_INDEX: u16 = 1;
+    const TXQ_INDEX: u16 = 2;
+    const RXQ_INDEX: u16 = 3;
+    const QUEUE_SIZE: u16 = 16;
+    const CAPTURE_RING_SIZE: usize = 64 * 1024;
+    /// The number of control requests in a row that may time out before the device
+    /// is considered wedged and is reset.
+    const MAX_CONTROL_TIMEOUTS: usize = 3;
+    /// The number of control requests that can be in flight at once, each taking
+    /// two descriptors of the control queue.
+    const NR_CONTROL_SLOTS: usize = Self::QUEUE_SIZE as usize / 2;
+    /// The bytes of the send buffer held by each control request.
+    const REQUEST_SLOT_SIZE: usize = PAGE_SIZE / Self::NR_CONTROL_SLOTS;
+    /// The bytes of the receive buffer held by the answer to each control request.
+    const ANSWER_SLOT_SIZE: usize = PAGE_SIZE;
+
+    /// Reads the configuration of the device, with the fields the negotiated
+    /// features make valid.
+    fn read_config(&self) -> VirtioSoundConfig {
+        self.config_manager.read_config(self.features)
+    }
+
+    pub fn set(mut transport: Box<dyn VirtioTransport>) -> Result<Arc<Self>, VirtioDeviceError> {
+        let stable_id = transport.location();
+        let config_manager = VirtioSoundConfig::new_manager(transport.as_ref());
+
+        let features = SoundFeatures::from_bits_truncate(SoundDevice::negotiate_features(
+            transport.read_device_features(),
+        ));
+        debug!("[sound device] features = {:?}", features);
+        let sound_config = config_manager.read_config(features);
+
+        let control_queue = SpinLock::new(
+            VirtQueue::new(Self::CONTROLQ_INDEX, Self::QUEUE_SIZE, transport.as_mut()).unwrap(),
+        );
+        let event_queue = SpinLock::new(
+            VirtQueue::new(Self::EVENTQ_INDEX, Self::QUEUE_SIZE, transport.as_mut()).unwrap(),
+        );
+        let tx_queue = SpinLock::new(
+            VirtQueue::new(Self::TXQ_INDEX, Self::QUEUE_SIZE, transport.as_mut()).unwrap(),
+        );
+        let rx_queue = SpinLock::new(
+            VirtQueue::new(Self::RXQ_INDEX, Self::QUEUE_SIZE, transport.as_mut()).unwrap(),
+        );
+        let send_buffer = {
+            let segment = FrameAllocOptions::new().alloc_segment(1).unwrap();
+            DmaStream::map(segment.into(), DmaDirection::ToDevice, false).unwrap()
+        };
+
+        let receive_buffer = {
+            let segment = FrameAllocOptions::new()
+                .alloc_segment(Self::NR_CONTROL_SLOTS * Self::ANSWER_SLOT_SIZE / PAGE_SIZE)
+                .unwrap();
+            DmaStream::map(segment.into(), DmaDirection::FromDevice, false).unwrap()
+        };
+
+        // One event slot for each entry of the event queue.
+        let event_buffer = {
+            let nbytes = usize::from(Self::QUEUE_SIZE) * size_of::<VirtioSndEvent>();
+            let segment = FrameAllocOptions::new()
+                .alloc_segment(nbytes.div_ceil(PAGE_SIZE))
+                .unwrap();
+            DmaStream::map(segment.into(), DmaDirection::FromDevice, false).unwrap()
+        };
+
+        let device = Arc::new(SoundDeviceInner {
+            config_manager,
+            features,
+            transport: SpinLock::new(transport),
+            control_queue,
+            event_queue,
+            tx_queue,
+            rx_queue,
+            send_buffer,
+            receive_buffer,
+            control_requests: SpinLock::new(ControlRequests::default()),
+            callbacks: RwLock::new(BTreeMap::new()),
+            event_callbacks: RwLock::new(BTreeMap::new()),
+            xfer_callbacks: RwLock::new(BTreeMap::new()),
+            nb_xfers: SpinLock::new(BTreeMap::new()),
+            silence_fills: SpinLock::new(BTreeMap::new()),
+            next_callback_id: AtomicUsize::new(0),
+            jack_connected: RwLock::new(
+                (0..sound_config.jacks)
+                    .map(|_| AtomicBool::new(false))
+                    .collect(),
+            ),
+            pcm_infos: RwLock::new(None),
+            chmap_infos: RwLock::new(None),
+            stable_id,
+            index: NEXT_DEVICE_INDEX.fetch_add(1, Ordering::Relaxed),
+            config: SpinLock::new(sound_config),
+            config_changed: AtomicBool::new(false),
+            needs_reset: AtomicBool::new(false),
+            control_timeouts: AtomicUsize::new(0),
+            paused: SpinLock::new(BTreeSet::new()),
+            tx_wait_queue: WaitQueue::new(),
+            rx_wait_queue: WaitQueue::new(),
+            control_wait_queue: WaitQueue::new(),
+            control_waiters: AtomicUsize::new(0),
+            capture_overrun_bytes: AtomicU64::new(0),
+            boost_completions: AtomicBool::new(false),
+            records: SpinLock::new(BTreeMap::new()),
+            event_buffer,
+            event_slots: SpinLock::new(BTreeMap::new()),
+            pull_streams: SpinLock::new(BTreeMap::new()),
+            retired_pull_streams: SpinLock::new(Vec::new()),
+            capture_streams: SpinLock::new(BTreeMap::new()),
+            xruns: SpinLock::new(BTreeSet::new()),
+        });
+        device.activate_event_buffers();
+
+        // Let a request waiting on the control queue check its timeout on every tick.
+        {
+            let device = Arc::downgrade(&device);
+            timer::register_callback(move || {
+                let Some(device) = device.upgrade() else {
+                    return;
+                };
+                if device.control_waiters.load(Ordering::Relaxed) > 0 {
+                    device.control_wait_queue.wake_all();
+                }
+            });
+        }
+
+        // Register irq callbacks
+        let mut transport = device.transport.disable_irq().lock();
+        // The completions of each data queue are processed out of its interrupt handler.
+        let rx_completion_work = {
+            let device = device.clone();
+            Taskless::new(move || device.process_rx_completions())
+        };
+        let tx_completion_work = {
+            let device = device.clone();
+            Taskless::new(move || device.process_tx_completions())
+        };
+        let handle_sound_input = {
+            let device = device.clone();
+            move |_: &TrapFrame| device.schedule_completions(&rx_completion_work)
+        };
+        let handle_sound_output = {
+            let device = device.clone();
+            move |_: &TrapFrame| device.schedule_completions(&tx_completion_work)
+        };
+        let handle_event = {
+            let device = device.clone();
+            move |_: &TrapFrame| device.handle_event_irq()
+        };
+        let handle_control = {
+            let device = device.clone();
+            move |_: &TrapFrame| {
+                device.control_wait_queue.wake_all();
+            }
+        };
+        transport
+            .register_queue_callback(Self::CONTROLQ_INDEX, Box::new(handle_control), false)
+            .unwrap();
+        transport
+            .register_queue_callback(Self::RXQ_INDEX, Box::new(handle_sound_input), false)
+            .unwrap();
+        transport
+            .register_queue_callback(Self::EVENTQ_INDEX, Box::new(handle_event), false)
+            .unwrap();
+        transport
+            .register_queue_callback(Self::TXQ_INDEX, Box::new(handle_sound_output), false)
+            .unwrap();
+        let handle_config_change = {
+            let device = device.clone();
+            move |_: &TrapFrame| device.handle_config_change()
+        };
+        transport
+            .register_cfg_callback(Box::new(handle_config_change))
+            .unwrap();
+        transport.finish_init();
+        info!("[sound device] loaded: {}", sound_config);
+        drop(transport);
+
+        Ok(device)
+    }
+
+    /// Make every event slot available to the device.
+    fn activate_event_buffers(&self) {
+        let mut event_queue = self.event_queue.disable_irq().lock();
+        for slot in 0..usize::from(Self::QUEUE_SIZE) {
+            self.activate_event_slot(&mut event_queue, slot);
+        }
+        if event_queue.should_notify() {
+            event_queue.notify();
+        }
+    }
+
+    /// Make an event slot available to the device again.
+    fn activate_event_slot(&self, event_queue: &mut VirtQueue, slot: usize) {
+        const EVENT_SIZE: usize = size_of::<VirtioSndEvent>();
+        let slot_slice = DmaStreamSlice::new(&self.event_buffer, slot * EVENT_SIZE, EVENT_SIZE);
+        let Ok(token) = event_queue.add_dma_buf(&[], &[&slot_slice]) else {
+            warn!(
+                "[sound device] event queue is full, dropping event slot {}",
+                slot
+            );
+            return;
+        };
+        self.event_slots.disable_irq().lock().insert(token, slot);
+    }
+
+    fn handle_event_irq(&self) {
+        let mut event_queue = self.event_queue.disable_irq().lock();
+        while let Ok((token, len)) = event_queue.pop_used() {
+            let Some(slot) = self.event_slots.disable_irq().lock().remove(&token) else {
+                continue;
+            };
+            let offset = slot * size_of::<VirtioSndEvent>();
+            self.event_buffer
+                .sync(offset..offset + size_of::<VirtioSndEvent>())
+                .unwrap();
+            let event: VirtioSndEvent = self.event_buffer.read_val(offset).unwrap();
+            // The slot is no longer needed once the event is read.
+            self.activate_event_slot(&mut event_queue, slot);
+            if (len as usize) < size_of::<VirtioSndEvent>() {
+                warn!("[sound device] dropped a truncated event of {} bytes", len);
+                continue;
+            }
+            self.dispatch_notification(&Notification::from(event));
+        }
+        if event_queue.should_notify() {
+            event_queue.notify();
+        }
+    }
+
+    /// Act on a notification of the device, then let the event callbacks know.
+    fn dispatch_notification(&self, notification: &Notification) {
+        let data = notification.data();
+        match notification.notification_type() {
+            NotificationType::PcmPeriodElapsed => {
+                self.pull_period(data);
+                self.report_event(SoundEvent::PeriodElapsed { stream_id: data });
+            }
+            NotificationType::PcmXrun => self.report_xrun(data),
+            NotificationType::JackConnected => self.report_jack(data, true),
+            NotificationType::JackDisconnected => self.report_jack(data, false),
+            NotificationType::Unknown(code) => debug!(
+                "[sound device] unhandled event {:#x} with data {:#x}",
+                code, data
+            ),
+        }
+    }
+
+    /// Mark a stream that has underrun or overrun as needing recovery, and let the
+    /// event callbacks know.
+    fn report_xrun(&self, stream_id: u32) {
+        warn!("[sound device] xrun on stream {}", stream_id);
+        self.xruns.lock().insert(stream_id);
+        self.report_event(SoundEvent::Xrun(XrunEvent { stream_id }));
+    }
+
+    /// Record that a jack got connected or disconnected, and let the event
+    /// callbacks know.
+    fn report_jack(&self, jack_id: u32, connected: bool) {
+        let jacks_connected = self.jack_connected.read();
+        let Some(jack_connected) = jacks_connected.get(jack_id as usize) else {
+            warn!("[sound device] event for unknown jack {}", jack_id);
+            return;
+        };
+        jack_connected.store(connected, Ordering::Relaxed);
+        drop(jacks_connected);
+        self.report_event(SoundEvent::Jack(JackState { jack_id, connected }));
+    }
+
+    /// Note that the configuration of the device changed, to be acted on by the
+    /// next control operation, and let the event callbacks and the component know.
+    fn handle_config_change(&self) {
+        // The device also raises a configuration change when it needs to be reset.
+        if self
+            .transport
+            .disable_irq()
+            .lock()
+            .read_device_status()
+            .contains(DeviceStatus::DEVICE_NEEDS_RESET)
+        {
+            warn!("[sound device] the device needs to be reset");
+            self.needs_reset.store(true, Ordering::Release);
+            return;
+        }
+        let config = self.read_config();
+        {
+            let mut last_config = self.config.lock();
+            if (config.streams, config.jacks, config.chmaps)
+                == (last_config.streams, last_config.jacks, last_config.chmaps)
+            {
+                debug!("[sound device] configuration space change without new items");
+                return;
+            }
+            *last_config = config;
+        }
+        info!("[sound device] configuration changed to {:?}", config);
+        self.config_changed.store(true, Ordering::Release);
+        self.report_event(SoundEvent::ConfigChanged);
+        aster_sound::device_changed(&self.stable_id);
+    }
+
+    /// Note that a control request timed out, asking for the device to be reset
+    /// once too many have in a row.
+    fn note_control_timeout(&self) {
+        let timeouts = self.control_timeouts.fetch_add(1, Ordering::Relaxed) + 1;
+        if timeouts >= Self::MAX_CONTROL_TIMEOUTS {
+            warn!(
+                "[sound device] {} control requests in a row timed out",
+                timeouts
+            );
+            self.needs_reset.store(true, Ordering::Release);
+        }
+    }
+
+    fn report_event(&self, event: SoundEvent) {
+        let callbacks = self.event_callbacks.read();
+        for callback in callbacks.values() {
+            callback(event);
+        }
+    }
+
+    /// Start playing an output stream in pull mode, with periods of `period_bytes`.
+    ///
+    /// Every period buffer is filled and submitted right away, then a period is
+    /// filled each time the device reports that one has elapsed.
+    fn add_pull_stream(
+        &self,
+        stream_id: u32,
+        period_bytes: usize,
+        callback: Arc<PlaybackCallback>,
+    ) -> Result<(), VirtioDeviceError> {
+        let buffer_size =
+            PullStream::FRAMES_OFFSET + period_bytes + size_of::<VirtioSndPcmStatus>();
+        // The buffers are allocated before any lock is taken.
+        let buffers = (0..PullStream::NR_BUFFERS)
+            .map(|_| alloc_dma_stream(buffer_size, DmaDirection::Bidirectional))
+            .collect::<Result<_, _>>()?;
+        let pull_stream = PullStream {
+            callback,
+            period_bytes,
+            buffers,
+            in_flight: VecDeque::new(),
+        };
+        self.pull_streams
+            .disable_irq()
+            .lock()
+            .insert(stream_id, pull_stream);
+        for _ in 0..PullStream::NR_BUFFERS {
+            self.pull_period(stream_id);
+        }
+        Ok(())
+    }
+
+    /// Stop playing an output stream in pull mode.
+    ///
+    /// The buffers of the periods still in flight are kept until the device returns
+    /// them, so that it never reads freed memory, and their tokens are claimed then.
+    fn remove_pull_stream(&self, stream_id: u32) {
+        let Some(pull_stream) = self.pull_streams.disable_irq().lock().remove(&stream_id) else {
+            return;
+        };
+        self.retired_pull_streams
+            .disable_irq()
+            .lock()
+            .push(pull_stream);
+        self.reclaim_retired_pull_streams();
+    }
+
+    /// Claim the periods of the retired pull-mode streams the device has returned,
+    /// and drop the streams none of whose periods is in flight any more.
+    fn reclaim_retired_pull_streams(&self) {
+        let mut queue = self.tx_queue.disable_irq().lock();
+        self.retired_pull_streams
+            .disable_irq()
+            .lock()
+            .retain_mut(|pull_stream| {
+                pull_stream
+                    .in_flight
+                    .retain(|(token, _)| queue.pop_used_with_token(*token).is_err());
+                !pull_stream.in_flight.is_empty()
+            });
+    }
+
+    /// Fill the next period of a pull-mode stream and submit it to the tx queue.
+    fn pull_period(&self, stream_id: u32) {
+        let mut pull_streams = self.pull_streams.disable_irq().lock();
+        let Some(pull_stream) = pull_streams.get_mut(&stream_id) else {
+            return;
+        };
+        let mut tx_queue = self.tx_queue.disable_irq().lock();
+        // Reclaim the periods consumed by the device.
+        while let Some((token, _)) = pull_stream.in_flight.front() {
+            if tx_queue.pop_used_with_token(*token).is_err() {
+                break;
+            }
+            pull_stream.in_flight.pop_front();
+        }
+        let Some(index) = (0..PullStream::NR_BUFFERS)
+            .find(|index| pull_stream.in_flight.iter().all(|(_, busy)| busy != index))
+        else {
+            warn!(
+                "[sound device] stream {} is late, skipping a period",
+                stream_id
+            );
+            return;
+        };
 
-        let stream_id_stream = {
-            let segment = FrameAllocOptions::new()
-                .zeroed(false)
-                .alloc_segment(1)
-                .unwrap();
-            DmaStream::map(segment.into(), DmaDirection::ToDevice, false).unwrap()
+        let period_bytes = pull_stream.period_bytes;
+        let buffer = &pull_stream.buffers[index];
+        buffer
+            .write_val(0, &VirtioSndPcmXfer::new(stream_id))
+            .unwrap();
+        let frames_writer = || {
+            buffer
+                .writer()
+                .unwrap()
+                .skip(PullStream::FRAMES_OFFSET)
+                .limit(period_bytes)
         };
-        stream_id_stream
-            .writer()
-            .unwrap()
-            .write_once(&stream_id_bytes)
+        // Whatever the callback leaves unwritten is played as silence.
+        frames_writer().fill(0u8);
+        (pull_stream.callback)(frames_writer());
+        buffer
+            .sync(0..PullStream::FRAMES_OFFSET + period_bytes)
             .unwrap();
 
-        loop {
-            let mut queue = self.sound_inner.tx_queue.disable_irq().lock();
-            // early_println!(
-            //     "queue has {:?} available descriptor",
-            //     queue.available_desc()
-            // );
-            // early_println!(
-            //     "queue has {:?} available descriptor",
-            //     queue.available_desc()
-            // );
-            if queue.available_desc() >= 3 {
-                // 为什么是3？
-                if let Some(buffer) = remaining_buffers.next() {
-                    // early_println!("buffer is {:?}", buffer);
-                    // early_println!("buffer is {:?}", buffer);
-                    let resp_slice = {
-                        let resp_slice =
-                            DmaStreamSlice::new(&self.sound_inner.receive_buffer, 0, 8);
-                        resp_slice
-                    };
-                    tokens[head] = {
-                        // 为什么用unsafe
-                        // 要用remain>0吗
-                        let mut reader = VmReader::from(buffer);
-                        let mut writer = self.sound_inner.send_buffer.writer().unwrap();
-                        let len = writer.write(&mut reader);
-                        self.sound_inner.send_buffer.sync(0..len).unwrap();
-
-                        let pcm_data_slice: DmaStreamSlice<&DmaStream> =
-                            DmaStreamSlice::new(&self.sound_inner.send_buffer, 0, len);
-
-                        let device_id_slice = DmaStreamSlice::new(&stream_id_stream, 0, 4);
-                        let inputs = vec![&device_id_slice, &pcm_data_slice]; //为什么需要两个分开？能并一起传吗
-
-                        queue
-                            .add_dma_buf(inputs.as_slice(), &mut [&resp_slice])
-                            .unwrap()
-                    };
-                    // read from resp_slice
-                    resp_slice.sync().unwrap();
-                    statuses[head] = resp_slice.read_val(0).unwrap();
-                    if queue.should_notify() {
-                        queue.notify();
-                    }
-                    buffers[head] = Some(buffer);
-                    head += 1;
-                    if head >= usize::from(Self::QUEUE_SIZE) {
-                        head = 0;
-                    }
-                } else if head == tail {
-                    //都已经使用过，tail追赶上head
-                    break;
-                }
-            }
-            if queue.can_pop() {
-                // early_println!("tail is {:?}", tail);
-                // early_println!("tail is {:?}", tail);
-                // pop以后改变tail的值
-                queue.pop_used_with_token(tokens[tail])?;
-                if statuses[tail].status != u32::from(CommandCode::SOk) {
-                    return Err(VirtioDeviceError::IoError);
-                }
-                tail += 1;
-                if tail >= usize::from(Self::QUEUE_SIZE) {
-                    tail = 0;
+        let header = DmaStreamSlice::new(buffer, 0, PullStream::FRAMES_OFFSET);
+        let frames = DmaStreamSlice::new(buffer, PullStream::FRAMES_OFFSET, period_bytes);
+        let status = DmaStreamSlice::new(
+            buffer,
+            PullStream::FRAMES_OFFSET + period_bytes,
+            size_of::<VirtioSndPcmStatus>(),
+        );
+        let Ok(token) = tx_queue.add_dma_buf(&[&header, &frames], &[&status]) else {
+            warn!(
+                "[sound device] tx queue is full, skipping a period of stream {}",
+                stream_id
+            );
+            return;
+        };
+        if tx_queue.should_notify() {
+            tx_queue.notify();
+        }
+        pull_stream.in_flight.push_back((token, index));
+    }
+
+    fn schedule_completions(&self, completion_work: &Arc<Taskless>) {
+        if self.boost_completions.load(Ordering::Relaxed) {
+            completion_work.schedule_urgent();
+        } else {
+            completion_work.schedule();
+        }
+    }
+
+    /// Wait until `is_done` holds for the tx queue, which is checked again each time
+    /// the device returns a used buffer.
+    ///
+    /// `is_done` should ask about the tokens of the caller, not whether any buffer
+    /// can pop, since the completions of other tokens may be kept in the queue
+    /// until their claimants pop them.
+    ///
+    /// An interrupt-driven caller sleeps on the tx wait queue. A polled stream
+    /// raises no interrupt to be woken up by, so its caller yields instead.
+    fn wait_tx_used(
+        &self,
+        completion_mode: CompletionMode,
+        mut is_done: impl FnMut(&mut VirtQueue) -> bool,
+    ) {
+        let mut check = || is_done(&mut *self.tx_queue.disable_irq().lock()).then_some(());
+        match completion_mode {
+            CompletionMode::Interrupt => self.tx_wait_queue.wait_until(check),
+            CompletionMode::Polling => {
+                while check().is_none() {
+                    Task::yield_now();
                 }
             }
+        }
+    }
+
+    /// Wait until the device completes the transfer of `token` on the tx queue, then
+    /// claim it.
+    fn claim_tx_token(&self, token: u16, completion_mode: CompletionMode) {
+        self.wait_tx_used(completion_mode, |queue| queue.is_completed(token));
+        self.tx_queue
+            .disable_irq()
+            .lock()
+            .pop_used_with_token(token)
+            .unwrap();
+    }
+
+    /// Whether the device has completed one of the non-blocking transfers of `tokens`.
+    ///
+    /// The transfers completed by the interrupt handler but not collected yet count
+    /// as well, since collecting them makes progress.
+    fn any_nb_completed(&self, queue: &mut VirtQueue, tokens: &[u16]) -> bool {
+        tokens.iter().any(|token| queue.is_completed(*token))
+            || self
+                .nb_xfers
+                .lock()
+                .values()
+                .any(|(_, completed)| *completed)
+    }
+
+    /// Get the info of a stream, as queried from the device.
+    fn pcm_info(&self, stream_id: u32) -> Result<VirtioSndPcmInfo, VirtioDeviceError> {
+        self.pcm_infos
+            .read()
+            .as_ref()
+            .and_then(|pcm_infos| pcm_infos.get(stream_id as usize))
+            .copied()
+            .ok_or(VirtioDeviceError::InvalidParam)
+    }
+
+    fn features_supported(&self, stream_id: u32) -> Result<PcmFeatures, VirtioDeviceError> {
+        let pcm_info = self.pcm_info(stream_id)?;
+        Ok(pcm_info.features())
+    }
+
+    /// Get the streams of the given direction.
+    fn streams_of(&self, direction: StreamDirection) -> Vec<u32> {
+        self.pcm_infos
+            .read()
+            .as_deref()
+            .unwrap_or(&[])
+            .iter()
+            .enumerate()
+            .filter(|(_, info)| info.direction() == direction)
+            .map(|(idx, _)| idx as u32)
+            .collect()
+    }
+
+    fn is_paused(&self, stream_id: u32) -> bool {
+        self.paused.lock().contains(&stream_id)
+    }
+
+    /// Reset the device, then drop the buffers of the streams and of the records
+    /// it can no longer fill.
+    fn reset(&self) {
+        let mut transport = self.transport.disable_irq().lock();
+        if transport
+            .write_device_status(DeviceStatus::empty())
+            .is_err()
+        {
+            warn!("[sound device] failed to reset the device");
+        }
+        while transport.read_device_status() != DeviceStatus::empty() {
             spin_loop();
         }
+        drop(transport);
 
-        Ok(())
+        self.pull_streams.disable_irq().lock().clear();
+        self.retired_pull_streams.disable_irq().lock().clear();
+        let capture_streams = core::mem::take(&mut *self.capture_streams.disable_irq().lock());
+        for capture_stream in capture_streams.values() {
+            capture_stream.ring.scrub();
+        }
+        self.records.disable_irq().lock().clear();
+        self.event_slots.disable_irq().lock().clear();
+        self.paused.lock().clear();
+        self.xruns.lock().clear();
+        self.nb_xfers.lock().clear();
+        self.silence_fills.lock().clear();
     }
 
-    /// Transfer PCM frame to device, based on the stream type(OUTPUT/INPUT).
+    /// Set the device up again if it was reset, e.g., because it lost power while
+    /// suspended, or if it needs to be reset, and return whether it was.
     ///
-    /// Currently supports only output stream.
+    /// With `force`, the device is reset and set up again in any case, e.g.,
+    /// because it stopped answering control requests.
     ///
-    /// This is a non-blocking method that returns a token.
+    /// The features are negotiated again and the virtqueues are created again,
+    /// since the device forgot them. The buffers made available on the old queues
+    /// are dropped, and the event buffers are made available on the new one.
+    fn reinit(&self, force: bool) -> Result<bool, VirtioDeviceError> {
+        let mut transport = self.transport.disable_irq().lock();
+        let status = transport.read_device_status();
+        if !force
+            && status.contains(DeviceStatus::DRIVER_OK)
+            && !status.contains(DeviceStatus::DEVICE_NEEDS_RESET)
+        {
+            return Ok(false);
+        }
+
+        info!("[sound device] the device was reset, setting it up again");
+        if transport
+            .write_device_status(DeviceStatus::empty())
+            .is_err()
+        {
+            warn!("[sound device] failed to reset the device");
+        }
+        while transport.read_device_status() != DeviceStatus::empty() {
+            spin_loop();
+        }
+        transport
+            .write_device_status(DeviceStatus::ACKNOWLEDGE | DeviceStatus::DRIVER)
+            .map_err(|_| VirtioDeviceError::IoError)?;
+        crate::negotiate_features(&mut transport);
+        if !transport.is_legacy_version() {
+            transport
+                .write_device_status(
+                    DeviceStatus::ACKNOWLEDGE | DeviceStatus::DRIVER | DeviceStatus::FEATURES_OK,
+                )
+                .map_err(|_| VirtioDeviceError::IoError)?;
+        }
+        for (index, queue) in [
+            (Self::CONTROLQ_INDEX, &self.control_queue),
+            (Self::EVENTQ_INDEX, &self.event_queue),
+            (Self::TXQ_INDEX, &self.tx_queue),
+            (Self::RXQ_INDEX, &self.rx_queue),
+        ] {
+            *queue.disable_irq().lock() =
+                VirtQueue::new(index, Self::QUEUE_SIZE, transport.as_mut())?;
+        }
+        transport.finish_init();
+        drop(transport);
+
+        *self.control_requests.lock() = ControlRequests::default();
+        self.pull_streams.disable_irq().lock().clear();
+        self.retired_pull_streams.disable_irq().lock().clear();
+        self.capture_streams.disable_irq().lock().clear();
+        self.records.disable_irq().lock().clear();
+        self.event_slots.disable_irq().lock().clear();
+        self.nb_xfers.lock().clear();
+        self.silence_fills.lock().clear();
+        self.xruns.lock().clear();
+        self.paused.lock().clear();
+        self.needs_reset.store(false, Ordering::Relaxed);
+        self.control_timeouts.store(0, Ordering::Relaxed);
+        self.activate_event_buffers();
+        Ok(true)
+    }
+
+    /// Submit a request on the control queue without waiting for its answer of
+    /// `answer_len` bytes, and return its token.
     ///
-    /// The length of the `frames` must be equal to the buffer size set for the stream corresponding to the `stream_id`.
-    pub fn pcm_xfer_nb(&mut self, stream_id: u32, frames: &[u8]) -> Result<u16, VirtioDeviceError> {
-        const U32_SIZE: usize = size_of::<u32>();
-        if !self.set_up {
-            self.set_up()?;
-            self.set_up = true;
+    /// The request is written to a slot of the send buffer and answered into the
+    /// matching slot of the receive buffer, so that other requests can be in flight
+    /// at the same time.
+    fn submit_control_request<Req: Pod>(
+        &self,
+        req: &Req,
+        answer_len: usize,
+    ) -> Result<u16, VirtioDeviceError> {
+        let req_len = req.as_bytes().len();
+        if req_len > Self::REQUEST_SLOT_SIZE {
+            return Err(VirtioDeviceError::InvalidParam);
         }
-        if !self.pcm_parameters[stream_id as usize].setup {
-            warn!("Please set parameters for a stream before using it!");
-            return Err(VirtioDeviceError::IoError);
+        if answer_len > Self::ANSWER_SLOT_SIZE {
+            return Err(VirtioDeviceError::BufferOverflow);
         }
-        let period_size: usize = self.pcm_parameters[stream_id as usize].period_bytes as usize;
-        assert_eq!(period_size, frames.len());
+        let mut queue = self.control_queue.disable_irq().lock();
+        let mut requests = self.control_requests.disable_irq().lock();
+        let slot = requests
+            .free_slot()
+            .ok_or(VirtioDeviceError::QueueUnknownError)?;
 
-        let id_stream = {
-            let segment = FrameAllocOptions::new()
-                .zeroed(false)
-                .alloc_segment(1)
-                .unwrap();
-            DmaStream::map(segment.into(), DmaDirection::Bidirectional, false).unwrap()
-        };
-        let stream_id_bytes = stream_id.to_le_bytes();
-        id_stream
-            .writer()
-            .unwrap()
-            .write_once(&stream_id_bytes)
-            .unwrap();
-        let id_stream_slice = DmaStreamSlice::new(&id_stream, 0, 4);
-        let mut reader = VmReader::from(frames);
-        let mut writer = self.sound_inner.send_buffer.writer().unwrap();
-        let len = writer.write(&mut reader);
-        self.sound_inner.send_buffer.sync(0..len).unwrap();
-
-        let frame_slice = DmaStreamSlice::new(&self.sound_inner.send_buffer, 0, period_size);
-        let inputs = vec![&id_stream_slice, &frame_slice];
-        let rsp = VirtioSndPcmStatus::new_zeroed();
-        let rsp_slice = {
-            let rsp_slice =
-                DmaStreamSlice::new(&self.sound_inner.receive_buffer, 0, rsp.as_bytes().len());
-            rsp_slice
-        };
-        let mut queue = self.sound_inner.tx_queue.disable_irq().lock();
-        let token = queue
-            .add_dma_buf(inputs.as_slice(), &mut [&rsp_slice])
-            .expect("add tx queue failed");
+        let req_slice =
+            DmaStreamSlice::new(&self.send_buffer, slot * Self::REQUEST_SLOT_SIZE, req_len);
+        req_slice.write_val(0, req).unwrap();
+        req_slice.sync().unwrap();
+        let resp_slice = DmaStreamSlice::new(
+            &self.receive_buffer,
+            slot * Self::ANSWER_SLOT_SIZE,
+            answer_len,
+        );
+        let token = queue.add_dma_buf(&[&req_slice], &[&resp_slice])?;
+        requests.slots.insert(token, slot);
         if queue.should_notify() {
             queue.notify();
         }
-        self.token_buf.insert(token, token);
-        self.token_rsp.insert(token, token);
         Ok(token)
     }
 
-    /// The PCM frame transmission corresponding to the given token has been completed.
-    pub fn pcm_xfer_ok(&mut self, token: u16) -> Result<(), VirtioDeviceError> {
-        assert!(self.token_buf.contains_key(&token));
-        assert!(self.token_rsp.contains_key(&token));
-        let mut queue = self.sound_inner.tx_queue.disable_irq().lock();
-        queue
-            .pop_used_with_token(token)
-            .expect("pop used failed during pcm transfer ack");
-
-        self.token_buf.remove(&token);
-        self.token_rsp.remove(&token);
-        Ok(())
-    }
-
-    // test the pcm related ability of device
-    fn test_device(&mut self) {
-        // let cloned_device = Arc::clone(&device);
-        // let mut device = cloned_device;
-        early_println!(
-            "Config is {:?}",
-            self.sound_inner.config_manager.read_config(false)
-        ); //Config is VirtioSoundConfig { jacks: 0, streams: 2, chmaps: 0, controls: 4294967295 }
-        self.set_up().unwrap();
-        const STREAMID: u32 = 0;
-        const BUFFER_BYTES: u32 = 80000;
-        const PERIOD_BYTES: u32 = 100;
-        const FEATURES: PcmFeatures = PcmFeatures::empty();
-        const CHANNELS: u8 = 1;
-        const FORMAT: PcmFormat = PcmFormat::U8;
-        const PCMRATE: PcmRate = PcmRate::Rate8000;
-
-
-        // A PCM stream has the following command lifecycle:
-        //
-        // - `SET PARAMETERS`
-        //
-        //   The driver negotiates the stream parameters (format, transport, etc) with
-        //   the device.
-        //
-        //   Possible valid transitions: `SET PARAMETERS`, `PREPARE`.
-        //
-        // - `PREPARE`
-        //
-        //   The device prepares the stream (allocates resources, etc).
-        //
-        //   Possible valid transitions: `SET PARAMETERS`, `PREPARE`, `START`,
-        //   `RELEASE`.   Output only: the driver transfers data for pre-buffing.
-        //
-        // - `START`
-        //
-        //   The device starts the stream (unmute, putting into running state, etc).
-        //
-        //   Possible valid transitions: `STOP`.
-        //   The driver transfers data to/from the stream.
-        //
-        // - `STOP`
-        //
-        //   The device stops the stream (mute, putting into non-running state, etc).
-        //
-        //   Possible valid transitions: `START`, `RELEASE`.
-        //
-        // - `RELEASE`
-        //
-        //   The device releases the stream (frees resources, etc).
-        //
-        //   Possible valid transitions: `SET PARAMETERS`, `PREPARE`.
-        //
-        // ```text
-        // +---------------+ +---------+ +---------+ +-------+ +-------+
-        // | SetParameters | | Prepare | | Release | | Start | | Stop  |
-        // +---------------+ +---------+ +---------+ +-------+ +-------+
-        //         |              |           |          |         |
-        //         |-             |           |          |         |
-        //         ||             |           |          |         |
-        //         |<             |           |          |         |
-        //         |              |           |          |         |
-        //         |------------->|           |          |         |
-        //         |              |           |          |         |
-        //         |<-------------|           |          |         |
-        //         |              |           |          |         |
-        //         |              |-          |          |         |
-        //         |              ||          |          |         |
-        //         |              |<          |          |         |
-        //         |              |           |          |         |
-        //         |              |--------------------->|         |
-        //         |              |           |          |         |
-        //         |              |---------->|          |         |
-        //         |              |           |          |         |
-        //         |              |           |          |-------->|
-        //         |              |           |          |         |
-        //         |              |           |          |<--------|
-        //         |              |           |          |         |
-        //         |              |           |<-------------------|
-        //         |              |           |          |         |
-        //         |<-------------------------|          |         |
-        //         |              |           |          |         |
-        //         |              |<----------|          |         |
-        // ```
-        let set_params_result = self.pcm_set_params(
-            STREAMID,
-            BUFFER_BYTES,
-            PERIOD_BYTES,
-            FEATURES,
-            CHANNELS,
-            FORMAT,
-            PCMRATE,
-        );
-        match set_params_result {
-            Ok(()) => {
-                early_println!("Set Parameters for stream {:?} completed!", STREAMID);
-            }
-            Err(e) => {
-                early_println!(
-                    "Set Parameters for stream {:?} wrong due to {:?}!",
-                    STREAMID,
-                    e
+    /// Pop the answers returned on the control queue.
+    ///
+    /// An answer is kept until the request it belongs to collects it, except the
+    /// late answer of a request given up on, whose slot is freed right away.
+    fn pop_control_answers(&self) {
+        let mut queue = self.control_queue.disable_irq().lock();
+        let mut requests = self.control_requests.disable_irq().lock();
+        while let Ok((token, len)) = queue.pop_used() {
+            if requests.abandoned.remove(&token) {
+                requests.slots.remove(&token);
+                debug!(
+                    "[sound device] dropped the late answer of request {}",
+                    token
                 );
+            } else {
+                requests.answered.insert(token, len as usize);
             }
         }
+    }
 
-
-        let pcm_prepare_result = self.pcm_prepare(STREAMID);
-        match pcm_prepare_result {
-            Ok(()) => {
-                early_println!("Preparation for stream {:?} completed!", STREAMID);
+    /// Wait until the device answers the request of `token` on the control queue,
+    /// for at most `timeout`, and return its answer.
+    ///
+    /// The answer is cut to the bytes the device wrote, up to `answer_len`.
+    ///
+    /// The caller sleeps on the control wait queue, woken up by the control queue
+    /// interrupt, and by the timer while waiting so that the timeout is noticed.
+    /// Before the first task runs there is no task to put to sleep, so requests
+    /// made while probing spin instead.
+    ///
+    /// The slots of a request that timed out are freed once the device answers it
+    /// late.
+    fn wait_control_answer(
+        &self,
+        token: u16,
+        answer_len: usize,
+        timeout: Duration,
+    ) -> Result<Vec<u8>, VirtioDeviceError> {
+        let start = read_tsc();
+        let collect = || {
+            self.pop_control_answers();
+            let mut requests = self.control_requests.disable_irq().lock();
+            if let Some(written) = requests.answered.remove(&token) {
+                // Copy the answer out before its slot can be taken by another request.
+                let slot = requests.slots.remove(&token).unwrap();
+                let offset = slot * Self::ANSWER_SLOT_SIZE;
+                let len = written.min(answer_len);
+                self.receive_buffer.sync(offset..offset + len).unwrap();
+                let mut answer = vec![0u8; len];
+                self.receive_buffer.read_bytes(offset, &mut answer).unwrap();
+                return Some(Ok(answer));
             }
-            Err(e) => {
-                early_println!(
-                    "Preparation for stream {:?} wrong due to {:?}!",
-                    STREAMID,
-                    e
-                );
+            if us_since(start) >= timeout.as_micros() as u64 {
+                requests.abandoned.insert(token);
+                return Some(Err(VirtioDeviceError::Timeout));
+            }
+            None
+        };
+        if Task::current().is_none() {
+            loop {
+                if let Some(result) = collect() {
+                    return result;
+                }
+                spin_loop();
             }
         }
+        self.control_waiters.fetch_add(1, Ordering::Relaxed);
+        let result = self.control_wait_queue.wait_until(collect);
+        self.control_waiters.fetch_sub(1, Ordering::Relaxed);
+        result
+    }
 
+    /// Pop the non-blocking transfers the device has completed and report them to
+    /// the transfer callbacks, then wake up the transfers waiting for the device
+    /// to complete their periods.
+    fn process_tx_completions(&self) {
+        let mut queue = self.tx_queue.disable_irq().lock();
+        let mut nb_xfers = self.nb_xfers.lock();
+        let completed = self.pop_nb_used(&mut queue, &mut nb_xfers);
 
-        let pcm_start_result = self.pcm_start(STREAMID);
-        match pcm_start_result {
-            Ok(()) => {
-                early_println!("Start for stream {:?} completed!", STREAMID);
+        // Play silence on the started streams that ran out of periods.
+        let xruns = self.xruns.lock();
+        for (stream_id, fill) in self.silence_fills.lock().iter_mut() {
+            let starving = fill.active
+                && fill.in_flight.is_none()
+                && !nb_xfers.values().any(|(id, done)| id == stream_id && !done)
+                && !xruns.contains(stream_id)
+                && !self.is_paused(*stream_id);
+            if starving {
+                fill.submit(&mut queue);
             }
-            Err(e) => {
-                early_println!("Start for stream {:?} wrong due to {:?}!", STREAMID, e);
+        }
+        drop(xruns);
+        drop(nb_xfers);
+        drop(queue);
+        self.reclaim_retired_pull_streams();
+
+        if !completed.is_empty() {
+            let callbacks = self.xfer_callbacks.read();
+            for token in completed {
+                for callback in callbacks.values() {
+                    callback(token);
+                }
             }
         }
+        self.tx_wait_queue.wake_all();
+    }
 
+    /// Check whether the device has completed the non-blocking transfer of `token`,
+    /// popping it from the tx queue if the interrupt handler has not yet.
+    fn is_xfer_completed(&self, token: u16) -> bool {
+        let mut queue = self.tx_queue.disable_irq().lock();
+        let mut nb_xfers = self.nb_xfers.lock();
+        self.pop_nb_used(&mut queue, &mut nb_xfers);
+        nb_xfers.get(&token).is_some_and(|(_, done)| *done)
+    }
 
-        let pcm_xfer_result = self.pcm_xfer(STREAMID, &test_frames::TEST_FRAMES_A4);
-        match pcm_xfer_result {
-            Ok(()) => {
-                early_println!("Transfer for stream {:?} completed!", STREAMID);
-            }
-            Err(e) => {
-                early_println!("Transfer for stream {:?} wrong due to {:?}!", STREAMID, e);
+    /// Pop the non-blocking transfers and the silent periods the device has used,
+    /// marking the transfers as completed, and return their tokens.
+    fn pop_nb_used(
+        &self,
+        queue: &mut VirtQueue,
+        nb_xfers: &mut BTreeMap<u16, (u32, bool)>,
+    ) -> Vec<u16> {
+        let mut completed = Vec::new();
+        let mut silence_fills = self.silence_fills.lock();
+        loop {
+            if let Some((token, (_, done))) = nb_xfers
+                .iter_mut()
+                .find(|(token, (_, done))| !*done && queue.pop_used_with_token(**token).is_ok())
+            {
+                *done = true;
+                completed.push(*token);
+            } else if let Some(fill) = silence_fills.values_mut().find(|fill| {
+                fill.in_flight
+                    .is_some_and(|token| queue.pop_used_with_token(token).is_ok())
+            }) {
+                fill.in_flight = None;
+                fill.inserted_bytes += fill.period_bytes as u64;
+            } else {
+                return completed;
             }
         }
+    }
 
+    /// Allocate the period of silence played on an output stream whenever none of
+    /// its non-blocking transfers is in flight, in periods of `period_bytes`.
+    ///
+    /// The silence is only played once [`Self::enable_silence_fill`] is called.
+    fn prepare_silence_fill(
+        &self,
+        stream_id: u32,
+        period_bytes: usize,
+        format: PcmFormat,
+        channels: u8,
+    ) -> Result<(), VirtioDeviceError> {
+        if self.silence_fills.lock().contains_key(&stream_id) {
+            return Ok(());
+        }
 
-        let pcm_stop_result = self.pcm_stop(STREAMID);
-        match pcm_stop_result {
-            Ok(()) => {
-                early_println!("Stop for stream {:?} completed!", STREAMID);
-            }
-            Err(e) => {
-                early_println!("Stop for stream {:?} wrong due to {:?}!", STREAMID, e);
-            }
+        let buffer_size =
+            SilenceFill::FRAMES_OFFSET + period_bytes + size_of::<VirtioSndPcmStatus>();
+        let buffer = alloc_dma_stream(buffer_size, DmaDirection::Bidirectional)?;
+        let mut silence = vec![0u8; period_bytes];
+        if let Ok(format) = SampleFormat::try_from(u8::from(format)) {
+            fill_silence(format, &mut silence);
         }
+        buffer
+            .write_val(0, &VirtioSndPcmXfer::new(stream_id))
+            .unwrap();
+        buffer
+            .write_bytes(SilenceFill::FRAMES_OFFSET, &silence)
+            .unwrap();
+        buffer
+            .sync(0..SilenceFill::FRAMES_OFFSET + period_bytes)
+            .unwrap();
+        let fill = SilenceFill {
+            period_bytes,
+            frame_bytes: frame_bytes(format, channels).unwrap_or(channels as usize),
+            buffer,
+            in_flight: None,
+            active: false,
+            inserted_bytes: 0,
+        };
+        self.silence_fills.lock().entry(stream_id).or_insert(fill);
+        Ok(())
+    }
 
+    /// Play silence on an output stream whenever it runs out of periods, with the
+    /// silent period allocated by [`Self::prepare_silence_fill`].
+    fn enable_silence_fill(&self, stream_id: u32) {
+        if let Some(fill) = self.silence_fills.lock().get_mut(&stream_id) {
+            fill.active = true;
+        }
+    }
 
-        let pcm_release_result = self.pcm_release(STREAMID);
-        match pcm_release_result {
-            Ok(()) => {
-                early_println!("Release for stream {:?} completed!", STREAMID);
-            }
-            Err(e) => {
-                early_println!("Release for stream {:?} wrong due to {:?}!", STREAMID, e);
-            }
+    /// Stop playing silence on a stopped output stream.
+    fn stop_silence_fill(&self, stream_id: u32) {
+        if let Some(fill) = self.silence_fills.lock().get_mut(&stream_id) {
+            fill.active = false;
         }
     }
 
-    // Test input function for virtio-sound device
-    fn test_device_input(&mut self) {
-        early_println!(
-            "Config is {:?}",
-            self.sound_inner.config_manager.read_config(false)
-        ); //Config is VirtioSoundConfig { jacks: 0, streams: 2, chmaps: 0, controls: 4294967295 }
-        self.set_up().unwrap();
-        const STREAMID: u32 = 1;
-        const BUFFER_BYTES: u32 = 80000;
-        const PERIOD_BYTES: u32 = 100;
-        const FEATURES: PcmFeatures = PcmFeatures::empty();
-        const CHANNELS: u8 = 1;
-        const FORMAT: PcmFormat = PcmFormat::U8;
-        const PCMRATE: PcmRate = PcmRate::Rate8000;
-
-        let set_params_result = self.pcm_set_params(
-            STREAMID,
-            BUFFER_BYTES,
-            PERIOD_BYTES,
-            FEATURES,
-            CHANNELS,
-            FORMAT,
-            PCMRATE,
-        );
-        match set_params_result {
-            Ok(()) => {
-                early_println!("Set Parameters for stream {:?} completed!", STREAMID);
-            }
-            Err(e) => {
-                early_println!(
-                    "Set Parameters for stream {:?} wrong due to {:?}!",
-                    STREAMID,
-                    e
+    /// Forget the silence of a released output stream, reclaiming its silent
+    /// period the device returned.
+    fn remove_silence_fill(&self, stream_id: u32) {
+        let mut queue = self.tx_queue.disable_irq().lock();
+        let Some(fill) = self.silence_fills.lock().remove(&stream_id) else {
+            return;
+        };
+        if let Some(token) = fill.in_flight {
+            if queue.pop_used_with_token(token).is_err() {
+                warn!(
+                    "[sound device] the silent period of stream {} was not returned",
+                    stream_id
                 );
             }
         }
+    }
 
-        early_println!("Entering recording mode!");
-
-        let buffer = vec![0u8; BUFFER_BYTES as usize];
-        while buffer.iter().all(|&b| b == 0) {
-            spin_loop();
+    /// Start capturing an input stream in periods of `period_bytes`.
+    ///
+    /// Every period buffer is submitted to the rx queue right away, then submitted
+    /// again each time the device has filled it.
+    fn add_capture_stream(
+        &self,
+        stream_id: u32,
+        period_bytes: usize,
+    ) -> Result<(), VirtioDeviceError> {
+        if self.is_capturing(stream_id) {
+            return Ok(());
         }
-
-        early_println!("Recording test completed.");
+        let buffer_size =
+            CaptureStream::FRAMES_OFFSET + period_bytes + size_of::<VirtioSndPcmStatus>();
+        // The buffers are allocated before any lock is taken.
+        let buffers = (0..CaptureStream::NR_BUFFERS)
+            .map(|_| alloc_dma_stream(buffer_size, DmaDirection::Bidirectional))
+            .collect::<Result<_, _>>()?;
+        let mut rx_queue = self.rx_queue.disable_irq().lock();
+        let mut capture_streams = self.capture_streams.disable_irq().lock();
+        if capture_streams.contains_key(&stream_id) {
+            return Ok(());
+        }
+        let capture_stream = capture_streams.entry(stream_id).or_insert(CaptureStream {
+            period_bytes,
+            buffers,
+            ring: Arc::new(CaptureRing::new(Self::CAPTURE_RING_SIZE)),
+            in_flight: BTreeMap::new(),
+        });
+        for index in 0..CaptureStream::NR_BUFFERS {
+            Self::submit_capture_period(&mut rx_queue, stream_id, capture_stream, index);
+        }
+        if rx_queue.should_notify() {
+            rx_queue.notify();
+        }
+        Ok(())
     }
-}
-
-pub struct SoundDeviceInner {
-    config_manager: ConfigManager<VirtioSoundConfig>,
-    transport: SpinLock<Box<dyn VirtioTransport>>,
-
-    /// 0: The control queue is used for sending control messages from the driver to the device.
-    /// 1: The event queue is used for sending notifications from the device to the driver.
-    /// 2: The tx queue is used to send PCM frames for output streams.
-    /// 3: The rx queue is used to receive PCM frames from input streams.
-    control_queue: SpinLock<VirtQueue>,
-    event_queue: SpinLock<VirtQueue>,
-    tx_queue: SpinLock<VirtQueue>,
-    rx_queue: SpinLock<VirtQueue>,
-    send_buffer: DmaStream,
-    receive_buffer: DmaStream,
-    callbacks: RwLock<Vec<&'static SoundCallback>, LocalIrqDisabled>,
-}
-
-impl AnySoundDevice for SoundDevice {
-    // fn register_playback_callback(&self, callback: &'static SoundCallback) {
-    //     let mut callbacks = self.callbacks.write();
-    //     callbacks.push(callback);
-    // }
 
-    fn test_device(&mut self) {
-        self.test_device();
+    /// Drop the period buffers of an input stream, and scrub the frames it
+    /// captured but no one read.
+    ///
+    /// The device must no longer hold any of them, i.e., the stream must be released.
+    fn remove_capture_stream(&self, stream_id: u32) {
+        let capture_stream = self.capture_streams.disable_irq().lock().remove(&stream_id);
+        if let Some(capture_stream) = capture_stream {
+            capture_stream.ring.scrub();
+        }
+        // The readers of the stream must not wait for periods that never come.
+        self.rx_wait_queue.wake_all();
     }
 
-    fn register_callback(&self, callback: &'static SoundCallback) {
-        let mut callbacks = self.sound_inner.callbacks.write();
-        callbacks.push(callback);
+    fn is_capturing(&self, stream_id: u32) -> bool {
+        self.capture_streams
+            .disable_irq()
+            .lock()
+            .contains_key(&stream_id)
     }
-}
 
-impl Debug for SoundDeviceInner {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.debug_struct("SoundDeviceInner")
-            .field("config", &self.config_manager.read_config(false))
-            .field("transport", &self.transport)
-            .field("control_queue", &self.control_queue)
-            .field("event_queue", &self.event_queue)
-            .field("tx_queue", &self.tx_queue)
-            .field("rx_queue", &self.rx_queue)
-            .field("send_buffer", &self.send_buffer)
-            .field("receive_buffer", &self.receive_buffer)
-            .finish()
+    /// Returns the ring of the frames captured on a started input stream.
+    fn capture_ring(&self, stream_id: u32) -> Option<Arc<CaptureRing>> {
+        self.capture_streams
+            .disable_irq()
+            .lock()
+            .get(&stream_id)
+            .map(|capture_stream| capture_stream.ring.clone())
     }
-}
-impl SoundDeviceInner {
-    const QUEUE_SIZE: u16 = 16;
-
-    pub fn set(mut transport: Box<dyn VirtioTransport>) -> Result<Arc<Self>, VirtioDeviceError> {
-        let config_manager = VirtioSoundConfig::new_manager(transport.as_ref());
 
-        let sound_config = config_manager.read_config(false);
-
-        early_println!(
-            "Load virtio-sound successfully. Config = {:?}",
-            sound_config
-        );
+    fn submit_capture_period(
+        rx_queue: &mut VirtQueue,
+        stream_id: u32,
+        capture_stream: &mut CaptureStream,
+        index: usize,
+    ) {
+        let period_bytes = capture_stream.period_bytes;
+        let buffer = &capture_stream.buffers[index];
+        buffer
+            .write_val(0, &VirtioSndPcmXfer::new(stream_id))
+            .unwrap();
+        buffer.sync(0..CaptureStream::FRAMES_OFFSET).unwrap();
 
-        const CONTROLQ_INDEX: u16 = 0;
-        const EVENTQ_INDEX: u16 = 1;
-        const TXQ_INDEX: u16 = 2;
-        const RXQ_INDEX: u16 = 3;
-        let control_queue = SpinLock::new(
-            VirtQueue::new(CONTROLQ_INDEX, Self::QUEUE_SIZE, transport.as_mut()).unwrap(),
-        );
-        let event_queue = SpinLock::new(
-            VirtQueue::new(EVENTQ_INDEX, Self::QUEUE_SIZE, transport.as_mut()).unwrap(),
+        let header = DmaStreamSlice::new(buffer, 0, CaptureStream::FRAMES_OFFSET);
+        let frames = DmaStreamSlice::new(buffer, CaptureStream::FRAMES_OFFSET, period_bytes);
+        let status = DmaStreamSlice::new(
+            buffer,
+            CaptureStream::FRAMES_OFFSET + period_bytes,
+            size_of::<VirtioSndPcmStatus>(),
         );
-        let tx_queue =
-            SpinLock::new(VirtQueue::new(TXQ_INDEX, Self::QUEUE_SIZE, transport.as_mut()).unwrap());
-        let rx_queue =
-            SpinLock::new(VirtQueue::new(RXQ_INDEX, Self::QUEUE_SIZE, transport.as_mut()).unwrap());
-        let send_buffer = {
-            let segment = FrameAllocOptions::new().alloc_segment(1).unwrap();
-            DmaStream::map(segment.into(), DmaDirection::ToDevice, false).unwrap()
-        };
-
-        let receive_buffer = {
-            let segment = FrameAllocOptions::new().alloc_segment(1).unwrap();
-            DmaStream::map(segment.into(), DmaDirection::FromDevice, false).unwrap()
-        };
-
-        let device = Arc::new(SoundDeviceInner {
-            config_manager,
-            transport: SpinLock::new(transport),
-            control_queue,
-            event_queue,
-            tx_queue,
-            rx_queue,
-            send_buffer,
-            receive_buffer,
-            callbacks: RwLock::new(Vec::new()),
-        });
-        device.activate_receive_buffer(&mut device.event_queue.disable_irq().lock());
-
-        // Register irq callbacks
-        let mut transport = device.transport.disable_irq().lock();
-        // TODO: callbacks for microphone input
-        let handle_sound_input = {
-            let device = device.clone();
-            move |_: &TrapFrame| device.handle_recv_irq()
+        let Ok(token) = rx_queue.add_dma_buf(&[&header], &[&frames, &status]) else {
+            warn!(
+                "[sound device] rx queue is full, dropping a period of stream {}",
+                stream_id
+            );
+            return;
         };
-        const RECV0_QUEUE_INDEX: u16 = 0;
-        const TRANSMIT0_QUEUE_INDEX: u16 = 1;
-        transport
-            .register_queue_callback(RECV0_QUEUE_INDEX, Box::new(handle_sound_input), false)
-            .unwrap();
-        transport
-            .register_cfg_callback(Box::new(config_space_change))
-            .unwrap();
-        transport.finish_init();
-        early_println!(
-            "Load virtio-sound successfully. Config = {:?}",
-            sound_config
-        );
-        drop(transport);
-
-        Ok(device)
+        capture_stream.in_flight.insert(token, index);
     }
 
-    fn record(&self, buffer: &mut [u8]) {
-        let buffer_len = buffer.len();
+    /// Deliver the periods filled by the device, and submit their buffers again.
+    fn process_rx_completions(&self) {
         let mut rx_queue = self.rx_queue.disable_irq().lock();
-        let mut writer = VmWriter::from(&mut *buffer);
-        while writer.avail() > 0 {
-            let mut reader = self.receive_buffer.reader().unwrap();
-            let len = reader.read(&mut writer);
-            self.receive_buffer.sync(0..len).unwrap();
-            let receive_slice = DmaStreamSlice::new(&self.receive_buffer, 0, buffer_len); // It should be noted that the length value contains the size of the virtio_snd_pcm_status structure plus the size of the recorded frames.
-            rx_queue.add_dma_buf(&[], &[&receive_slice]).unwrap();
-
-            if rx_queue.should_notify() {
-                rx_queue.notify();
-            }
-
-            // 等待数据接收完成
-            while !rx_queue.can_pop() {
-                spin_loop();
+        let mut completed = false;
+        while let Ok((token, len)) = rx_queue.pop_used() {
+            completed = true;
+            // The frames of record requests are collected by `record_poll`.
+            if let Some(record) = self.records.disable_irq().lock().get_mut(&token) {
+                record.used_len = Some(len);
+                continue;
             }
-
-            // 清理已使用的缓冲区
-            rx_queue.pop_used().unwrap();
+            let mut capture_streams = self.capture_streams.disable_irq().lock();
+            let Some((stream_id, capture_stream, index)) =
+                capture_streams
+                    .iter_mut()
+                    .find_map(|(stream_id, capture_stream)| {
+                        let index = capture_stream.in_flight.remove(&token)?;
+                        Some((*stream_id, capture_stream, index))
+                    })
+            else {
+                // The period of a released stream.
+                continue;
+            };
+            self.deliver_capture_period(capture_stream, index, len);
+            Self::submit_capture_period(&mut rx_queue, stream_id, capture_stream, index);
+        }
+        if rx_queue.should_notify() {
+            rx_queue.notify();
+        }
+        drop(rx_queue);
+        if completed {
+            self.rx_wait_queue.wake_all();
         }
-        early_println!("The input stream buffer is {:?}", buffer);
     }
 
-    fn handle_recv_irq(&self) {
-        let mut receive_queue = self.rx_queue.disable_irq().lock();
-
-        let Ok((_, len)) = receive_queue.pop_used() else {
+    /// Push the frames of a filled period into the ring of its stream and pass them to
+    /// the record callbacks.
+    fn deliver_capture_period(&self, capture_stream: &CaptureStream, index: usize, used_len: u32) {
+        const STATUS_SIZE: usize = size_of::<VirtioSndPcmStatus>();
+        let period_bytes = capture_stream.period_bytes;
+        let buffer = &capture_stream.buffers[index];
+        let frames_offset = CaptureStream::FRAMES_OFFSET;
+        buffer
+            .sync(frames_offset..frames_offset + period_bytes + STATUS_SIZE)
+            .unwrap();
+        let status: VirtioSndPcmStatus = buffer.read_val(frames_offset + period_bytes).unwrap();
+        if let Err(err) = check_status(status.status.get()) {
+            warn!("[sound device] capture failed: {:?}", err);
             return;
-        };
-        self.receive_buffer.sync(0..len as usize).unwrap();
+        }
 
-        let mut buffer = vec![0u8; len as usize];
-        self.record(&mut buffer);
+        // The frames are followed by the status of the transfer.
+        let len = (used_len as usize)
+            .saturating_sub(STATUS_SIZE)
+            .min(period_bytes);
+        let mut frames = vec![0u8; len];
+        // Enforce the capture kill-switch before the frames reach any reader.
+        let privacy = aster_sound::capture_privacy();
+        if privacy.blocked {
+            if privacy.mode == CaptureBlockMode::Error {
+                return;
+            }
+            buffer.write_bytes(frames_offset, &frames).unwrap();
+        } else {
+            buffer.read_bytes(frames_offset, &mut frames).unwrap();
+        }
+        let stored = capture_stream.ring.push(&frames);
+        if stored < len {
+            self.capture_overrun_bytes
+                .fetch_add((len - stored) as u64, Ordering::Relaxed);
+            warn!("capture ring overrun, {} bytes dropped", len - stored);
+        }
 
         let callbacks = self.callbacks.read();
-        for callback in callbacks.iter() {
-            let reader = self.receive_buffer.reader().unwrap().limit(len as usize);
+        for callback in callbacks.values() {
+            let reader = buffer.reader().unwrap().skip(frames_offset).limit(len);
             callback(reader);
         }
-        drop(callbacks);
-
-        self.activate_receive_buffer(&mut receive_queue);
-    }
-
-    fn activate_receive_buffer(&self, rec_queue: &mut VirtQueue) {
-        rec_queue
-            .add_dma_buf(&[], &[&DmaStreamSlice::new(&self.receive_buffer, 0, 1)])
-            .unwrap();
-        early_println!("{:?}", rec_queue);
-        if rec_queue.should_notify() {
-            early_println!("You should notify");
-            rec_queue.notify();
-        }
-        early_println!("finish ask notify");
     }
 }
 
-fn config_space_change(_: &TrapFrame) {
-    debug!("Virtio-Sound device configuration space change");
-    early_println!("Virtio-Sound device configuration space change")
-}
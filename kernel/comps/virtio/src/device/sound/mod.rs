@@ -1,14 +1,20 @@
 pub mod config;
 pub mod device;
-pub mod test_frames;
 
 pub static DEVICE_NAME: &str = "Virtio-Sound";
 
 use alloc::fmt::Debug;
-use core::fmt::{self, Display, Formatter};
+use core::{
+    fmt::{self, Display, Formatter},
+    ops::RangeInclusive,
+};
 
+use aster_sound::{SampleFormat, StreamDirection};
 use bitflags::bitflags;
+use log::warn;
 use ostd::Pod;
+
+use crate::device::VirtioDeviceError;
 // jack control request types
 pub const VIRTIO_SND_R_JACK_INFO: u32 = 1;
 pub const VIRTIO_SND_R_JACK_REMAP: u32 = 2;
@@ -50,7 +56,7 @@ pub const VIRTIO_SND_S_BAD_MSG: u32 = 0x8001; // a control message is malformed
 pub const VIRTIO_SND_S_NOT_SUPP: u32 = 0x8002; // requested operation or parameters are not supported
 pub const VIRTIO_SND_S_IO_ERR: u32 = 0x8003; // an I/O error occurred
 
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[repr(u32)]
 pub enum RequestStatusCode {
     /* common status codes */
@@ -60,26 +66,102 @@ pub enum RequestStatusCode {
     IoErr,
 }
 
+impl TryFrom<u32> for RequestStatusCode {
+    /// The status code, if it is not one of the spec.
+    type Error = u32;
+
+    fn try_from(code: u32) -> Result<Self, Self::Error> {
+        match code {
+            VIRTIO_SND_S_OK => Ok(Self::Ok),
+            VIRTIO_SND_S_BAD_MSG => Ok(Self::BadMsg),
+            VIRTIO_SND_S_NOT_SUPP => Ok(Self::NotSupp),
+            VIRTIO_SND_S_IO_ERR => Ok(Self::IoErr),
+            _ => Err(code),
+        }
+    }
+}
+
 impl From<RequestStatusCode> for VirtioSndHdr {
     fn from(value: RequestStatusCode) -> Self {
-        VirtioSndHdr { code: value as _ }
+        VirtioSndHdr {
+            code: (value as u32).into(),
+        }
     }
 }
 
+macro_rules! le_int {
+    ($(#[$attr:meta])* $name:ident, $ty:ty) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Default, Pod, Eq, PartialEq)]
+        #[repr(C)]
+        pub struct $name($ty);
+
+        impl $name {
+            /// Stores `value` in little-endian.
+            pub const fn new(value: $ty) -> Self {
+                Self(value.to_le())
+            }
+
+            /// Returns the value in the endianness of the CPU.
+            pub const fn get(self) -> $ty {
+                <$ty>::from_le(self.0)
+            }
+        }
+
+        impl From<$ty> for $name {
+            fn from(value: $ty) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl From<$name> for $ty {
+            fn from(value: $name) -> Self {
+                value.get()
+            }
+        }
+
+        impl Debug for $name {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                Debug::fmt(&self.get(), f)
+            }
+        }
+    };
+}
+
+le_int!(
+    /// A `le16` field of the structures shared with the device.
+    ///
+    /// The device reads and writes the fields in little-endian whatever the
+    /// endianness of the CPU, so they are converted when they are accessed.
+    Le16,
+    u16
+);
+le_int!(
+    /// A `le32` field of the structures shared with the device.
+    Le32,
+    u32
+);
+le_int!(
+    /// A `le64` field of the structures shared with the device.
+    Le64,
+    u64
+);
+
 /// Virtio Sound Request / Response common header
 #[derive(Debug, Clone, Copy, Pod, Eq, PartialEq)]
 #[repr(C)]
 pub struct VirtioSndHdr {
     /// specifies a device request type (VIRTIO_SND_R_*) / response status (VIRTIO_SND_S_*)
-    /// p.s. use u32 to represent le32
-    pub code: u32,
+    pub code: Le32,
 }
 
 const SND_HDR_SIZE: usize = size_of::<VirtioSndHdr>();
 
 impl From<CommandCode> for VirtioSndHdr {
     fn from(value: CommandCode) -> Self {
-        VirtioSndHdr { code: value.into() }
+        VirtioSndHdr {
+            code: u32::from(value).into(),
+        }
     }
 }
 
@@ -88,32 +170,32 @@ impl From<CommandCode> for VirtioSndHdr {
 #[repr(C)]
 pub struct VirtioSndEvent {
     pub header: VirtioSndHdr, // indicates an event type (VIRTIO_SND_EVT_*)
-    pub data: u32,            // indicates an optional event data
+    pub data: Le32,           // indicates an optional event data
 }
 
 /// The notification type.
-#[repr(u32)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum NotificationType {
     /// An external device has been connected to the jack.
-    JackConnected = 0x1000,
+    JackConnected,
     /// An external device has been disconnected from the jack.
     JackDisconnected,
     /// A hardware buffer period has elapsed, the period size is controlled using the `period_bytes` field.
-    PcmPeriodElapsed = 0x1100,
+    PcmPeriodElapsed,
     /// An underflow for the output stream or an overflow for the inputstream has occurred.
     PcmXrun,
+    /// An event type the driver does not know, with its code.
+    Unknown(u32),
 }
 
-impl NotificationType {
-    /// Converts the given value to a variant of this enum, if any matches.
-    fn n(value: u32) -> Option<Self> {
-        match value {
-            0x1100 => Some(Self::PcmPeriodElapsed),
-            0x1101 => Some(Self::PcmXrun),
-            0x1000 => Some(Self::JackConnected),
-            0x1001 => Some(Self::JackDisconnected),
-            _ => None,
+impl From<u32> for NotificationType {
+    fn from(code: u32) -> Self {
+        match code {
+            VIRTIO_SND_EVT_JACK_CONNECTED => Self::JackConnected,
+            VIRTIO_SND_EVT_JACK_DISCONNECTED => Self::JackDisconnected,
+            VIRTIO_SND_EVT_PCM_PERIOD_ELAPSED => Self::PcmPeriodElapsed,
+            VIRTIO_SND_EVT_PCM_XRUN => Self::PcmXrun,
+            _ => Self::Unknown(code),
         }
     }
 }
@@ -125,6 +207,19 @@ pub struct Notification {
     data: u32,
 }
 
+/// Parses an event written by the device on the event queue.
+///
+/// An event of a type the driver does not know is kept as
+/// [`NotificationType::Unknown`], so that it can still be reported.
+impl From<VirtioSndEvent> for Notification {
+    fn from(event: VirtioSndEvent) -> Self {
+        Self {
+            notification_type: NotificationType::from(event.header.code.get()),
+            data: event.data.get(),
+        }
+    }
+}
+
 impl Notification {
     /// Get the resource index.
     pub fn data(&self) -> u32 {
@@ -184,14 +279,42 @@ impl From<CommandCode> for u32 {
     }
 }
 
+impl TryFrom<u32> for CommandCode {
+    /// The code, if it is not one of the spec.
+    type Error = u32;
+
+    fn try_from(code: u32) -> Result<Self, Self::Error> {
+        match code {
+            VIRTIO_SND_R_JACK_INFO => Ok(Self::RJackInfo),
+            VIRTIO_SND_R_JACK_REMAP => Ok(Self::RJackRemap),
+            VIRTIO_SND_R_PCM_INFO => Ok(Self::RPcmInfo),
+            VIRTIO_SND_R_PCM_SET_PARAMS => Ok(Self::RPcmSetParams),
+            VIRTIO_SND_R_PCM_PREPARE => Ok(Self::RPcmPrepare),
+            VIRTIO_SND_R_PCM_RELEASE => Ok(Self::RPcmRelease),
+            VIRTIO_SND_R_PCM_START => Ok(Self::RPcmStart),
+            VIRTIO_SND_R_PCM_STOP => Ok(Self::RPcmStop),
+            VIRTIO_SND_R_CHMAP_INFO => Ok(Self::RChmapInfo),
+            VIRTIO_SND_EVT_JACK_CONNECTED => Ok(Self::EvtJackConnected),
+            VIRTIO_SND_EVT_JACK_DISCONNECTED => Ok(Self::EvtJackDisconnected),
+            VIRTIO_SND_EVT_PCM_PERIOD_ELAPSED => Ok(Self::EvtPcmPeriodElapsed),
+            VIRTIO_SND_EVT_PCM_XRUN => Ok(Self::EvtPcmXrun),
+            VIRTIO_SND_S_OK => Ok(Self::SOk),
+            VIRTIO_SND_S_BAD_MSG => Ok(Self::SBadMsg),
+            VIRTIO_SND_S_NOT_SUPP => Ok(Self::SNotSupp),
+            VIRTIO_SND_S_IO_ERR => Ok(Self::SIoErr),
+            _ => Err(code),
+        }
+    }
+}
+
 /// Virtio Sound request information about any kind of configuration item (A special control message)
 #[derive(Debug, Clone, Copy, Pod)]
 #[repr(C)]
 pub struct VirtioSndQueryInfo {
     pub hdr: VirtioSndHdr, // a particular item request type (VIRTIO_SND_R_*_INFO)
-    pub start_id: u32,     // starting identifier for the item
-    pub count: u32,        // number of items for which information is requested
-    pub size: u32,         // size of the structure containing information for one item
+    pub start_id: Le32,    // starting identifier for the item
+    pub count: Le32,       // number of items for which information is requested
+    pub size: Le32,        // size of the structure containing information for one item
 }
 
 #[derive(Debug, Clone, Copy, Pod)]
@@ -205,7 +328,7 @@ struct VirtIOSndQueryInfoRsp {
 #[derive(Debug, Clone, Copy, Pod, Eq, PartialEq)]
 #[repr(C)]
 pub struct VirtioSndInfo {
-    pub hda_fn_nid: u32, // a function group node identifier (Used to link together different types of resources)
+    pub hda_fn_nid: Le32, // a function group node identifier (Used to link together different types of resources)
 }
 
 // supported PCM stream features
@@ -359,6 +482,43 @@ pub enum PcmFormat {
     Iec958Subframe = 24,
 }
 
+impl PcmFormat {
+    /// Returns the number of bytes a sample takes in a frame, or `None` for the
+    /// compressed formats whose samples are not byte-aligned.
+    pub fn bytes_per_sample(self) -> Option<usize> {
+        match self {
+            PcmFormat::ImaAdpcm => None,
+            PcmFormat::MuLaw | PcmFormat::ALaw | PcmFormat::S8 | PcmFormat::U8 => Some(1),
+            PcmFormat::DsdU8 => Some(1),
+            PcmFormat::S16 | PcmFormat::U16 | PcmFormat::DsdU16 => Some(2),
+            PcmFormat::S18_3
+            | PcmFormat::U18_3
+            | PcmFormat::S20_3
+            | PcmFormat::U20_3
+            | PcmFormat::S24_3
+            | PcmFormat::U24_3 => Some(3),
+            PcmFormat::S20
+            | PcmFormat::U20
+            | PcmFormat::S24
+            | PcmFormat::U24
+            | PcmFormat::S32
+            | PcmFormat::U32
+            | PcmFormat::FLOAT
+            | PcmFormat::DsdU32
+            | PcmFormat::Iec958Subframe => Some(4),
+            PcmFormat::FLOAT64 => Some(8),
+        }
+    }
+}
+
+/// Returns the number of bytes a frame of `channels` samples in `format` takes,
+/// or `None` if the samples of the format are not byte-aligned.
+pub fn frame_bytes(format: PcmFormat, channels: u8) -> Option<usize> {
+    format
+        .bytes_per_sample()
+        .map(|sample_bytes| sample_bytes * channels as usize)
+}
+
 impl From<PcmFormat> for PcmFormats {
     fn from(format: PcmFormat) -> Self {
         match format {
@@ -397,12 +557,44 @@ impl From<PcmFormat> for u8 {
     }
 }
 
+impl From<SampleFormat> for PcmFormat {
+    fn from(format: SampleFormat) -> Self {
+        match format {
+            SampleFormat::ImaAdpcm => PcmFormat::ImaAdpcm,
+            SampleFormat::MuLaw => PcmFormat::MuLaw,
+            SampleFormat::ALaw => PcmFormat::ALaw,
+            SampleFormat::S8 => PcmFormat::S8,
+            SampleFormat::U8 => PcmFormat::U8,
+            SampleFormat::S16 => PcmFormat::S16,
+            SampleFormat::U16 => PcmFormat::U16,
+            SampleFormat::S18_3 => PcmFormat::S18_3,
+            SampleFormat::U18_3 => PcmFormat::U18_3,
+            SampleFormat::S20_3 => PcmFormat::S20_3,
+            SampleFormat::U20_3 => PcmFormat::U20_3,
+            SampleFormat::S24_3 => PcmFormat::S24_3,
+            SampleFormat::U24_3 => PcmFormat::U24_3,
+            SampleFormat::S20 => PcmFormat::S20,
+            SampleFormat::U20 => PcmFormat::U20,
+            SampleFormat::S24 => PcmFormat::S24,
+            SampleFormat::U24 => PcmFormat::U24,
+            SampleFormat::S32 => PcmFormat::S32,
+            SampleFormat::U32 => PcmFormat::U32,
+            SampleFormat::Float => PcmFormat::FLOAT,
+            SampleFormat::Float64 => PcmFormat::FLOAT64,
+            SampleFormat::DsdU8 => PcmFormat::DsdU8,
+            SampleFormat::DsdU16 => PcmFormat::DsdU16,
+            SampleFormat::DsdU32 => PcmFormat::DsdU32,
+            SampleFormat::Iec958Subframe => PcmFormat::Iec958Subframe,
+        }
+    }
+}
+
 /// PCM control request / PCM common header
 #[derive(Debug, Clone, Copy, Pod)]
 #[repr(C)]
 pub struct VirtioSndPcmHdr {
     pub hdr: VirtioSndHdr, // request type (VIRTIO_SND_R_PCM_*)
-    pub stream_id: u32,    // PCM stream identifier from 0 to streams - 1
+    pub stream_id: Le32,   // PCM stream identifier from 0 to streams - 1
 }
 
 // supported PCM frame rates
@@ -498,6 +690,41 @@ impl From<PcmRate> for PcmRates {
     }
 }
 
+impl PcmRate {
+    /// Get the frequency of the PCM rate in Hz.
+    pub fn hz(self) -> u32 {
+        PCM_RATES_HZ[self as usize]
+    }
+
+    /// Get the PCM rate running at the given frequency in Hz, if any.
+    pub fn from_hz(hz: u32) -> Option<Self> {
+        let rate = match hz {
+            5512 => Self::Rate5512,
+            8000 => Self::Rate8000,
+            11025 => Self::Rate11025,
+            16000 => Self::Rate16000,
+            22050 => Self::Rate22050,
+            32000 => Self::Rate32000,
+            44100 => Self::Rate44100,
+            48000 => Self::Rate48000,
+            64000 => Self::Rate64000,
+            88200 => Self::Rate88200,
+            96000 => Self::Rate96000,
+            176400 => Self::Rate176400,
+            192000 => Self::Rate192000,
+            384000 => Self::Rate384000,
+            _ => return None,
+        };
+        Some(rate)
+    }
+}
+
+/// The frame rate in Hz of each `VIRTIO_SND_PCM_RATE_*` value.
+const PCM_RATES_HZ: [u32; 14] = [
+    5512, 8000, 11025, 16000, 22050, 32000, 44100, 48000, 64000, 88200, 96000, 176400, 192000,
+    384000,
+];
+
 impl From<PcmRate> for u8 {
     fn from(rate: PcmRate) -> Self {
         rate as _
@@ -509,10 +736,10 @@ impl From<PcmRate> for u8 {
 #[repr(C)]
 pub struct VirtioSndPcmInfo {
     pub hdr: VirtioSndInfo,
-    pub features: u32, // a bit map of the supported features /* 1 << VIRTIO_SND_PCM_F_XXX */
-    pub formats: u64,  // supported sample format bit map /* 1 << VIRTIO_SND_PCM_FMT_XXX */
-    pub rates: u64,    // supported frame rate bit map /* 1 << VIRTIO_SND_PcmRate_XXX */
-    pub direction: u8, // the direction of data flow (VIRTIO_SND_D_*)
+    pub features: Le32, // a bit map of the supported features /* 1 << VIRTIO_SND_PCM_F_XXX */
+    pub formats: Le64,  // supported sample format bit map /* 1 << VIRTIO_SND_PCM_FMT_XXX */
+    pub rates: Le64,    // supported frame rate bit map /* 1 << VIRTIO_SND_PcmRate_XXX */
+    pub direction: u8,  // the direction of data flow (VIRTIO_SND_D_*)
     pub channels_min: u8, // minimum number of supported channels
     pub channels_max: u8, // maximum number of supported channels
 
@@ -525,9 +752,9 @@ impl Debug for VirtioSndPcmInfo {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         f.debug_struct("VirtIOSndPcmInfo")
             .field("hdr", &self.hdr)
-            .field("features", &PcmFeatures::from_bits(self.features))
-            .field("formats", &PcmFormats::from_bits(self.formats))
-            .field("rates", &PcmRates::from_bits(self.rates))
+            .field("features", &self.features())
+            .field("formats", &self.formats())
+            .field("rates", &self.rates())
             .field("direction", &self.direction)
             .field("channels_min", &self.channels_min)
             .field("channels_max", &self.channels_max)
@@ -538,22 +765,159 @@ impl Debug for VirtioSndPcmInfo {
 
 impl Display for VirtioSndPcmInfo {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let direction = if self.direction == VIRTIO_SND_D_INPUT {
-            "INPUT"
-        } else {
-            "OUTPUT"
+        let direction = match self.direction() {
+            StreamDirection::Input => "INPUT",
+            StreamDirection::Output => "OUTPUT",
         };
         write!(
             f,
             "features: {:?}, rates: {:?}, formats: {:?}, direction: {}",
-            PcmFeatures::from_bits(self.features),
-            PcmRates::from_bits(self.rates),
-            PcmFormats::from_bits(self.formats),
+            self.features(),
+            self.rates(),
+            self.formats(),
             direction
         )
     }
 }
 
+impl VirtioSndPcmInfo {
+    /// Returns the direction of data flow of the stream.
+    pub fn direction(&self) -> StreamDirection {
+        match self.direction {
+            VIRTIO_SND_D_OUTPUT => StreamDirection::Output,
+            VIRTIO_SND_D_INPUT => StreamDirection::Input,
+            // The spec has no other direction.
+            _ => StreamDirection::Output,
+        }
+    }
+
+    /// Returns the features the stream supports.
+    ///
+    /// Like the formats and the rates, the bits the driver does not know, e.g.,
+    /// reserved ones set by the device, are ignored.
+    pub fn features(&self) -> PcmFeatures {
+        PcmFeatures::from_bits_truncate(self.features.get())
+    }
+
+    /// Returns the sample formats the stream supports.
+    pub fn formats(&self) -> PcmFormats {
+        PcmFormats::from_bits_truncate(self.formats.get())
+    }
+
+    /// Returns the frame rates the stream supports.
+    pub fn rates(&self) -> PcmRates {
+        PcmRates::from_bits_truncate(self.rates.get())
+    }
+
+    /// Returns the range of the numbers of channels the stream supports.
+    pub fn channel_range(&self) -> RangeInclusive<u8> {
+        self.channels_min..=self.channels_max
+    }
+}
+
+/// The direction and the capabilities of a PCM stream, as reported by the device.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StreamInfo {
+    pub direction: StreamDirection,
+    pub formats: PcmFormats,
+    pub rates: PcmRates,
+    pub channels: RangeInclusive<u8>,
+    pub features: PcmFeatures,
+    /// The function group node the stream belongs to, e.g., to tell which jacks
+    /// are wired to it.
+    pub hda_fn_nid: u32,
+}
+
+impl From<&VirtioSndPcmInfo> for StreamInfo {
+    fn from(pcm_info: &VirtioSndPcmInfo) -> Self {
+        StreamInfo {
+            direction: pcm_info.direction(),
+            formats: pcm_info.formats(),
+            rates: pcm_info.rates(),
+            channels: pcm_info.channel_range(),
+            features: pcm_info.features(),
+            hda_fn_nid: pcm_info.hdr.hda_fn_nid.get(),
+        }
+    }
+}
+
+/// Jack response information
+#[derive(Debug, Clone, Copy, Pod)]
+#[repr(C)]
+pub struct VirtioSndJackInfo {
+    pub hdr: VirtioSndInfo,
+    pub features: Le32, // a bit map of the supported features /* 1 << VIRTIO_SND_JACK_F_XXX */
+    pub hda_reg_defconf: Le32, // a pin default configuration value
+    pub hda_reg_caps: Le32, // a pin capabilities value
+    pub connected: u8,  // the current jack connection status (1 - connected, 0 - disconnected)
+
+    pub padding: [u8; 7],
+}
+
+impl VirtioSndJackInfo {
+    /// Returns the features the jack supports.
+    pub fn features(&self) -> JackFeatures {
+        JackFeatures::from_bits_truncate(self.features.get())
+    }
+
+    /// Returns whether an external device is connected to the jack.
+    pub fn is_connected(&self) -> bool {
+        self.connected != 0
+    }
+}
+
+impl Display for VirtioSndJackInfo {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "features: {:?}, hda_reg_defconf: {:#010x}, hda_reg_caps: {:#010x}, connected: {}",
+            self.features(),
+            self.hda_reg_defconf.get(),
+            self.hda_reg_caps.get(),
+            self.is_connected()
+        )
+    }
+}
+
+bitflags! {
+    /// Supported jack features.
+    #[derive(Default)]
+    #[repr(transparent)]
+    pub struct JackFeatures: u32 {
+        /// Supports jack remapping.
+        const REMAP = 1 << 0;
+    }
+}
+
+/// Jack control request / jack common header
+#[derive(Debug, Clone, Copy, Pod)]
+#[repr(C)]
+pub struct VirtioSndJackHdr {
+    pub hdr: VirtioSndHdr, // request type (VIRTIO_SND_R_JACK_*)
+    pub jack_id: Le32,     // a jack identifier from 0 to jacks - 1
+}
+
+/// Remap the association and the sequence of the specified jack
+#[derive(Debug, Clone, Copy, Pod)]
+#[repr(C)]
+pub struct VirtioSndJackRemap {
+    pub hdr: VirtioSndJackHdr, // VIRTIO_SND_R_JACK_REMAP
+    pub association: Le32,     // the selected association number
+    pub sequence: Le32,        // the selected sequence number
+}
+
+impl Display for VirtioSndJackRemap {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "jack_id: {}, association: {}, sequence: {}",
+            self.hdr.jack_id.get(),
+            self.association.get(),
+            self.sequence.get()
+        )
+    }
+}
+
 #[repr(u32)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ItemInformationRequestType {
@@ -567,7 +931,9 @@ pub enum ItemInformationRequestType {
 
 impl From<ItemInformationRequestType> for VirtioSndHdr {
     fn from(value: ItemInformationRequestType) -> Self {
-        VirtioSndHdr { code: value.into() }
+        VirtioSndHdr {
+            code: u32::from(value).into(),
+        }
     }
 }
 
@@ -624,12 +990,12 @@ pub const VIRTIO_SND_CHMAP_MAX_SIZE: usize = 18;
 #[repr(C)]
 pub struct VirtioSndPcmSetParams {
     pub hdr: VirtioSndPcmHdr, //
-    pub buffer_bytes: u32,    // the size of the hardware buffer used by the driver
-    pub period_bytes: u32,    // the size of the hardware period used by the driver
-    pub features: u32, // specifies a selected feature bit map /* 1 << VIRTIO_SND_PCM_F_XXX */
-    pub channels: u8,  // a selected number of channels
-    pub format: u8,    // a selected sample format (VIRTIO_SND_PCM_FMT_*).
-    pub rate: u8,      // a selected frame rate (VIRTIO_SND_PcmRate_*).
+    pub buffer_bytes: Le32,   // the size of the hardware buffer used by the driver
+    pub period_bytes: Le32,   // the size of the hardware period used by the driver
+    pub features: Le32, // specifies a selected feature bit map /* 1 << VIRTIO_SND_PCM_F_XXX */
+    pub channels: u8,   // a selected number of channels
+    pub format: u8,     // a selected sample format (VIRTIO_SND_PCM_FMT_*).
+    pub rate: u8,       // a selected frame rate (VIRTIO_SND_PcmRate_*).
     pub padding: u8,
 }
 
@@ -637,15 +1003,23 @@ pub struct VirtioSndPcmSetParams {
 #[derive(Debug, Clone, Copy, Pod)]
 #[repr(C)]
 pub struct VirtioSndPcmXfer {
-    pub stream_id: u32, // a PCM stream identifier from 0 to streams - 1
+    pub stream_id: Le32, // a PCM stream identifier from 0 to streams - 1
+}
+
+impl VirtioSndPcmXfer {
+    pub fn new(stream_id: u32) -> Self {
+        Self {
+            stream_id: stream_id.into(),
+        }
+    }
 }
 
 /// PCM I/O status
 #[derive(Debug, Clone, Copy, Pod, Default)]
 #[repr(C)]
 pub struct VirtioSndPcmStatus {
-    pub status: u32, // contains VIRTIO_SND_S_OK if an operation is successful, and VIRTIO_SND_S_IO_ERR otherwise.
-    pub latency_bytes: u32, // indicates the current device latency
+    pub status: Le32, // contains VIRTIO_SND_S_OK if an operation is successful, and VIRTIO_SND_S_IO_ERR otherwise.
+    pub latency_bytes: Le32, // indicates the current device latency
 }
 
 // channel maps response information
@@ -688,6 +1062,217 @@ impl Display for VirtioSndChmapInfo {
     }
 }
 
+// supported roles for control elements
+pub const VIRTIO_SND_CTL_ROLE_UNDEFINED: u32 = 0;
+pub const VIRTIO_SND_CTL_ROLE_VOLUME: u32 = 1;
+pub const VIRTIO_SND_CTL_ROLE_MUTE: u32 = 2;
+pub const VIRTIO_SND_CTL_ROLE_GAIN: u32 = 3;
+
+// supported value types for control elements
+pub const VIRTIO_SND_CTL_TYPE_BOOLEAN: u32 = 0;
+pub const VIRTIO_SND_CTL_TYPE_INTEGER: u32 = 1;
+pub const VIRTIO_SND_CTL_TYPE_INTEGER64: u32 = 2;
+pub const VIRTIO_SND_CTL_TYPE_ENUMERATED: u32 = 3;
+pub const VIRTIO_SND_CTL_TYPE_BYTES: u32 = 4;
+pub const VIRTIO_SND_CTL_TYPE_IEC958: u32 = 5;
+
+// supported access rights for control elements
+pub const VIRTIO_SND_CTL_ACCESS_READ: u32 = 0;
+pub const VIRTIO_SND_CTL_ACCESS_WRITE: u32 = 1;
+pub const VIRTIO_SND_CTL_ACCESS_VOLATILE: u32 = 2;
+pub const VIRTIO_SND_CTL_ACCESS_INACTIVE: u32 = 3;
+pub const VIRTIO_SND_CTL_ACCESS_TLV_READ: u32 = 4;
+pub const VIRTIO_SND_CTL_ACCESS_TLV_WRITE: u32 = 5;
+pub const VIRTIO_SND_CTL_ACCESS_TLV_COMMAND: u32 = 6;
+
+// control element event masks
+pub const VIRTIO_SND_CTL_EVT_MASK_VALUE: u16 = 0; /* the value was changed */
+pub const VIRTIO_SND_CTL_EVT_MASK_INFO: u16 = 1; /* the information was changed */
+pub const VIRTIO_SND_CTL_EVT_MASK_TLV: u16 = 2; /* the metadata was changed */
+
+// maximum length of a control element name, including the terminating NUL
+pub const VIRTIO_SND_CTL_NAME_MAX_SIZE: usize = 44;
+
+// size of the value of a control element
+pub const VIRTIO_SND_CTL_VALUE_SIZE: usize = 512;
+
+/// Control element request / control element common header
+#[derive(Debug, Clone, Copy, Pod)]
+#[repr(C)]
+pub struct VirtioSndCtlHdr {
+    pub hdr: VirtioSndHdr, // request type (VIRTIO_SND_R_CTL_*)
+    pub control_id: Le32,  // a control element identifier from 0 to controls - 1
+}
+
+/// Control element response information
+#[derive(Debug, Clone, Copy, Pod)]
+#[repr(C)]
+pub struct VirtioSndCtlInfo {
+    pub hdr: VirtioSndInfo,
+    pub role: Le32,   // the role of the element (VIRTIO_SND_CTL_ROLE_*)
+    pub r#type: Le32, // the type of the element value (VIRTIO_SND_CTL_TYPE_*)
+    pub access: Le32, // a bit map of the access rights /* 1 << VIRTIO_SND_CTL_ACCESS_XXX */
+    pub count: Le32,  // the number of members in the element value
+    pub index: Le32,  // the index of the element among the ones of the same name
+    pub name: [u8; VIRTIO_SND_CTL_NAME_MAX_SIZE], // the NUL-terminated name of the element
+    pub padding: [u8; 4],
+    // the range of an integer, integer64 or enumerated value, laid out as
+    // `{ le32 min, max, step }`, `{ le64 min, max, step }` or `{ le32 items }`
+    pub value: [u8; 24],
+}
+
+impl VirtioSndCtlInfo {
+    /// Returns the name of the element, or an empty string if it is not valid UTF-8.
+    pub fn name(&self) -> &str {
+        let len = self
+            .name
+            .iter()
+            .position(|&byte| byte == 0)
+            .unwrap_or(self.name.len());
+        core::str::from_utf8(&self.name[..len]).unwrap_or("")
+    }
+
+    /// Returns whether the element has the access right (VIRTIO_SND_CTL_ACCESS_*).
+    pub fn has_access(&self, access: u32) -> bool {
+        access < u32::BITS && self.access.get() & (1 << access) != 0
+    }
+
+    /// Returns the minimum, the maximum and the step of an integer value.
+    pub fn integer_range(&self) -> (i32, i32, i32) {
+        let field =
+            |i: usize| i32::from_le_bytes(self.value[i * 4..(i + 1) * 4].try_into().unwrap());
+        (field(0), field(1), field(2))
+    }
+
+    /// Returns the minimum, the maximum and the step of an integer64 value.
+    pub fn integer64_range(&self) -> (i64, i64, i64) {
+        let field =
+            |i: usize| i64::from_le_bytes(self.value[i * 8..(i + 1) * 8].try_into().unwrap());
+        (field(0), field(1), field(2))
+    }
+
+    /// Returns the number of items of an enumerated value.
+    pub fn enumerated_items(&self) -> u32 {
+        u32::from_le_bytes(self.value[..4].try_into().unwrap())
+    }
+}
+
+impl Display for VirtioSndCtlInfo {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let role = match self.role.get() {
+            VIRTIO_SND_CTL_ROLE_VOLUME => "VOLUME",
+            VIRTIO_SND_CTL_ROLE_MUTE => "MUTE",
+            VIRTIO_SND_CTL_ROLE_GAIN => "GAIN",
+            _ => "UNDEFINED",
+        };
+        write!(
+            f,
+            "name: {:?}, index: {}, role: {}, access: {:#x}, count: {}, ",
+            self.name(),
+            self.index.get(),
+            role,
+            self.access.get(),
+            self.count.get()
+        )?;
+        match self.r#type.get() {
+            VIRTIO_SND_CTL_TYPE_BOOLEAN => write!(f, "type: BOOLEAN"),
+            VIRTIO_SND_CTL_TYPE_INTEGER => {
+                let (min, max, step) = self.integer_range();
+                write!(f, "type: INTEGER [{}..={}, step {}]", min, max, step)
+            }
+            VIRTIO_SND_CTL_TYPE_INTEGER64 => {
+                let (min, max, step) = self.integer64_range();
+                write!(f, "type: INTEGER64 [{}..={}, step {}]", min, max, step)
+            }
+            VIRTIO_SND_CTL_TYPE_ENUMERATED => {
+                write!(f, "type: ENUMERATED ({} items)", self.enumerated_items())
+            }
+            VIRTIO_SND_CTL_TYPE_BYTES => write!(f, "type: BYTES"),
+            VIRTIO_SND_CTL_TYPE_IEC958 => write!(f, "type: IEC958"),
+            r#type => write!(f, "type: {}", r#type),
+        }
+    }
+}
+
+/// The name of an item of an enumerated control element
+#[derive(Debug, Clone, Copy, Pod)]
+#[repr(C)]
+pub struct VirtioSndCtlEnumItem {
+    pub item: [u8; 64], // the NUL-terminated name of the item
+}
+
+/// Control element value
+///
+/// Depending on the type of the element, the value is an array of `count`
+/// `le32` (boolean, integer or enumerated), `le64` (integer64) or bytes, or an
+/// IEC 60958 status.
+#[derive(Debug, Clone, Copy, Pod)]
+#[repr(C)]
+pub struct VirtioSndCtlValue {
+    pub value: [u8; VIRTIO_SND_CTL_VALUE_SIZE],
+}
+
+impl VirtioSndCtlValue {
+    /// Returns the `index`-th member of a boolean, integer or enumerated value.
+    pub fn integer(&self, index: usize) -> i32 {
+        i32::from_le_bytes(self.value[index * 4..(index + 1) * 4].try_into().unwrap())
+    }
+
+    /// Sets the `index`-th member of a boolean, integer or enumerated value.
+    pub fn set_integer(&mut self, index: usize, value: i32) {
+        self.value[index * 4..(index + 1) * 4].copy_from_slice(&value.to_le_bytes());
+    }
+
+    /// Returns the `index`-th member of an integer64 value.
+    pub fn integer64(&self, index: usize) -> i64 {
+        i64::from_le_bytes(self.value[index * 8..(index + 1) * 8].try_into().unwrap())
+    }
+
+    /// Sets the `index`-th member of an integer64 value.
+    pub fn set_integer64(&mut self, index: usize, value: i64) {
+        self.value[index * 8..(index + 1) * 8].copy_from_slice(&value.to_le_bytes());
+    }
+}
+
+impl Default for VirtioSndCtlValue {
+    fn default() -> Self {
+        Self::new_zeroed()
+    }
+}
+
+impl Display for VirtioSndCtlValue {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "value: [")?;
+        for (i, byte) in self.value.iter().enumerate() {
+            if i != 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{:02x}", byte)?;
+        }
+        write!(f, "]")
+    }
+}
+
+/// Control element notification
+#[derive(Debug, Clone, Copy, Pod)]
+#[repr(C)]
+pub struct VirtioSndCtlEvent {
+    pub hdr: VirtioSndHdr, // VIRTIO_SND_EVT_CTL_NOTIFY
+    pub control_id: Le16,  // a control element identifier from 0 to controls - 1
+    pub mask: Le16,        // a bit map of what changed /* 1 << VIRTIO_SND_CTL_EVT_MASK_XXX */
+}
+
+impl Display for VirtioSndCtlEvent {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "control_id: {}, mask: {:#x}",
+            self.control_id.get(),
+            self.mask.get()
+        )
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PcmParameters {
     setup: bool,
@@ -713,6 +1298,137 @@ impl Default for PcmParameters {
     }
 }
 
+/// A builder of the parameters of a PCM stream.
+///
+/// The parameters are validated against the capabilities the stream advertises
+/// before they are turned into a [`VirtioSndPcmSetParams`] request.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PcmParamsBuilder {
+    buffer_bytes: u32,
+    period_bytes: u32,
+    features: PcmFeatures,
+    channels: u8,
+    format: PcmFormat,
+    rate: PcmRate,
+}
+
+impl PcmParamsBuilder {
+    /// Creates a builder of the parameters of a stream playing or capturing
+    /// `channels` channels of samples in `format` at `rate`.
+    ///
+    /// The buffer and the period sizes default to those of [`PcmParameters`], and
+    /// no feature is requested.
+    pub fn new(format: PcmFormat, rate: PcmRate, channels: u8) -> Self {
+        let defaults = PcmParameters::default();
+        Self {
+            buffer_bytes: defaults.buffer_bytes,
+            period_bytes: defaults.period_bytes,
+            features: PcmFeatures::empty(),
+            channels,
+            format,
+            rate,
+        }
+    }
+
+    /// Sets the size of the hardware buffer in bytes.
+    pub fn buffer_bytes(mut self, buffer_bytes: u32) -> Self {
+        self.buffer_bytes = buffer_bytes;
+        self
+    }
+
+    /// Sets the size of a period in bytes.
+    pub fn period_bytes(mut self, period_bytes: u32) -> Self {
+        self.period_bytes = period_bytes;
+        self
+    }
+
+    /// Sets the features requested for the stream.
+    pub fn features(mut self, features: PcmFeatures) -> Self {
+        self.features = features;
+        self
+    }
+
+    /// Validates the parameters against `pcm_info`, the information of the stream
+    /// `stream_id`, and builds the request setting them.
+    ///
+    /// The buffer must hold a whole, nonzero number of periods. The features, the
+    /// format, the rate and the number of channels must be supported by the stream;
+    /// what the stream advertises is logged when they are not, instead of the
+    /// device rejecting them with an opaque error.
+    pub fn build(
+        &self,
+        stream_id: u32,
+        pcm_info: &VirtioSndPcmInfo,
+    ) -> Result<VirtioSndPcmSetParams, VirtioDeviceError> {
+        if self.period_bytes == 0
+            || self.period_bytes > self.buffer_bytes
+            || self.buffer_bytes % self.period_bytes != 0
+        {
+            return Err(VirtioDeviceError::InvalidParam);
+        }
+        let supported_features = pcm_info.features();
+        let formats = pcm_info.formats();
+        let rates = pcm_info.rates();
+        let channels_range = pcm_info.channel_range();
+        if !(supported_features.contains(self.features)
+            && formats.contains(self.format.into())
+            && rates.contains(self.rate.into())
+            && channels_range.contains(&self.channels))
+        {
+            warn!(
+                "[sound device] stream {} does not support {:?}, {:?}, {:?} with {} channels; \
+                 it advertises {:?}, {:?}, {:?} with {:?} channels",
+                stream_id,
+                self.features,
+                self.format,
+                self.rate,
+                self.channels,
+                supported_features,
+                formats,
+                rates,
+                channels_range
+            );
+            return Err(VirtioDeviceError::Unsupported);
+        }
+        Ok(VirtioSndPcmSetParams {
+            hdr: VirtioSndPcmHdr {
+                hdr: CommandCode::RPcmSetParams.into(),
+                stream_id: stream_id.into(),
+            },
+            buffer_bytes: self.buffer_bytes.into(),
+            period_bytes: self.period_bytes.into(),
+            features: self.features.bits().into(),
+            channels: self.channels,
+            format: self.format.into(),
+            rate: self.rate.into(),
+            padding: 0,
+        })
+    }
+}
+
+impl From<&PcmParameters> for PcmParamsBuilder {
+    fn from(params: &PcmParameters) -> Self {
+        PcmParamsBuilder::new(params.format, params.rate, params.channels)
+            .buffer_bytes(params.buffer_bytes)
+            .period_bytes(params.period_bytes)
+            .features(params.features)
+    }
+}
+
+impl From<&PcmParamsBuilder> for PcmParameters {
+    fn from(builder: &PcmParamsBuilder) -> Self {
+        PcmParameters {
+            setup: true,
+            buffer_bytes: builder.buffer_bytes,
+            period_bytes: builder.period_bytes,
+            features: builder.features,
+            channels: builder.channels,
+            format: builder.format,
+            rate: builder.rate,
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[repr(u8)]
 enum ChannelPosition {
@@ -848,3 +1564,25 @@ pub enum PCMState {
     Start,
     Stop,
 }
+
+impl PCMState {
+    /// Returns whether a stream in this state accepts the request moving it to `next`.
+    ///
+    /// The transitions follow the PCM command lifecycle of the virtio-sound specification.
+    pub fn can_transition_to(self, next: PCMState) -> bool {
+        use PCMState::*;
+        matches!(
+            (self, next),
+            (SetParameters | Prepare | Release, SetParameters | Prepare)
+                | (Prepare | Stop, Start | Release)
+                | (Start, Stop)
+        )
+    }
+
+    /// Returns whether frames can be transferred on a stream in this state.
+    ///
+    /// Periods may be queued on a prepared stream before it is started.
+    pub fn can_transfer(self) -> bool {
+        matches!(self, PCMState::Prepare | PCMState::Start)
+    }
+}
@@ -1,3774 +0,0 @@
-pub const TEST_FRAMES_A4: [u8; 80000] = [
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88,
-    50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1,
-    11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63,
-    104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182,
-    217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253,
-    252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214,
-    178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100,
-    59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1,
-    7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92,
-    135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208,
-    237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254,
-    242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189,
-    147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70,
-    35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4,
-    20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81,
-    124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199,
-    230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255,
-    247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199,
-    159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81,
-    44, 16, 2, 3, 18, 47, 84, 128, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2,
-    14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70,
-    112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189,
-    223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254,
-    251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208,
-    171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92,
-    53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1,
-    9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100,
-    143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214,
-    241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253,
-    239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182,
-    139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63,
-    30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6,
-    25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88,
-    131, 174, 211, 239, 253, 252, 237, 208, 171, 128, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205,
-    235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254,
-    244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192,
-    151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73,
-    38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3,
-    18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77,
-    120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196,
-    228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254,
-    248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202,
-    163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84,
-    47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1,
-    13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66,
-    108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185,
-    220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253,
-    251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84, 127, 171, 208, 237, 252, 253, 239, 211,
-    174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202, 233, 251, 254, 242, 217, 182, 139, 96,
-    56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254, 246, 223, 189, 147, 104, 63, 30, 8, 1,
-    8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196, 155, 112, 70, 35, 11, 1, 6, 25, 56, 96,
-    139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77, 41, 14, 2, 4, 20, 50, 88, 131, 174, 211,
-    239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2, 16, 44, 81, 124, 167, 205, 235, 251, 253,
-    241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73, 116, 159, 199, 230, 249, 254, 244, 220, 185,
-    143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192, 225, 247, 255, 247, 225, 192, 151, 108, 66,
-    32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254, 249, 230, 199, 159, 116, 73, 38, 13, 1, 4,
-    22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205, 167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-    127, 171, 208, 237, 252, 253, 239, 211, 174, 131, 88, 50, 20, 4, 2, 14, 41, 77, 120, 163, 202,
-    233, 251, 254, 242, 217, 182, 139, 96, 56, 25, 6, 1, 11, 35, 70, 112, 155, 196, 228, 248, 254,
-    246, 223, 189, 147, 104, 63, 30, 8, 1, 8, 30, 63, 104, 147, 189, 223, 246, 254, 248, 228, 196,
-    155, 112, 70, 35, 11, 1, 6, 25, 56, 96, 139, 182, 217, 242, 254, 251, 233, 202, 163, 120, 77,
-    41, 14, 2, 4, 20, 50, 88, 131, 174, 211, 239, 253, 252, 237, 208, 171, 127, 84, 47, 18, 3, 2,
-    16, 44, 81, 124, 167, 205, 235, 251, 253, 241, 214, 178, 135, 92, 53, 22, 4, 1, 13, 38, 73,
-    116, 159, 199, 230, 249, 254, 244, 220, 185, 143, 100, 59, 27, 7, 1, 9, 32, 66, 108, 151, 192,
-    225, 247, 255, 247, 225, 192, 151, 108, 66, 32, 9, 1, 7, 27, 59, 100, 143, 185, 220, 244, 254,
-    249, 230, 199, 159, 116, 73, 38, 13, 1, 4, 22, 53, 92, 135, 178, 214, 241, 253, 251, 235, 205,
-    167, 124, 81, 44, 16, 2, 3, 18, 47, 84,
-];
@@ -31,7 +31,9 @@ use crate::transport::VirtioTransport;
 
 pub mod device;
 mod dma_buf;
+pub mod pm;
 pub mod queue;
+pub mod self_test;
 mod transport;
 
 #[init_component]
@@ -109,8 +111,11 @@ fn negotiate_features(transport: &mut Box<dyn VirtioTransport>) {
         VirtioDeviceType::Sound => SoundDevice::negotiate_features(device_specified_features),
         _ => device_specified_features,
     };
-    let mut support_feature = Feature::from_bits_truncate(features);
-    support_feature.remove(Feature::RING_EVENT_IDX);
+    // `VirtQueue` chains the buffers of a request in an indirect table when
+    // `RING_INDIRECT_DESC` is accepted, suppresses notifications and interrupts
+    // with the event index when `RING_EVENT_IDX` is, and lays out its rings in
+    // the packed layout when `RING_PACKED` is.
+    let support_feature = Feature::from_bits_truncate(features);
     transport
         .write_driver_features(features & (support_feature.bits | device_support_features))
         .unwrap();
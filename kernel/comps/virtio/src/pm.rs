@@ -0,0 +1,68 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! Power management of the virtio devices.
+//!
+//! Drivers register each device they probe that can be suspended. The devices
+//! are then suspended and resumed together from [`suspend_devices`] and
+//! [`resume_devices`], e.g., around a system sleep.
+
+use alloc::{
+    collections::btree_map::BTreeMap,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+
+use ostd::sync::SpinLock;
+
+use crate::device::VirtioDeviceError;
+
+/// A device that can be suspended and resumed.
+pub trait PowerManaged: Send + Sync {
+    /// Quiesces the device before it loses power.
+    fn suspend(&self) -> Result<(), VirtioDeviceError>;
+
+    /// Restores the device suspended by [`PowerManaged::suspend`], setting it up
+    /// again if it was reset in between.
+    fn resume(&self) -> Result<(), VirtioDeviceError>;
+}
+
+static PM_DEVICES: SpinLock<BTreeMap<String, Arc<dyn PowerManaged>>> =
+    SpinLock::new(BTreeMap::new());
+
+/// Registers a device under `name`, replacing any device of the same name.
+pub fn register_pm_device(name: &str, device: Arc<dyn PowerManaged>) {
+    PM_DEVICES.lock().insert(name.to_string(), device);
+}
+
+/// Unregisters the device registered under `name`, e.g., when it is removed.
+pub fn unregister_pm_device(name: &str) {
+    PM_DEVICES.lock().remove(name);
+}
+
+/// Suspends every registered device, in name order.
+///
+/// All the devices are suspended even if some fail; the first error is returned.
+pub fn suspend_devices() -> Result<(), VirtioDeviceError> {
+    pm_devices()
+        .into_iter()
+        .map(|device| device.suspend())
+        .fold(Ok(()), Result::and)
+}
+
+/// Resumes every registered device, in the reverse order of [`suspend_devices`].
+///
+/// All the devices are resumed even if some fail; the first error is returned.
+pub fn resume_devices() -> Result<(), VirtioDeviceError> {
+    pm_devices()
+        .into_iter()
+        .rev()
+        .map(|device| device.resume())
+        .fold(Ok(()), Result::and)
+}
+
+/// Returns the registered devices, so that they are suspended or resumed without
+/// holding the lock, since they may block.
+fn pm_devices() -> Vec<Arc<dyn PowerManaged>> {
+    PM_DEVICES.lock().values().cloned().collect()
+}
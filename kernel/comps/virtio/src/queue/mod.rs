@@ -0,0 +1,243 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! Virtqueue
+
+mod packed;
+mod split;
+
+use alloc::collections::BTreeMap;
+use core::sync::atomic::{fence, Ordering};
+
+use ostd::mm::{DmaCoherent, FrameAllocOptions, PAGE_SIZE};
+
+pub use self::split::{AvailRing, Descriptor, UsedElem, UsedRing};
+use self::{packed::PackedQueue, split::SplitQueue};
+use crate::{
+    dma_buf::DmaBuf,
+    transport::{ConfigManager, VirtioTransport},
+    Feature,
+};
+
+#[derive(Debug)]
+pub enum QueueError {
+    InvalidArgs,
+    BufferTooSmall,
+    NotReady,
+    AlreadyUsed,
+    WrongToken,
+}
+
+/// The mechanism for bulk data transport on virtio devices.
+///
+/// Each device can have zero or more virtqueues.
+///
+/// The rings of a virtqueue are packed if `VIRTIO_F_RING_PACKED` was negotiated,
+/// and split otherwise, which the drivers do not need to care about.
+#[derive(Debug)]
+pub struct VirtQueue {
+    rings: Rings,
+    /// The tokens popped from the rings while another token was claimed, with the
+    /// lengths used by the device, so that the device can complete them in any order.
+    completed: BTreeMap<u16, u32>,
+}
+
+#[derive(Debug)]
+enum Rings {
+    Split(SplitQueue),
+    Packed(PackedQueue),
+}
+
+/// Calls a method of the rings of a virtqueue, whatever their layout.
+macro_rules! with_rings {
+    ($rings:expr, $ring:ident => $call:expr) => {
+        match $rings {
+            Rings::Split($ring) => $call,
+            Rings::Packed($ring) => $call,
+        }
+    };
+}
+
+impl VirtQueue {
+    /// Create a new VirtQueue.
+    pub(crate) fn new(
+        idx: u16,
+        size: u16,
+        transport: &mut dyn VirtioTransport,
+    ) -> Result<Self, QueueError> {
+        let driver_features = Feature::from_bits_truncate(transport.read_driver_features());
+        // Legacy devices only know the split layout.
+        let is_packed =
+            driver_features.contains(Feature::RING_PACKED) && !transport.is_legacy_version();
+        let rings = if is_packed {
+            Rings::Packed(PackedQueue::new(idx, size, transport, driver_features)?)
+        } else {
+            Rings::Split(SplitQueue::new(idx, size, transport, driver_features)?)
+        };
+        Ok(VirtQueue {
+            rings,
+            completed: BTreeMap::new(),
+        })
+    }
+
+    /// Add dma buffers to the virtqueue, return a token.
+    pub fn add_dma_buf<T: DmaBuf>(
+        &mut self,
+        inputs: &[&T],
+        outputs: &[&T],
+    ) -> Result<u16, QueueError> {
+        with_rings!(&mut self.rings, ring => ring.add_dma_buf(inputs, outputs))
+    }
+
+    /// Whether there is a used element that can pop.
+    ///
+    /// This includes the tokens kept for their claimants by [`Self::pop_used_with_token`],
+    /// so a caller waiting for its own token should ask [`Self::is_completed`] instead.
+    pub fn can_pop(&self) -> bool {
+        !self.completed.is_empty() || with_rings!(&self.rings, ring => ring.can_pop())
+    }
+
+    /// The number of free descriptors.
+    pub fn available_desc(&self) -> usize {
+        with_rings!(&self.rings, ring => ring.available_desc())
+    }
+
+    /// Get a token from device used buffers, return (token, len).
+    ///
+    /// The tokens kept while another token was claimed are returned first, in ascending
+    /// order of token rather than in the order the device used them.
+    pub fn pop_used(&mut self) -> Result<(u16, u32), QueueError> {
+        let (token, len) = match self.completed.pop_first() {
+            Some(completion) => completion,
+            None => with_rings!(&mut self.rings, ring => ring.pop_used())?,
+        };
+        with_rings!(&mut self.rings, ring => ring.recycle(token));
+        Ok((token, len))
+    }
+
+    /// If the given token was completed by the device, pops it and returns the total buffer
+    /// length which was used (written) by the device.
+    ///
+    /// The device may complete the tokens out of order, so the other tokens completed
+    /// before the given one are kept until they are claimed.
+    pub fn pop_used_with_token(&mut self, token: u16) -> Result<u32, QueueError> {
+        let len = match self.completed.remove(&token) {
+            Some(len) => len,
+            None => loop {
+                let (used_token, len) = with_rings!(&mut self.rings, ring => ring.pop_used())?;
+                if used_token == token {
+                    break len;
+                }
+                self.completed.insert(used_token, len);
+            },
+        };
+        // The token is only recycled now, so that it cannot be handed out again while
+        // its completion waits to be claimed.
+        with_rings!(&mut self.rings, ring => ring.recycle(token));
+        Ok(len)
+    }
+
+    /// Whether the device has completed the given token, so that
+    /// [`Self::pop_used_with_token`] returns it.
+    ///
+    /// The other tokens the device has completed are popped and kept until they are claimed.
+    pub fn is_completed(&mut self, token: u16) -> bool {
+        while let Ok((used_token, len)) = with_rings!(&mut self.rings, ring => ring.pop_used()) {
+            self.completed.insert(used_token, len);
+        }
+        self.completed.contains_key(&token)
+    }
+
+    /// Return size of the queue.
+    pub fn size(&self) -> u16 {
+        with_rings!(&self.rings, ring => ring.size())
+    }
+
+    /// whether the driver should notify the device
+    pub fn should_notify(&self) -> bool {
+        with_rings!(&self.rings, ring => ring.should_notify())
+    }
+
+    /// notify that there are available rings
+    pub fn notify(&mut self) {
+        with_rings!(&mut self.rings, ring => ring.notify())
+    }
+
+    /// Disables registered callbacks.
+    ///
+    /// That is to say, the queue won't generate interrupts after calling this method.
+    pub fn disable_callback(&mut self) {
+        with_rings!(&mut self.rings, ring => ring.disable_callback())
+    }
+
+    /// Enables registered callbacks.
+    ///
+    /// The queue will generate interrupts if any event comes after calling this method.
+    pub fn enable_callback(&mut self) {
+        with_rings!(&mut self.rings, ring => ring.enable_callback())
+    }
+
+    /// Enables registered callbacks, but delays the interrupt until the device has
+    /// used `nr_used` more buffers, if the event index was negotiated.
+    pub fn enable_callback_delayed(&mut self, nr_used: u16) {
+        with_rings!(&mut self.rings, ring => ring.enable_callback_delayed(nr_used))
+    }
+}
+
+/// The operations on the rings of a virtqueue, which [`VirtQueue`] forwards to
+/// the rings of its layout.
+trait QueueRing {
+    fn add_dma_buf<T: DmaBuf>(&mut self, inputs: &[&T], outputs: &[&T]) -> Result<u16, QueueError>;
+
+    fn can_pop(&self) -> bool;
+
+    fn available_desc(&self) -> usize;
+
+    fn pop_used(&mut self) -> Result<(u16, u32), QueueError>;
+
+    fn recycle(&mut self, token: u16);
+
+    fn size(&self) -> u16;
+
+    fn should_notify(&self) -> bool;
+
+    fn notify(&mut self);
+
+    fn disable_callback(&mut self);
+
+    fn enable_callback(&mut self);
+
+    fn enable_callback_delayed(&mut self, nr_used: u16);
+}
+
+/// Allocates a DMA area of at least `size` bytes, made of whole zeroed frames.
+fn alloc_area(size: usize) -> DmaCoherent {
+    DmaCoherent::map(
+        FrameAllocOptions::new()
+            .alloc_segment(size.div_ceil(PAGE_SIZE))
+            .unwrap()
+            .into(),
+        true,
+    )
+    .unwrap()
+}
+
+/// Notifies the device that there are new available buffers in the queue `queue_idx`.
+fn notify_device(notify_config: &ConfigManager<u32>, queue_idx: u32) {
+    // Make the available buffers visible before the device is notified.
+    fence(Ordering::SeqCst);
+    if notify_config.is_modern() {
+        notify_config.write_once::<u32>(0, queue_idx).unwrap();
+    } else {
+        notify_config
+            .write_once::<u16>(0, queue_idx as u16)
+            .unwrap();
+    }
+}
+
+/// Whether the index moving from `old` to `new` passed `event`, i.e., the other side
+/// asked to be notified about an entry in between.
+///
+/// Ref: linux virtio_ring.h vring_need_event
+fn need_event(event: u16, new: u16, old: u16) -> bool {
+    new.wrapping_sub(event).wrapping_sub(1) < new.wrapping_sub(old)
+}
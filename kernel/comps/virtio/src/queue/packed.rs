@@ -0,0 +1,471 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! The packed virtqueue, whose descriptors are both made available by the driver
+//! and used by the device in a single ring.
+
+use alloc::vec::Vec;
+use core::{
+    mem::size_of,
+    sync::atomic::{fence, Ordering},
+};
+
+use aster_util::{field_ptr, safe_ptr::SafePtr};
+use bitflags::bitflags;
+use log::debug;
+use ostd::{
+    mm::{DmaCoherent, HasDaddr},
+    Pod,
+};
+
+use super::{
+    alloc_area, need_event, notify_device, AvailRing, Descriptor, QueueError, QueueRing, UsedRing,
+};
+use crate::{
+    dma_buf::DmaBuf,
+    transport::{ConfigManager, VirtioTransport},
+    Feature,
+};
+
+/// Events are enabled.
+const RING_EVENT_FLAGS_ENABLE: u16 = 0x0;
+/// Events are disabled.
+const RING_EVENT_FLAGS_DISABLE: u16 = 0x1;
+/// Events are enabled only at the descriptor in `off_wrap`, which requires the event index.
+const RING_EVENT_FLAGS_DESC: u16 = 0x2;
+
+/// A virtqueue in the packed layout.
+///
+/// Indirect descriptors are not used in this layout, so that the buffers of a request
+/// always take a descriptor of the ring each.
+#[derive(Debug)]
+pub(super) struct PackedQueue {
+    /// Descriptor ring
+    descs: SafePtr<PackedDescriptor, DmaCoherent>,
+    /// Driver event suppression, which is written by the driver to suppress interrupts.
+    driver_event: SafePtr<EventSuppression, DmaCoherent>,
+    /// Device event suppression, which is written by the device to suppress notifications.
+    device_event: SafePtr<EventSuppression, DmaCoherent>,
+    /// Notify configuration manager
+    notify_config: ConfigManager<u32>,
+
+    /// The index of queue
+    queue_idx: u32,
+    /// The size of the queue, which is the number of descriptors in the ring.
+    queue_size: u16,
+    /// The number of free descriptors.
+    num_free: u16,
+    /// The index of the next descriptor to make available.
+    next_avail: u16,
+    /// The wrap counter of the descriptors made available, flipped each time
+    /// `next_avail` wraps around.
+    avail_wrap_counter: bool,
+    /// The index of the next descriptor to be used by the device.
+    last_used: u16,
+    /// The wrap counter of the used descriptors, flipped each time `last_used`
+    /// wraps around.
+    used_wrap_counter: bool,
+    /// The buffer IDs not taken by an available request, which are the tokens.
+    free_ids: Vec<u16>,
+    /// The number of descriptors of each available request, indexed by its buffer ID.
+    chain_lens: Vec<u16>,
+    /// The number of descriptors made available since the device was last notified.
+    nr_added: u16,
+    /// Whether the callback of this queue is enabled
+    is_callback_enabled: bool,
+    /// Whether `VIRTIO_F_EVENT_IDX` was negotiated, so that interrupts can be
+    /// delayed to a given descriptor.
+    event_idx: bool,
+    /// The total length of the device-writable buffers of each available request,
+    /// indexed by its buffer ID.
+    #[cfg(debug_assertions)]
+    writable_lens: Vec<Option<u32>>,
+}
+
+impl PackedQueue {
+    /// Create a new packed queue, using the ring features in `driver_features`.
+    pub(super) fn new(
+        idx: u16,
+        size: u16,
+        transport: &mut dyn VirtioTransport,
+        driver_features: Feature,
+    ) -> Result<Self, QueueError> {
+        // Unlike split rings, the size of a packed ring needs not be a power of two.
+        let max_size = transport
+            .max_queue_size(idx)
+            .map_err(|_| QueueError::InvalidArgs)?;
+        if size == 0 || size > max_size {
+            return Err(QueueError::InvalidArgs);
+        }
+
+        let descs: SafePtr<PackedDescriptor, DmaCoherent> =
+            SafePtr::new(alloc_area(size_of::<PackedDescriptor>() * size as usize), 0);
+        let driver_event: SafePtr<EventSuppression, DmaCoherent> =
+            SafePtr::new(alloc_area(size_of::<EventSuppression>()), 0);
+        let device_event: SafePtr<EventSuppression, DmaCoherent> =
+            SafePtr::new(alloc_area(size_of::<EventSuppression>()), 0);
+        debug!("queue_desc start paddr:{:x?}", descs.paddr());
+        debug!("queue_driver start paddr:{:x?}", driver_event.paddr());
+        debug!("queue_device start paddr:{:x?}", device_event.paddr());
+
+        // The transport only passes the addresses of the areas to the device.
+        transport
+            .set_queue(
+                idx,
+                size,
+                &descs.clone().cast::<Descriptor>(),
+                &driver_event.clone().cast::<AvailRing>(),
+                &device_event.clone().cast::<UsedRing>(),
+            )
+            .unwrap();
+
+        let notify_config = transport.notify_config(idx as usize);
+        Ok(PackedQueue {
+            descs,
+            driver_event,
+            device_event,
+            notify_config,
+            queue_idx: idx as u32,
+            queue_size: size,
+            num_free: size,
+            next_avail: 0,
+            avail_wrap_counter: true,
+            last_used: 0,
+            used_wrap_counter: true,
+            free_ids: (0..size).rev().collect(),
+            chain_lens: alloc::vec![0; size as usize],
+            nr_added: 0,
+            is_callback_enabled: true,
+            event_idx: driver_features.contains(Feature::RING_EVENT_IDX),
+            #[cfg(debug_assertions)]
+            writable_lens: alloc::vec![None; size as usize],
+        })
+    }
+
+    /// The descriptor at `index` in the ring.
+    fn desc_ptr(&self, index: u16) -> SafePtr<PackedDescriptor, &DmaCoherent> {
+        let mut ptr = self.descs.borrow_vm();
+        ptr.add(index as usize);
+        ptr
+    }
+
+    /// The `AVAIL` and `USED` flags marking a descriptor available in the current
+    /// lap of the ring.
+    fn avail_flags(&self) -> PackedDescFlags {
+        if self.avail_wrap_counter {
+            PackedDescFlags::AVAIL
+        } else {
+            PackedDescFlags::USED
+        }
+    }
+
+    /// Whether the descriptor with `flags` was used in the current lap of the ring.
+    fn is_used(&self, flags: PackedDescFlags) -> bool {
+        let avail = flags.contains(PackedDescFlags::AVAIL);
+        let used = flags.contains(PackedDescFlags::USED);
+        avail == used && used == self.used_wrap_counter
+    }
+
+    /// Checks that the device returned a request it was given, and did not report
+    /// writing more than the device-writable buffers of the request.
+    #[cfg(debug_assertions)]
+    fn check_used_len(&mut self, id: u16, len: u32) {
+        let Some(writable_len) = self
+            .writable_lens
+            .get_mut(id as usize)
+            .and_then(Option::take)
+        else {
+            panic!(
+                "virtqueue {}: the device returned the unavailable buffer {}",
+                self.queue_idx, id
+            );
+        };
+        assert!(
+            len <= writable_len,
+            "virtqueue {}: the device wrote {} bytes to {} writable bytes of buffer {}",
+            self.queue_idx,
+            len,
+            writable_len,
+            id
+        );
+    }
+
+    /// Sets the descriptor at which the device interrupts next, with the wrap counter
+    /// of its lap.
+    fn set_used_event(&mut self, index: u16, wrap_counter: bool) {
+        let off_wrap = index | ((wrap_counter as u16) << 15);
+        field_ptr!(&self.driver_event, EventSuppression, off_wrap)
+            .write_once(&off_wrap)
+            .unwrap();
+        // Make the new event visible before the descriptors are checked again.
+        fence(Ordering::SeqCst);
+    }
+
+    /// Sets how the device interrupts.
+    fn set_event_flags(&mut self, flags: u16) {
+        field_ptr!(&self.driver_event, EventSuppression, flags)
+            .write_once(&flags)
+            .unwrap();
+        fence(Ordering::SeqCst);
+    }
+}
+
+impl QueueRing for PackedQueue {
+    /// Add dma buffers to the virtqueue, return a token.
+    ///
+    /// The token is the buffer ID of the request, which the device returns with the
+    /// last descriptor it uses.
+    ///
+    /// Ref: linux virtio_ring.c virtqueue_add_packed
+    fn add_dma_buf<T: DmaBuf>(&mut self, inputs: &[&T], outputs: &[&T]) -> Result<u16, QueueError> {
+        if inputs.is_empty() && outputs.is_empty() {
+            return Err(QueueError::InvalidArgs);
+        }
+        let nr_descs = inputs.len() + outputs.len();
+        if nr_descs > self.num_free as usize {
+            return Err(QueueError::BufferTooSmall);
+        }
+        // There are as many buffer IDs as descriptors, so one is free if a descriptor is.
+        let id = self.free_ids.pop().unwrap();
+
+        let head = self.next_avail;
+        let mut head_flags = PackedDescFlags::empty();
+        let bufs = inputs
+            .iter()
+            .map(|input| (input, PackedDescFlags::empty()))
+            .chain(
+                outputs
+                    .iter()
+                    .map(|output| (output, PackedDescFlags::WRITE)),
+            );
+        for (i, (buf, mut flags)) in bufs.enumerate() {
+            debug_assert_ne!(buf.len(), 0);
+            if i + 1 < nr_descs {
+                flags |= PackedDescFlags::NEXT;
+            }
+            flags |= self.avail_flags();
+
+            let desc = self.desc_ptr(self.next_avail);
+            field_ptr!(&desc, PackedDescriptor, addr)
+                .write_once(&(buf.daddr() as u64))
+                .unwrap();
+            field_ptr!(&desc, PackedDescriptor, len)
+                .write_once(&(buf.len() as u32))
+                .unwrap();
+            field_ptr!(&desc, PackedDescriptor, id)
+                .write_once(&id)
+                .unwrap();
+            // The head is made available last, so that the device never sees a partial chain.
+            if i == 0 {
+                head_flags = flags;
+            } else {
+                field_ptr!(&desc, PackedDescriptor, flags)
+                    .write_once(&flags)
+                    .unwrap();
+            }
+
+            self.next_avail += 1;
+            if self.next_avail == self.queue_size {
+                self.next_avail = 0;
+                self.avail_wrap_counter = !self.avail_wrap_counter;
+            }
+        }
+        // write barrier
+        fence(Ordering::SeqCst);
+        field_ptr!(&self.desc_ptr(head), PackedDescriptor, flags)
+            .write_once(&head_flags)
+            .unwrap();
+        fence(Ordering::SeqCst);
+
+        self.num_free -= nr_descs as u16;
+        self.nr_added = self.nr_added.wrapping_add(nr_descs as u16);
+        self.chain_lens[id as usize] = nr_descs as u16;
+        #[cfg(debug_assertions)]
+        {
+            let writable_len = outputs.iter().map(|output| output.len() as u32).sum();
+            self.writable_lens[id as usize] = Some(writable_len);
+        }
+        Ok(id)
+    }
+
+    /// Whether there is a used element that can pop.
+    fn can_pop(&self) -> bool {
+        // read barrier
+        fence(Ordering::SeqCst);
+
+        let flags = field_ptr!(&self.desc_ptr(self.last_used), PackedDescriptor, flags)
+            .read_once()
+            .unwrap();
+        self.is_used(flags)
+    }
+
+    /// The number of free descriptors.
+    fn available_desc(&self) -> usize {
+        self.num_free as usize
+    }
+
+    /// Get a token from device used buffers, return (token, len).
+    ///
+    /// The descriptors of the token stay counted as taken, and its buffer ID as the
+    /// token of an available request, until it is recycled.
+    ///
+    /// Ref: linux virtio_ring.c virtqueue_get_buf_ctx_packed
+    fn pop_used(&mut self) -> Result<(u16, u32), QueueError> {
+        if !self.can_pop() {
+            return Err(QueueError::NotReady);
+        }
+
+        let desc = self.desc_ptr(self.last_used);
+        let id = field_ptr!(&desc, PackedDescriptor, id).read_once().unwrap();
+        let len = field_ptr!(&desc, PackedDescriptor, len)
+            .read_once()
+            .unwrap();
+
+        #[cfg(debug_assertions)]
+        self.check_used_len(id, len);
+        self.last_used += self.chain_lens[id as usize];
+        if self.last_used >= self.queue_size {
+            self.last_used -= self.queue_size;
+            self.used_wrap_counter = !self.used_wrap_counter;
+        }
+
+        // After `enable_callback_delayed`, the device interrupts only at the descriptor
+        // of the event, which is moved along so that the callback keeps being called.
+        if self.is_callback_enabled
+            && field_ptr!(&self.driver_event, EventSuppression, flags)
+                .read_once()
+                .unwrap()
+                == RING_EVENT_FLAGS_DESC
+        {
+            self.set_used_event(self.last_used, self.used_wrap_counter);
+        }
+
+        Ok((id, len))
+    }
+
+    /// Frees the descriptors and the buffer ID of a token popped from the used ring.
+    ///
+    /// Ref: linux virtio_ring.c detach_buf_packed
+    fn recycle(&mut self, token: u16) {
+        let nr_descs = core::mem::take(&mut self.chain_lens[token as usize]);
+        self.num_free += nr_descs;
+        self.free_ids.push(token);
+    }
+
+    /// Return size of the queue.
+    fn size(&self) -> u16 {
+        self.queue_size
+    }
+
+    /// whether the driver should notify the device
+    ///
+    /// Ref: linux virtio_ring.c virtqueue_kick_prepare_packed
+    fn should_notify(&self) -> bool {
+        // read barrier
+        fence(Ordering::SeqCst);
+        let flags = field_ptr!(&self.device_event, EventSuppression, flags)
+            .read_once()
+            .unwrap();
+        if flags != RING_EVENT_FLAGS_DESC {
+            return flags != RING_EVENT_FLAGS_DISABLE;
+        }
+
+        let off_wrap = field_ptr!(&self.device_event, EventSuppression, off_wrap)
+            .read_once()
+            .unwrap();
+        let mut event = off_wrap & 0x7fff;
+        // An event in the previous lap is behind all the descriptors of this one.
+        if (off_wrap >> 15 != 0) != self.avail_wrap_counter {
+            event = event.wrapping_sub(self.queue_size);
+        }
+        need_event(
+            event,
+            self.next_avail,
+            self.next_avail.wrapping_sub(self.nr_added),
+        )
+    }
+
+    /// notify that there are available rings
+    fn notify(&mut self) {
+        self.nr_added = 0;
+        notify_device(&self.notify_config, self.queue_idx);
+    }
+
+    /// Disables registered callbacks.
+    ///
+    /// That is to say, the queue won't generate interrupts after calling this method.
+    fn disable_callback(&mut self) {
+        if !self.is_callback_enabled {
+            return;
+        }
+
+        self.set_event_flags(RING_EVENT_FLAGS_DISABLE);
+        self.is_callback_enabled = false;
+    }
+
+    /// Enables registered callbacks.
+    ///
+    /// The queue will generate interrupts if any event comes after calling this method.
+    fn enable_callback(&mut self) {
+        self.set_event_flags(RING_EVENT_FLAGS_ENABLE);
+        self.is_callback_enabled = true;
+    }
+
+    /// Enables registered callbacks, but delays the interrupt until the device has
+    /// used `nr_used` more descriptors.
+    ///
+    /// Without the event index, an interrupt cannot be delayed, so this is
+    /// [`QueueRing::enable_callback`].
+    ///
+    /// Ref: linux virtio_ring.c virtqueue_enable_cb_delayed_packed
+    fn enable_callback_delayed(&mut self, nr_used: u16) {
+        if !self.event_idx {
+            self.enable_callback();
+            return;
+        }
+
+        let mut used_event = self.last_used + nr_used.clamp(1, self.queue_size) - 1;
+        let mut wrap_counter = self.used_wrap_counter;
+        if used_event >= self.queue_size {
+            used_event -= self.queue_size;
+            wrap_counter = !wrap_counter;
+        }
+        self.set_used_event(used_event, wrap_counter);
+        self.set_event_flags(RING_EVENT_FLAGS_DESC);
+        self.is_callback_enabled = true;
+    }
+}
+
+/// A descriptor of the packed ring, which is made available by the driver and then
+/// overwritten by the device to mark it used.
+#[repr(C, align(16))]
+#[derive(Debug, Default, Copy, Clone, Pod)]
+struct PackedDescriptor {
+    addr: u64,
+    len: u32,
+    /// The buffer ID, which is the same for all the descriptors of a request.
+    id: u16,
+    flags: PackedDescFlags,
+}
+
+bitflags! {
+    /// Packed descriptor flags
+    #[derive(Pod, Default)]
+    #[repr(C)]
+    struct PackedDescFlags: u16 {
+        const NEXT = 1;
+        const WRITE = 2;
+        const INDIRECT = 4;
+        const AVAIL = 1 << 7;
+        const USED = 1 << 15;
+    }
+}
+
+/// The area through which one side asks the other to suppress its events.
+#[repr(C, align(4))]
+#[derive(Debug, Default, Copy, Clone, Pod)]
+struct EventSuppression {
+    /// The index of the descriptor to be notified about in the low 15 bits, and the
+    /// wrap counter of its lap in the highest bit.
+    off_wrap: u16,
+    flags: u16,
+}
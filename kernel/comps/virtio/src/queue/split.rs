@@ -0,0 +1,683 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! The split virtqueue, whose descriptor table, available ring and used ring
+//! are separate areas.
+
+use alloc::{sync::Arc, vec::Vec};
+use core::{
+    mem::size_of,
+    sync::atomic::{fence, Ordering},
+};
+
+use aster_network::{dma_pool::DmaPool, DmaSegment};
+use aster_rights::{Dup, TRightSet, TRights, Write};
+use aster_util::{field_ptr, safe_ptr::SafePtr};
+use bitflags::bitflags;
+use log::debug;
+use ostd::{
+    mm::{DmaCoherent, DmaDirection, FrameAllocOptions, HasDaddr},
+    Pod,
+};
+use spin::Once;
+
+use super::{alloc_area, need_event, notify_device, QueueError, QueueRing};
+use crate::{
+    dma_buf::DmaBuf,
+    transport::{pci::legacy::VirtioPciLegacyTransport, ConfigManager, VirtioTransport},
+    Feature,
+};
+
+/// The maximum number of buffers chained in an indirect descriptor table.
+const MAX_INDIRECT_DESCS: usize = 16;
+/// The size of an indirect descriptor table, in bytes.
+const INDIRECT_TABLE_SIZE: usize = MAX_INDIRECT_DESCS * size_of::<Descriptor>();
+
+/// The pool the indirect descriptor tables of all the virtqueues are allocated from.
+static INDIRECT_TABLE_POOL: Once<Arc<DmaPool>> = Once::new();
+
+/// A virtqueue in the split layout, the only one of legacy devices.
+#[derive(Debug)]
+pub(super) struct SplitQueue {
+    /// Descriptor table
+    descs: Vec<SafePtr<Descriptor, DmaCoherent>>,
+    /// Available ring
+    avail: SafePtr<AvailRing, DmaCoherent>,
+    /// Used ring
+    used: SafePtr<UsedRing, DmaCoherent>,
+    /// Notify configuration manager
+    notify_config: ConfigManager<u32>,
+
+    /// The index of queue
+    queue_idx: u32,
+    /// The size of the queue.
+    ///
+    /// This is both the number of descriptors, and the number of slots in the available and used
+    /// rings.
+    queue_size: u16,
+    /// The number of used queues.
+    num_used: u16,
+    /// The head desc index of the free list.
+    free_head: u16,
+    /// the index of the next avail ring index
+    avail_idx: u16,
+    /// last service used index
+    last_used_idx: u16,
+    /// Whether the callback of this queue is enabled
+    is_callback_enabled: bool,
+    /// Whether `VIRTIO_RING_F_INDIRECT_DESC` was negotiated, so that the buffers of
+    /// a request can be chained in an indirect table taking a single descriptor.
+    indirect_desc: bool,
+    /// The indirect table of each available chain, indexed by the head descriptor.
+    indirect_tables: Vec<Option<DmaSegment>>,
+    /// Whether `VIRTIO_F_EVENT_IDX` was negotiated, so that notifications and
+    /// interrupts are suppressed with `avail_event` and `used_event` instead of flags.
+    event_idx: bool,
+    /// The available index when the device was last notified.
+    notified_avail_idx: u16,
+    /// The total length of the device-writable buffers of each available chain,
+    /// indexed by the head descriptor.
+    #[cfg(debug_assertions)]
+    writable_lens: Vec<Option<u32>>,
+}
+
+impl SplitQueue {
+    /// Create a new split queue, using the ring features in `driver_features`.
+    pub(super) fn new(
+        idx: u16,
+        mut size: u16,
+        transport: &mut dyn VirtioTransport,
+        driver_features: Feature,
+    ) -> Result<Self, QueueError> {
+        if !size.is_power_of_two() {
+            return Err(QueueError::InvalidArgs);
+        }
+
+        let (descriptor_ptr, avail_ring_ptr, used_ring_ptr) = if transport.is_legacy_version() {
+            // The descriptors and the available ring are placed in the first frames, and the used
+            // ring in the next ones, because the virtio-mmio legacy required the address to be
+            // continuous. The queue size is decided by the device.
+            let queue_size = transport.max_queue_size(idx).unwrap() as usize;
+            let desc_size = size_of::<Descriptor>() * queue_size;
+            size = queue_size as u16;
+
+            let (seg1, seg2) = {
+                let align_size = VirtioPciLegacyTransport::QUEUE_ALIGN_SIZE;
+                let total_frames =
+                    VirtioPciLegacyTransport::calc_virtqueue_size_aligned(queue_size) / align_size;
+                let continue_segment = FrameAllocOptions::new()
+                    .alloc_segment(total_frames)
+                    .unwrap();
+
+                let seg1_frames = (desc_size + avail_ring_size(queue_size)).div_ceil(align_size);
+
+                continue_segment.split(seg1_frames * align_size)
+            };
+            let desc_frame_ptr: SafePtr<Descriptor, DmaCoherent> =
+                SafePtr::new(DmaCoherent::map(seg1.into(), true).unwrap(), 0);
+            let mut avail_frame_ptr: SafePtr<AvailRing, DmaCoherent> =
+                desc_frame_ptr.clone().cast();
+            avail_frame_ptr.byte_add(desc_size);
+            let used_frame_ptr: SafePtr<UsedRing, DmaCoherent> =
+                SafePtr::new(DmaCoherent::map(seg2.into(), true).unwrap(), 0);
+            (desc_frame_ptr, avail_frame_ptr, used_frame_ptr)
+        } else {
+            let max_size = transport
+                .max_queue_size(idx)
+                .map_err(|_| QueueError::InvalidArgs)?;
+            if size > max_size {
+                return Err(QueueError::InvalidArgs);
+            }
+            let queue_size = size as usize;
+            (
+                SafePtr::new(alloc_area(size_of::<Descriptor>() * queue_size), 0),
+                SafePtr::new(alloc_area(avail_ring_size(queue_size)), 0),
+                SafePtr::new(alloc_area(used_ring_size(queue_size)), 0),
+            )
+        };
+        debug!("queue_desc start paddr:{:x?}", descriptor_ptr.paddr());
+        debug!("queue_driver start paddr:{:x?}", avail_ring_ptr.paddr());
+        debug!("queue_device start paddr:{:x?}", used_ring_ptr.paddr());
+
+        transport
+            .set_queue(idx, size, &descriptor_ptr, &avail_ring_ptr, &used_ring_ptr)
+            .unwrap();
+        let mut descs = Vec::with_capacity(size as usize);
+        descs.push(descriptor_ptr);
+        for i in 0..size {
+            let mut desc = descs.get(i as usize).unwrap().clone();
+            let next_i = i + 1;
+            if next_i != size {
+                field_ptr!(&desc, Descriptor, next)
+                    .write_once(&next_i)
+                    .unwrap();
+                desc.add(1);
+                descs.push(desc);
+            } else {
+                field_ptr!(&desc, Descriptor, next)
+                    .write_once(&(0u16))
+                    .unwrap();
+            }
+        }
+
+        let indirect_desc = driver_features.contains(Feature::RING_INDIRECT_DESC);
+        let event_idx = driver_features.contains(Feature::RING_EVENT_IDX);
+        if indirect_desc {
+            INDIRECT_TABLE_POOL.call_once(|| {
+                DmaPool::new(INDIRECT_TABLE_SIZE, 1, 16, DmaDirection::ToDevice, false)
+            });
+        }
+
+        let notify_config = transport.notify_config(idx as usize);
+        field_ptr!(&avail_ring_ptr, AvailRing, flags)
+            .write_once(&AvailFlags::empty())
+            .unwrap();
+        Ok(SplitQueue {
+            descs,
+            avail: avail_ring_ptr,
+            used: used_ring_ptr,
+            notify_config,
+            queue_size: size,
+            queue_idx: idx as u32,
+            num_used: 0,
+            free_head: 0,
+            avail_idx: 0,
+            last_used_idx: 0,
+            is_callback_enabled: true,
+            indirect_desc,
+            indirect_tables: (0..size).map(|_| None).collect(),
+            event_idx,
+            notified_avail_idx: 0,
+            #[cfg(debug_assertions)]
+            writable_lens: alloc::vec![None; size as usize],
+        })
+    }
+
+    /// Takes a descriptor from the free list pointing to the indirect `table` of
+    /// `nr_bufs` buffers.
+    fn add_indirect_table(&mut self, table: DmaSegment, nr_bufs: usize) {
+        let head = self.free_head;
+        let desc = &self.descs[head as usize];
+        #[cfg(debug_assertions)]
+        check_poisoned(desc, head);
+        field_ptr!(desc, Descriptor, addr)
+            .write_once(&(table.daddr() as u64))
+            .unwrap();
+        field_ptr!(desc, Descriptor, len)
+            .write_once(&((nr_bufs * size_of::<Descriptor>()) as u32))
+            .unwrap();
+        field_ptr!(desc, Descriptor, flags)
+            .write_once(&DescFlags::INDIRECT)
+            .unwrap();
+        self.free_head = field_ptr!(desc, Descriptor, next).read_once().unwrap();
+        self.indirect_tables[head as usize] = Some(table);
+    }
+
+    /// Takes a descriptor from the free list for each buffer, chaining them.
+    fn add_direct_descs<T: DmaBuf>(&mut self, inputs: &[&T], outputs: &[&T]) {
+        // allocate descriptors from free list
+        let mut last = self.free_head;
+        for input in inputs.iter() {
+            let desc = &self.descs[self.free_head as usize];
+            #[cfg(debug_assertions)]
+            check_poisoned(desc, self.free_head);
+            set_dma_buf(&desc.borrow_vm().restrict::<TRights![Write, Dup]>(), *input);
+            field_ptr!(desc, Descriptor, flags)
+                .write_once(&DescFlags::NEXT)
+                .unwrap();
+            last = self.free_head;
+            self.free_head = field_ptr!(desc, Descriptor, next).read_once().unwrap();
+        }
+        for output in outputs.iter() {
+            let desc = &mut self.descs[self.free_head as usize];
+            #[cfg(debug_assertions)]
+            check_poisoned(desc, self.free_head);
+            set_dma_buf(
+                &desc.borrow_vm().restrict::<TRights![Write, Dup]>(),
+                *output,
+            );
+            field_ptr!(desc, Descriptor, flags)
+                .write_once(&(DescFlags::NEXT | DescFlags::WRITE))
+                .unwrap();
+            last = self.free_head;
+            self.free_head = field_ptr!(desc, Descriptor, next).read_once().unwrap();
+        }
+        // set last_elem.next = NULL
+        {
+            let desc = &mut self.descs[last as usize];
+            let mut flags: DescFlags = field_ptr!(desc, Descriptor, flags).read_once().unwrap();
+            flags.remove(DescFlags::NEXT);
+            field_ptr!(desc, Descriptor, flags)
+                .write_once(&flags)
+                .unwrap();
+        }
+    }
+
+    /// Recycle descriptors in the list specified by head.
+    ///
+    /// This will push all linked descriptors at the front of the free list.
+    fn recycle_descriptors(&mut self, mut head: u16) {
+        // The indirect table, if any, is returned to the pool.
+        self.indirect_tables[head as usize] = None;
+        let origin_free_head = self.free_head;
+        self.free_head = head;
+        loop {
+            let desc = &mut self.descs[head as usize];
+            // Sets the buffer address and length to 0, or poisons them in debug builds
+            // so that a device still using the descriptor faults on an obvious address.
+            let (addr, len) = if cfg!(debug_assertions) {
+                (POISON_ADDR, POISON_LEN)
+            } else {
+                (0, 0)
+            };
+            field_ptr!(desc, Descriptor, addr)
+                .write_once(&addr)
+                .unwrap();
+            field_ptr!(desc, Descriptor, len).write_once(&len).unwrap();
+            self.num_used -= 1;
+
+            let flags: DescFlags = field_ptr!(desc, Descriptor, flags).read_once().unwrap();
+            if flags.contains(DescFlags::NEXT) {
+                field_ptr!(desc, Descriptor, flags)
+                    .write_once(&DescFlags::empty())
+                    .unwrap();
+                head = field_ptr!(desc, Descriptor, next).read_once().unwrap();
+            } else {
+                field_ptr!(desc, Descriptor, next)
+                    .write_once(&origin_free_head)
+                    .unwrap();
+                break;
+            }
+        }
+    }
+
+    /// Checks that the device returned a chain it was given, and did not report
+    /// writing more than the device-writable buffers of the chain.
+    #[cfg(debug_assertions)]
+    fn check_used_len(&mut self, head: u16, len: u32) {
+        let Some(writable_len) = self
+            .writable_lens
+            .get_mut(head as usize)
+            .and_then(Option::take)
+        else {
+            panic!(
+                "virtqueue {}: the device returned the unavailable descriptor {}",
+                self.queue_idx, head
+            );
+        };
+        assert!(
+            len <= writable_len,
+            "virtqueue {}: the device wrote {} bytes to {} writable bytes of descriptor {}",
+            self.queue_idx,
+            len,
+            writable_len,
+            head
+        );
+    }
+
+    /// Moves `used_event` to the next used entry once the device has passed it, so
+    /// that an enabled callback keeps being called.
+    fn refresh_used_event(&mut self) {
+        if !self.event_idx || !self.is_callback_enabled {
+            return;
+        }
+        let used_event: u16 = self.used_event_ptr().read_once().unwrap();
+        if (used_event.wrapping_sub(self.last_used_idx) as i16) < 0 {
+            self.set_used_event(self.last_used_idx);
+        }
+    }
+
+    /// Sets the used index at which the device interrupts next.
+    fn set_used_event(&mut self, used_event: u16) {
+        self.used_event_ptr().write_once(&used_event).unwrap();
+        // Make the new event visible before the used index is checked again.
+        fence(Ordering::SeqCst);
+    }
+
+    /// The entry at `slot` of the available ring, which follows its header.
+    fn avail_entry_ptr(&self, slot: u16) -> SafePtr<u16, &DmaCoherent> {
+        let mut ptr = self.avail.borrow_vm();
+        ptr.byte_add(size_of::<AvailRing>() + slot as usize * size_of::<u16>());
+        ptr.cast::<u16>()
+    }
+
+    /// The element at `slot` of the used ring, which follows its header.
+    fn used_elem_ptr(&self, slot: u16) -> SafePtr<UsedElem, &DmaCoherent> {
+        let mut ptr = self.used.borrow_vm();
+        ptr.byte_add(size_of::<UsedRing>() + slot as usize * size_of::<UsedElem>());
+        ptr.cast::<UsedElem>()
+    }
+
+    /// The `used_event` field, which follows the `queue_size` entries of the available ring.
+    fn used_event_ptr(&self) -> SafePtr<u16, &DmaCoherent> {
+        self.avail_entry_ptr(self.queue_size)
+    }
+
+    /// The `avail_event` field, which follows the `queue_size` elements of the used ring.
+    fn avail_event_ptr(&self) -> SafePtr<u16, &DmaCoherent> {
+        self.used_elem_ptr(self.queue_size).cast::<u16>()
+    }
+}
+
+impl QueueRing for SplitQueue {
+    /// Add dma buffers to the virtqueue, return a token.
+    ///
+    /// If indirect descriptors were negotiated, the buffers of a request are chained
+    /// in an indirect table, so that the request takes a single descriptor of the ring.
+    ///
+    /// Ref: linux virtio_ring.c virtqueue_add
+    fn add_dma_buf<T: DmaBuf>(&mut self, inputs: &[&T], outputs: &[&T]) -> Result<u16, QueueError> {
+        if inputs.is_empty() && outputs.is_empty() {
+            return Err(QueueError::InvalidArgs);
+        }
+        let nr_bufs = inputs.len() + outputs.len();
+        let indirect_table = if self.indirect_desc && (2..=MAX_INDIRECT_DESCS).contains(&nr_bufs) {
+            alloc_indirect_table(inputs, outputs)
+        } else {
+            None
+        };
+        let nr_descs = if indirect_table.is_some() { 1 } else { nr_bufs };
+        if nr_descs + self.num_used as usize > self.queue_size as usize {
+            return Err(QueueError::BufferTooSmall);
+        }
+
+        let head = self.free_head;
+        if let Some(table) = indirect_table {
+            self.add_indirect_table(table, nr_bufs);
+        } else {
+            self.add_direct_descs(inputs, outputs);
+        }
+        self.num_used += nr_descs as u16;
+        #[cfg(debug_assertions)]
+        {
+            let writable_len = outputs.iter().map(|output| output.len() as u32).sum();
+            self.writable_lens[head as usize] = Some(writable_len);
+        }
+
+        let avail_slot = self.avail_idx & (self.queue_size - 1);
+        self.avail_entry_ptr(avail_slot).write_once(&head).unwrap();
+        // write barrier
+        fence(Ordering::SeqCst);
+
+        // increase head of avail ring
+        self.avail_idx = self.avail_idx.wrapping_add(1);
+        field_ptr!(&self.avail, AvailRing, idx)
+            .write_once(&self.avail_idx)
+            .unwrap();
+
+        fence(Ordering::SeqCst);
+        Ok(head)
+    }
+
+    /// Whether there is a used element that can pop.
+    fn can_pop(&self) -> bool {
+        // read barrier
+        fence(Ordering::SeqCst);
+
+        self.last_used_idx != field_ptr!(&self.used, UsedRing, idx).read_once().unwrap()
+    }
+
+    /// The number of free descriptors.
+    fn available_desc(&self) -> usize {
+        (self.queue_size - self.num_used) as usize
+    }
+
+    /// Get a token from device used buffers, return (token, len).
+    ///
+    /// The descriptors of the token are kept until it is recycled.
+    ///
+    /// Ref: linux virtio_ring.c virtqueue_get_buf_ctx
+    fn pop_used(&mut self) -> Result<(u16, u32), QueueError> {
+        if !self.can_pop() {
+            return Err(QueueError::NotReady);
+        }
+
+        let last_used_slot = self.last_used_idx & (self.queue_size - 1);
+        let element_ptr = self.used_elem_ptr(last_used_slot);
+        let index = field_ptr!(&element_ptr, UsedElem, id).read_once().unwrap();
+        let len = field_ptr!(&element_ptr, UsedElem, len).read_once().unwrap();
+
+        #[cfg(debug_assertions)]
+        self.check_used_len(index as u16, len);
+        self.last_used_idx = self.last_used_idx.wrapping_add(1);
+        self.refresh_used_event();
+
+        Ok((index as u16, len))
+    }
+
+    /// Recycles the descriptors of a token popped from the used ring.
+    fn recycle(&mut self, token: u16) {
+        self.recycle_descriptors(token);
+    }
+
+    /// Return size of the queue.
+    fn size(&self) -> u16 {
+        self.queue_size
+    }
+
+    /// whether the driver should notify the device
+    ///
+    /// With the event index, the device is only notified once the available index
+    /// passes the `avail_event` it asked for since the last notification.
+    fn should_notify(&self) -> bool {
+        // read barrier
+        fence(Ordering::SeqCst);
+        if self.event_idx {
+            let avail_event = self.avail_event_ptr().read_once().unwrap();
+            return need_event(avail_event, self.avail_idx, self.notified_avail_idx);
+        }
+        let flags = field_ptr!(&self.used, UsedRing, flags).read_once().unwrap();
+        flags & 0x0001u16 == 0u16
+    }
+
+    /// notify that there are available rings
+    fn notify(&mut self) {
+        self.notified_avail_idx = self.avail_idx;
+        notify_device(&self.notify_config, self.queue_idx);
+    }
+
+    /// Disables registered callbacks.
+    ///
+    /// That is to say, the queue won't generate interrupts after calling this method.
+    fn disable_callback(&mut self) {
+        if !self.is_callback_enabled {
+            return;
+        }
+
+        if self.event_idx {
+            // The device interrupts only once it writes the entry at `used_event`,
+            // which is as far as possible from the entries to come.
+            self.set_used_event(self.last_used_idx.wrapping_add(0x8000));
+            self.is_callback_enabled = false;
+            return;
+        }
+
+        let flags_ptr = field_ptr!(&self.avail, AvailRing, flags);
+        let mut flags: AvailFlags = flags_ptr.read_once().unwrap();
+        debug_assert!(!flags.contains(AvailFlags::VIRTQ_AVAIL_F_NO_INTERRUPT));
+        flags.insert(AvailFlags::VIRTQ_AVAIL_F_NO_INTERRUPT);
+        flags_ptr.write_once(&flags).unwrap();
+
+        self.is_callback_enabled = false;
+    }
+
+    /// Enables registered callbacks.
+    ///
+    /// The queue will generate interrupts if any event comes after calling this method.
+    fn enable_callback(&mut self) {
+        if self.event_idx {
+            self.set_used_event(self.last_used_idx);
+            self.is_callback_enabled = true;
+            return;
+        }
+
+        if self.is_callback_enabled {
+            return;
+        }
+
+        let flags_ptr = field_ptr!(&self.avail, AvailRing, flags);
+        let mut flags: AvailFlags = flags_ptr.read_once().unwrap();
+        debug_assert!(flags.contains(AvailFlags::VIRTQ_AVAIL_F_NO_INTERRUPT));
+        flags.remove(AvailFlags::VIRTQ_AVAIL_F_NO_INTERRUPT);
+        flags_ptr.write_once(&flags).unwrap();
+
+        self.is_callback_enabled = true;
+    }
+
+    /// Enables registered callbacks, but delays the interrupt until the device has
+    /// used `nr_used` more buffers, e.g., all but the last few of those in flight.
+    ///
+    /// Without the event index, an interrupt cannot be delayed, so this is
+    /// [`QueueRing::enable_callback`].
+    ///
+    /// Ref: linux virtio_ring.c virtqueue_enable_cb_delayed
+    fn enable_callback_delayed(&mut self, nr_used: u16) {
+        if !self.event_idx {
+            self.enable_callback();
+            return;
+        }
+
+        self.set_used_event(self.last_used_idx.wrapping_add(nr_used.max(1) - 1));
+        self.is_callback_enabled = true;
+    }
+}
+
+#[repr(C, align(16))]
+#[derive(Debug, Default, Copy, Clone, Pod)]
+pub struct Descriptor {
+    addr: u64,
+    len: u32,
+    flags: DescFlags,
+    next: u16,
+}
+
+/// The address recycled descriptors point to in debug builds.
+const POISON_ADDR: u64 = 0xdead_0000_dead_0000;
+/// The length of recycled descriptors in debug builds.
+const POISON_LEN: u32 = 0xdead_beef;
+
+/// Checks that a descriptor taken from the free list has not been touched since it was recycled.
+#[cfg(debug_assertions)]
+fn check_poisoned(desc: &SafePtr<Descriptor, DmaCoherent>, index: u16) {
+    let addr: u64 = field_ptr!(desc, Descriptor, addr).read_once().unwrap();
+    let len: u32 = field_ptr!(desc, Descriptor, len).read_once().unwrap();
+    // The descriptors never used yet are zeroed.
+    assert!(
+        (addr, len) == (POISON_ADDR, POISON_LEN) || (addr, len) == (0, 0),
+        "free descriptor {} was overwritten: addr = {:#x}, len = {}",
+        index,
+        addr,
+        len
+    );
+}
+
+/// Allocates an indirect table chaining the `inputs` and then the `outputs`.
+///
+/// Returns `None` if no table can be allocated, so that the buffers are chained in
+/// the ring instead.
+fn alloc_indirect_table<T: DmaBuf>(inputs: &[&T], outputs: &[&T]) -> Option<DmaSegment> {
+    let table = INDIRECT_TABLE_POOL.get()?.alloc_segment().ok()?;
+    let nr_bufs = inputs.len() + outputs.len();
+    let bufs = inputs
+        .iter()
+        .map(|input| (input, DescFlags::empty()))
+        .chain(outputs.iter().map(|output| (output, DescFlags::WRITE)));
+    let mut writer = table.writer().ok()?;
+    for (i, (buf, flags)) in bufs.enumerate() {
+        debug_assert_ne!(buf.len(), 0);
+        let next = i + 1;
+        let desc = Descriptor {
+            addr: buf.daddr() as u64,
+            len: buf.len() as u32,
+            flags: if next < nr_bufs {
+                flags | DescFlags::NEXT
+            } else {
+                flags
+            },
+            next: if next < nr_bufs { next as u16 } else { 0 },
+        };
+        writer.write_val(&desc).ok()?;
+    }
+    table.sync(0..nr_bufs * size_of::<Descriptor>()).ok()?;
+    Some(table)
+}
+
+type DescriptorPtr<'a> = SafePtr<Descriptor, &'a DmaCoherent, TRightSet<TRights![Dup, Write]>>;
+
+#[inline]
+fn set_dma_buf<T: DmaBuf>(desc_ptr: &DescriptorPtr, buf: &T) {
+    // TODO: skip the empty dma buffer or just return error?
+    debug_assert_ne!(buf.len(), 0);
+    let daddr = buf.daddr();
+    field_ptr!(desc_ptr, Descriptor, addr)
+        .write_once(&(daddr as u64))
+        .unwrap();
+    field_ptr!(desc_ptr, Descriptor, len)
+        .write_once(&(buf.len() as u32))
+        .unwrap();
+}
+
+bitflags! {
+    /// Descriptor flags
+    #[derive(Pod, Default)]
+    #[repr(C)]
+    struct DescFlags: u16 {
+        const NEXT = 1;
+        const WRITE = 2;
+        const INDIRECT = 4;
+    }
+}
+
+/// The driver uses the available ring to offer buffers to the device:
+/// each ring entry refers to the head of a descriptor chain.
+/// It is only written by the driver and read by the device.
+///
+/// This is the header of the ring, which is followed by `queue_size` entries of `u16`
+/// and then the `used_event` field.
+#[repr(C, align(2))]
+#[derive(Debug, Copy, Clone, Pod)]
+pub struct AvailRing {
+    flags: AvailFlags,
+    /// A driver MUST NOT decrement the idx.
+    idx: u16,
+}
+
+/// The used ring is where the device returns buffers once it is done with them:
+/// it is only written to by the device, and read by the driver.
+///
+/// This is the header of the ring, which is followed by `queue_size` elements of
+/// [`UsedElem`] and then the `avail_event` field.
+#[repr(C, align(4))]
+#[derive(Debug, Copy, Clone, Pod)]
+pub struct UsedRing {
+    // the flag in UsedRing
+    flags: u16,
+    // the next index of the used element in ring array
+    idx: u16,
+}
+
+/// The size of the available ring of a queue with `queue_size` entries, in bytes.
+fn avail_ring_size(queue_size: usize) -> usize {
+    size_of::<AvailRing>() + size_of::<u16>() * (queue_size + 1)
+}
+
+/// The size of the used ring of a queue with `queue_size` elements, in bytes.
+fn used_ring_size(queue_size: usize) -> usize {
+    size_of::<UsedRing>() + size_of::<UsedElem>() * queue_size + size_of::<u16>()
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Pod)]
+pub struct UsedElem {
+    id: u32,
+    len: u32,
+}
+
+bitflags! {
+    /// The flags useds in [`AvailRing`]
+    #[repr(C)]
+    #[derive(Pod)]
+    pub struct AvailFlags: u16 {
+        /// The flag used to disable virt queue interrupt
+        const VIRTQ_AVAIL_F_NO_INTERRUPT = 1;
+    }
+}
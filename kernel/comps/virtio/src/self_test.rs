@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! Self-tests of the virtio devices.
+//!
+//! Drivers register a named test for each device they probe, instead of
+//! exercising the device at probe time. All the tests are then run on demand
+//! from [`run_self_tests`], e.g., by a debug command.
+
+use alloc::{
+    boxed::Box,
+    collections::btree_map::BTreeMap,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+
+use log::{error, info};
+use ostd::sync::SpinLock;
+
+use crate::device::VirtioDeviceError;
+
+/// A self-test of a device.
+pub type SelfTest = dyn Fn() -> Result<(), VirtioDeviceError> + Send + Sync;
+
+/// The outcome of a self-test.
+#[derive(Debug)]
+pub struct SelfTestResult {
+    pub name: String,
+    pub result: Result<(), VirtioDeviceError>,
+}
+
+impl SelfTestResult {
+    pub fn passed(&self) -> bool {
+        self.result.is_ok()
+    }
+}
+
+static SELF_TESTS: SpinLock<BTreeMap<String, Arc<SelfTest>>> = SpinLock::new(BTreeMap::new());
+
+/// Registers a self-test under `name`, replacing any test of the same name.
+pub fn register_self_test(
+    name: &str,
+    test: impl Fn() -> Result<(), VirtioDeviceError> + Send + Sync + 'static,
+) {
+    let test: Box<SelfTest> = Box::new(test);
+    SELF_TESTS.lock().insert(name.to_string(), Arc::from(test));
+}
+
+/// Unregisters the self-test registered under `name`, e.g., when its device is removed.
+pub fn unregister_self_test(name: &str) {
+    SELF_TESTS.lock().remove(name);
+}
+
+/// Returns the names of the registered self-tests.
+pub fn self_tests() -> Vec<String> {
+    SELF_TESTS.lock().keys().cloned().collect()
+}
+
+/// Runs every registered self-test in name order and reports their results.
+pub fn run_self_tests() -> Vec<SelfTestResult> {
+    // Run the tests without holding the lock, since they may take long.
+    let tests: Vec<_> = SELF_TESTS
+        .lock()
+        .iter()
+        .map(|(name, test)| (name.clone(), test.clone()))
+        .collect();
+
+    tests
+        .into_iter()
+        .map(|(name, test)| {
+            let result = test();
+            match &result {
+                Ok(()) => info!("[Virtio]: self-test {} passed", name),
+                Err(err) => error!("[Virtio]: self-test {} failed: {:?}", name, err),
+            }
+            SelfTestResult { name, result }
+        })
+        .collect()
+}
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: MPL-2.0
 
-use alloc::{boxed::Box, sync::Arc};
+use alloc::{boxed::Box, format, string::String, sync::Arc};
 use core::mem::size_of;
 
 use aster_rights::{ReadOp, WriteOp};
@@ -39,6 +39,8 @@ pub struct VirtioMmioTransport {
     device: Arc<VirtioMmioDevice>,
     common_device: ostd::bus::mmio::common_device::MmioCommonDevice,
     multiplex: Arc<RwLock<MultiplexIrq>>,
+    /// The features written by the driver, since the register is write-only.
+    driver_features: u64,
 }
 
 impl MmioDevice for VirtioMmioDevice {
@@ -79,6 +81,7 @@ impl VirtioMmioTransport {
             common_device: device,
             multiplex: MultiplexIrq::new(irq, interrupt_ack, interrupt_status),
             device: Arc::new(VirtioMmioDevice { device_id }),
+            driver_features: 0,
         };
         if device.common_device.read_version().unwrap() == VirtioMmioVersion::Legacy {
             field_ptr!(&device.layout, VirtioMmioLayout, legacy_guest_page_size)
@@ -94,6 +97,10 @@ impl VirtioTransport for VirtioMmioTransport {
         VirtioDeviceType::try_from(self.device.device_id() as u8).unwrap()
     }
 
+    fn location(&self) -> String {
+        format!("mmio-{:#x}", self.common_device.address())
+    }
+
     fn set_queue(
         &mut self,
         idx: u16,
@@ -243,9 +250,14 @@ impl VirtioTransport for VirtioMmioTransport {
         field_ptr!(&self.layout, VirtioMmioLayout, driver_features)
             .write_once(&high)
             .unwrap();
+        self.driver_features = features;
         Ok(())
     }
 
+    fn read_driver_features(&self) -> u64 {
+        self.driver_features
+    }
+
     fn read_device_status(&self) -> DeviceStatus {
         DeviceStatus::from_bits(
             field_ptr!(&self.layout, VirtioMmioLayout, status)
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: MPL-2.0
 
-use alloc::boxed::Box;
+use alloc::{boxed::Box, string::String};
 use core::fmt::Debug;
 
 use aster_util::safe_ptr::SafePtr;
@@ -34,12 +34,22 @@ pub trait VirtioTransport: Sync + Send + Debug {
     /// Get device type.
     fn device_type(&self) -> VirtioDeviceType;
 
+    /// Get the location of the device on its bus, e.g., `pci-0000:00:04.0` or
+    /// `mmio-0xfeb00000`.
+    ///
+    /// Unlike the probing order, the location stays the same across boots as long
+    /// as the machine is configured the same way.
+    fn location(&self) -> String;
+
     /// Get device features.
     fn read_device_features(&self) -> u64;
 
     /// Set driver features.
     fn write_driver_features(&mut self, features: u64) -> Result<(), VirtioTransportError>;
 
+    /// Get driver features, i.e., the features negotiated with the device.
+    fn read_driver_features(&self) -> u64;
+
     /// Get device status.
     fn read_device_status(&self) -> DeviceStatus;
 
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: MPL-2.0
 
-use alloc::boxed::Box;
+use alloc::{boxed::Box, string::String};
 use core::fmt::Debug;
 
 use aster_util::{field_ptr, safe_ptr::SafePtr};
@@ -74,6 +74,10 @@ impl VirtioTransport for VirtioPciModernTransport {
         self.device_type
     }
 
+    fn location(&self) -> String {
+        super::pci_location(&self.common_device)
+    }
+
     fn set_queue(
         &mut self,
         idx: u16,
@@ -184,6 +188,24 @@ impl VirtioTransport for VirtioPciModernTransport {
         Ok(())
     }
 
+    fn read_driver_features(&self) -> u64 {
+        // select low
+        field_ptr!(&self.common_cfg, VirtioPciCommonCfg, driver_feature_select)
+            .write_once(&0u32)
+            .unwrap();
+        let driver_feature_low = field_ptr!(&self.common_cfg, VirtioPciCommonCfg, driver_features)
+            .read_once()
+            .unwrap();
+        // select high
+        field_ptr!(&self.common_cfg, VirtioPciCommonCfg, driver_feature_select)
+            .write_once(&1u32)
+            .unwrap();
+        let driver_feature_high = field_ptr!(&self.common_cfg, VirtioPciCommonCfg, driver_features)
+            .read_once()
+            .unwrap() as u64;
+        driver_feature_high << 32 | driver_feature_low as u64
+    }
+
     fn read_device_status(&self) -> DeviceStatus {
         let status = field_ptr!(&self.common_cfg, VirtioPciCommonCfg, device_status)
             .read_once()
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: MPL-2.0
 
-use alloc::boxed::Box;
+use alloc::{boxed::Box, string::String};
 use core::fmt::Debug;
 
 use aster_util::safe_ptr::SafePtr;
@@ -158,6 +158,10 @@ impl VirtioTransport for VirtioPciLegacyTransport {
         self.device_type
     }
 
+    fn location(&self) -> String {
+        super::pci_location(&self.common_device)
+    }
+
     fn set_queue(
         &mut self,
         idx: u16,
@@ -232,6 +236,14 @@ impl VirtioTransport for VirtioPciLegacyTransport {
         Ok(())
     }
 
+    fn read_driver_features(&self) -> u64 {
+        let features = self
+            .config_bar
+            .read_once::<u32>(DRIVER_FEATURES_OFFSET)
+            .unwrap();
+        features as u64
+    }
+
     fn read_device_status(&self) -> DeviceStatus {
         let status = self
             .config_bar
@@ -7,13 +7,23 @@ pub mod driver;
 pub mod legacy;
 pub(super) mod msix;
 
-use alloc::sync::Arc;
+use alloc::{format, string::String, sync::Arc};
 
-use ostd::bus::pci::PCI_BUS;
+use ostd::bus::pci::{common_device::PciCommonDevice, PCI_BUS};
 use spin::Once;
 
 use self::driver::VirtioPciDriver;
 
+/// Formats the location of a PCI device as its domain, bus, device and function numbers.
+fn pci_location(common_device: &PciCommonDevice) -> String {
+    let location = common_device.location();
+    // Only the first PCI domain is supported.
+    format!(
+        "pci-0000:{:02x}:{:02x}.{:x}",
+        location.bus, location.device, location.function
+    )
+}
+
 pub static VIRTIO_PCI_DRIVER: Once<Arc<VirtioPciDriver>> = Once::new();
 pub fn virtio_pci_init() {
     VIRTIO_PCI_DRIVER.call_once(|| Arc::new(VirtioPciDriver::new()));
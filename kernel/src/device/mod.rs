@@ -44,6 +44,7 @@ pub fn init() -> Result<()> {
     add_node(tty, "tty")?;
     let sound=Arc::new(sound::Sound);
     add_node(sound, "sound")?;
+    sound::init()?;
     cfg_if! {
         if #[cfg(all(target_arch = "x86_64", feature = "cvm_guest"))] {
             let tdx_guest = Arc::new(tdxguest::TdxGuest);
@@ -82,6 +83,10 @@ pub fn get_device(dev: usize) -> Result<Arc<dyn Device>> {
         (1, 8) => Ok(Arc::new(random::Random)),
         (1, 9) => Ok(Arc::new(urandom::Urandom)),
         (6,6)=>Ok(Arc::new(sound::Sound)),
+        (116, minor) => match sound::SoundPcm::from_minor(minor) {
+            Some(pcm) => Ok(Arc::new(pcm)),
+            None => return_errno_with_message!(Errno::EINVAL, "unsupported device"),
+        },
         _ => return_errno_with_message!(Errno::EINVAL, "unsupported device"),
     }
 }
\ No newline at end of file
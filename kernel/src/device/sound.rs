@@ -1,4 +1,13 @@
-use aster_sound;
+use aster_sound::{
+    self,
+    integration::{run_integration_tests, Outcome},
+    stream::{open_input, open_output, InputStream, OutputStream, StreamParams},
+    SampleFormat, SoundError, StreamDirection,
+};
+use ostd::{
+    arch::qemu::{exit_qemu, QemuExitCode},
+    boot::kcmdline::{KCmdlineArg, ModuleArg},
+};
 
 use super::*;
 use crate::{
@@ -6,9 +15,10 @@ use crate::{
     fs::inode_handle::FileIo,
     prelude::*,
     process::signal::{PollHandle, Pollable},
+    sched::priority::Priority,
+    thread::kernel_thread::ThreadOptions,
 };
 
-
 pub struct Sound;
 
 impl Device for Sound {
@@ -22,12 +32,232 @@ impl Device for Sound {
     }
 
     fn open(&self) -> Result<Option<Arc<dyn FileIo>>> {
-        let device=&aster_sound::all_devices()[0].1;
-        device.lock().test_device();
+        if aster_sound::default_output().is_none() {
+            return_errno_with_message!(Errno::ENODEV, "no sound output device is found");
+        }
         Ok(Some(Arc::new(Sound)))
     }
 }
 
+/// The major number of the PCM device nodes, the same as ALSA's.
+const PCM_MAJOR: u32 = 116;
+/// The minor numbers of the PCM nodes of a card, as in ALSA.
+const PCM_MINORS_PER_CARD: u32 = 32;
+const PCM_PLAYBACK_MINOR: u32 = 16;
+const PCM_CAPTURE_MINOR: u32 = 24;
+
+/// A PCM device node of a sound card, playing or recording depending on its direction.
+///
+/// A card only gets the nodes of the directions its device was registered with.
+pub struct SoundPcm {
+    card: u32,
+    direction: StreamDirection,
+}
+
+impl SoundPcm {
+    /// Returns the node with the given minor number, if it names one.
+    pub fn from_minor(minor: u32) -> Option<Self> {
+        let card = minor / PCM_MINORS_PER_CARD;
+        let direction = match minor % PCM_MINORS_PER_CARD {
+            PCM_PLAYBACK_MINOR => StreamDirection::Output,
+            PCM_CAPTURE_MINOR => StreamDirection::Input,
+            _ => return None,
+        };
+        Some(Self { card, direction })
+    }
+
+    fn path(&self) -> String {
+        let suffix = match self.direction {
+            StreamDirection::Output => 'p',
+            StreamDirection::Input => 'c',
+        };
+        format!("snd/pcmC{}D0{}", self.card, suffix)
+    }
+}
+
+impl Device for SoundPcm {
+    fn type_(&self) -> DeviceType {
+        DeviceType::CharDevice
+    }
+
+    fn id(&self) -> DeviceId {
+        let minor = match self.direction {
+            StreamDirection::Output => PCM_PLAYBACK_MINOR,
+            StreamDirection::Input => PCM_CAPTURE_MINOR,
+        };
+        DeviceId::new(PCM_MAJOR, self.card * PCM_MINORS_PER_CARD + minor)
+    }
+
+    fn open(&self) -> Result<Option<Arc<dyn FileIo>>> {
+        let registered = aster_sound::device_infos()
+            .get(self.card as usize)
+            .is_some_and(|info| info.has_direction(self.direction));
+        if !registered {
+            return_errno_with_message!(Errno::ENODEV, "the sound card has no such direction");
+        }
+        Ok(Some(Arc::new(SoundPcmFile {
+            card: self.card,
+            direction: self.direction,
+            stream: Mutex::new(None),
+        })))
+    }
+}
+
+/// Creates the PCM device nodes of the registered sound cards.
+///
+/// If no sound device was found, the null device is registered as the only card.
+/// The devices generating test signals are only registered if the sound tests
+/// are asked for.
+pub fn init() -> Result<()> {
+    aster_sound::set_thread_spawner(spawn_sound_thread);
+    aster_sound::register_null_device_if_none();
+    let karg = ostd::boot::kernel_cmdline();
+    if integration_test_requested(karg) || self_test_requested(karg) {
+        aster_sound::loopback::register_loopback_device();
+        aster_sound::tone::register_tone_device();
+    }
+    for (card, info) in aster_sound::device_infos().iter().enumerate() {
+        for direction in [StreamDirection::Output, StreamDirection::Input] {
+            if !info.has_direction(direction) {
+                continue;
+            }
+            let pcm = SoundPcm {
+                card: card as u32,
+                direction,
+            };
+            let path = pcm.path();
+            add_node(Arc::new(pcm), &path)?;
+        }
+    }
+    Ok(())
+}
+
+/// Runs a thread of the sound drivers, e.g., the pump of an output stream.
+fn spawn_sound_thread(f: Box<dyn FnOnce() + Send>) {
+    let f = SpinLock::new(Some(f));
+    // FIXME: remove the use of real-time priority.
+    ThreadOptions::new(move || {
+        let f = f.lock().take();
+        if let Some(f) = f {
+            f();
+        }
+    })
+    .priority(Priority::default_real_time())
+    .spawn();
+}
+
+/// The parameters the stream of a PCM node is opened with.
+const PCM_PARAMS: StreamParams = StreamParams {
+    format: SampleFormat::S16,
+    rate: 48000,
+    channels: 2,
+    // 40 ms, in periods of 10 ms.
+    buffer_bytes: 7680,
+    period_bytes: 1920,
+};
+
+/// An opened PCM node.
+///
+/// A stream of the card is opened and started by the first read or write,
+/// and closed when the file is dropped.
+struct SoundPcmFile {
+    card: u32,
+    direction: StreamDirection,
+    stream: Mutex<Option<PcmStream>>,
+}
+
+enum PcmStream {
+    Output(OutputStream),
+    Input(InputStream),
+}
+
+impl SoundPcmFile {
+    fn open_stream(&self) -> Result<PcmStream> {
+        let Some(info) = aster_sound::device_infos()
+            .into_iter()
+            .nth(self.card as usize)
+        else {
+            return_errno_with_message!(Errno::ENODEV, "the sound card is removed");
+        };
+        let stream = match (self.direction, info.output, info.input) {
+            (StreamDirection::Output, Some(device), _) => {
+                let mut stream = open_output(&device, PCM_PARAMS).map_err(sound_error)?;
+                stream.start().map_err(sound_error)?;
+                PcmStream::Output(stream)
+            }
+            (StreamDirection::Input, _, Some(device)) => {
+                let mut stream = open_input(&device, PCM_PARAMS).map_err(sound_error)?;
+                stream.start().map_err(sound_error)?;
+                PcmStream::Input(stream)
+            }
+            _ => return_errno_with_message!(Errno::ENODEV, "the sound card has no such direction"),
+        };
+        Ok(stream)
+    }
+}
+
+impl Pollable for SoundPcmFile {
+    fn poll(&self, mask: IoEvents, _: Option<&mut PollHandle>) -> IoEvents {
+        let events = match self.direction {
+            StreamDirection::Output => IoEvents::OUT,
+            StreamDirection::Input => IoEvents::IN,
+        };
+        events & mask
+    }
+}
+
+impl FileIo for SoundPcmFile {
+    fn read(&self, writer: &mut VmWriter) -> Result<usize> {
+        if self.direction != StreamDirection::Input {
+            return_errno_with_message!(Errno::EINVAL, "a playback node cannot be read");
+        }
+        let mut stream = self.stream.lock();
+        if stream.is_none() {
+            *stream = Some(self.open_stream()?);
+        }
+        let Some(PcmStream::Input(stream)) = stream.as_mut() else {
+            unreachable!("a capture node only opens input streams");
+        };
+        let mut buf = vec![0; writer.avail()];
+        let len = stream.read(&mut buf).map_err(sound_error)?;
+        buf.truncate(len);
+        writer.write_fallible(&mut buf.as_slice().into())?;
+        Ok(len)
+    }
+
+    fn write(&self, reader: &mut VmReader) -> Result<usize> {
+        if self.direction != StreamDirection::Output {
+            return_errno_with_message!(Errno::EINVAL, "a capture node cannot be written");
+        }
+        let mut stream = self.stream.lock();
+        if stream.is_none() {
+            *stream = Some(self.open_stream()?);
+        }
+        let Some(PcmStream::Output(stream)) = stream.as_mut() else {
+            unreachable!("a playback node only opens output streams");
+        };
+        let buf = reader.collect()?;
+        stream.write(&buf).map_err(sound_error)
+    }
+}
+
+/// Converts an error of the sound drivers to the errno reported to the user.
+fn sound_error(error: SoundError) -> Error {
+    let (errno, msg) = match error {
+        SoundError::NotReady => (Errno::EBADFD, "the sound stream is not ready"),
+        SoundError::InvalidParam => (Errno::EINVAL, "the sound parameters are not accepted"),
+        SoundError::IoError => (Errno::EIO, "the sound device reported an error"),
+        SoundError::QuotaExceeded => (Errno::ENOMEM, "the sound DMA quota is exceeded"),
+        SoundError::Unsupported => (Errno::EOPNOTSUPP, "the sound device does not support it"),
+        SoundError::CaptureBlocked => (Errno::EACCES, "sound capture is blocked"),
+        SoundError::Busy => (Errno::EBUSY, "every sound stream is claimed"),
+        SoundError::Preempted => (Errno::EBUSY, "the sound stream was preempted"),
+        SoundError::Xrun => (Errno::EPIPE, "the sound stream underran or overran"),
+        SoundError::WouldBlock => (Errno::EAGAIN, "the sound device is busy"),
+    };
+    Error::with_message(errno, msg)
+}
+
 impl Pollable for Sound {
     fn poll(&self, mask: IoEvents, _: Option<&mut PollHandle>) -> IoEvents {
         let events = IoEvents::IN | IoEvents::OUT;
@@ -43,4 +273,56 @@ impl FileIo for Sound {
     fn write(&self, reader: &mut VmReader) -> Result<usize> {
         Ok(reader.remain())
     }
-}
\ No newline at end of file
+}
+
+/// Returns whether the kernel is asked to run the sound integration tests,
+/// with `sound.integration_test` on its command line.
+pub fn integration_test_requested(karg: &KCmdlineArg) -> bool {
+    karg.get_module_args("sound").is_some_and(|args| {
+        args.iter().any(
+            |arg| matches!(arg, ModuleArg::Arg(name) if name.as_bytes() == b"integration_test"),
+        )
+    })
+}
+
+/// Returns whether the kernel is asked to run the device self-tests, with
+/// `sound.self_test` on its command line.
+pub fn self_test_requested(karg: &KCmdlineArg) -> bool {
+    karg.get_module_args("sound").is_some_and(|args| {
+        args.iter()
+            .any(|arg| matches!(arg, ModuleArg::Arg(name) if name.as_bytes() == b"self_test"))
+    })
+}
+
+/// Runs the self-tests of the virtio devices, e.g., playing a tone on each
+/// sound device, and reports their results.
+pub fn run_self_tests() {
+    for result in aster_virtio::self_test::run_self_tests() {
+        let outcome = if result.passed() { "passed" } else { "failed" };
+        println!("[sound self-test] {}: {}", result.name, outcome);
+    }
+}
+
+/// Runs the sound integration tests, reports their results and exits QEMU.
+///
+/// Each case is reported on a line of its own, followed by a summary line
+/// that scripts can look for in the QEMU log.
+pub fn run_integration_tests_and_exit() -> ! {
+    let report = run_integration_tests();
+    for result in report.results.iter() {
+        println!("[sound test] {}", result);
+    }
+    println!(
+        "[sound test] {} passed, {} failed, {} skipped",
+        report.count(Outcome::Passed),
+        report.failures().count(),
+        report.count(Outcome::Skipped),
+    );
+
+    let exit_code = if report.passed() {
+        QemuExitCode::Success
+    } else {
+        QemuExitCode::Failed
+    };
+    exit_qemu(exit_code);
+}
@@ -143,6 +143,14 @@ fn init_thread() {
 
     let karg = boot::kernel_cmdline();
 
+    if device::sound::self_test_requested(karg) {
+        device::sound::run_self_tests();
+    }
+
+    if device::sound::integration_test_requested(karg) {
+        device::sound::run_integration_tests_and_exit();
+    }
+
     let initproc = Process::spawn_user_process(
         karg.get_initproc_path().unwrap(),
         karg.get_initproc_argv().to_vec(),